@@ -70,9 +70,23 @@ mod tests {
     pub fn generate() {
         // TODO move to main
         test! {
+            // arm.rs
+            AeabiDcmp,
+            AeabiFcmp,
+
             // float/add.rs
             Adddf3,
             Addsf3,
+            Addtf3,
+
+            // float/cmp.rs
+            Comparedf2,
+            Comparesf2,
+            Comparetf2,
+
+            // float/complex.rs
+            Muldc3,
+            Mulsc3,
 
             // float/conv.rs
             Fixdfdi,
@@ -81,30 +95,141 @@ mod tests {
             Fixsfsi,
             Fixsfti,
             Fixdfti,
+            Fixtfdi,
+            Fixtfsi,
+            Fixtfti,
+            Fixxfdi,
+            Fixxfti,
             Fixunsdfdi,
             Fixunsdfsi,
             Fixunssfdi,
             Fixunssfsi,
             Fixunssfti,
             Fixunsdfti,
+            Fixunstfdi,
+            Fixunstfsi,
+            Fixunstfti,
+            Fixunsxfdi,
+            Fixunsxfsi,
+            Fixunsxfti,
+            FixsfsiSat,
+            FixsfdiSat,
+            FixsftiSat,
+            FixunssfsiSat,
+            FixunssfdiSat,
+            FixunssftiSat,
+            FixdfsiSat,
+            FixdfdiSat,
+            FixdftiSat,
+            FixunsdfsiSat,
+            FixunsdfdiSat,
+            FixunsdftiSat,
+            FixtfsiSat,
+            FixtfdiSat,
+            FixtftiSat,
+            FixunstfsiSat,
+            FixunstfdiSat,
+            FixunstftiSat,
+            FixxfsiSat,
+            FixxfdiSat,
+            FixxftiSat,
+            FixunsxfsiSat,
+            FixunsxfdiSat,
+            FixunsxftiSat,
+            Llroundf,
+            Llround,
+            Llrintf,
+            Llrint,
+            Llroundevenf,
+            Llroundeven,
             Floatdidf,
+            Floatdisf,
             Floatsidf,
             Floatsisf,
             Floattisf,
             Floattidf,
+            Floatditf,
+            Floatsitf,
+            Floattitf,
+            Floatdixf,
+            Floattixf,
             Floatundidf,
+            Floatundisf,
             Floatunsidf,
             Floatunsisf,
             Floatuntisf,
             Floatuntidf,
+            Floatunditf,
+            Floatunsitf,
+            Floatuntitf,
+            Floatundixf,
+            Floatuntixf,
+
+            // float/div.rs
+            Divdf3,
+            Divsf3,
+            Divtf3,
+
+            // float/extend.rs
+            Extendbfsf2,
+            Extendhfsf2,
+            Extendsfdf2,
+            Extenddftf2,
+            Extendsftf2,
+
+            // float/fenv.rs
+            Addsf3Except,
+            Addsf3FlushToZero,
+            Addsf3Rounding,
+            Comparesf2Except,
+            Divdf3Except,
+            Divsf3FlushToZero,
+            Extendsfdf2Except,
+            FixdfsiExcept,
+            FixunssfdiExcept,
+            FloatdidfExcept,
+            FloatdisfExcept,
+            FloattisfRounding,
+            FloattixfRounding,
+            FloatuntidfRounding,
+            FloatuntisfExcept,
+            Muldf3FlushToZero,
+            Mulsf3Except,
+            Powixf2Except,
+            Subdf3Rounding,
+            Truncdfsf2Except,
+
+            // float/mul.rs
+            Muldf3,
+            Mulsf3,
+            Multf3,
 
             // float/pow.rs
             Powidf2,
             Powisf2,
+            Powixf2,
 
             // float/sub.rs
             Subdf3,
             Subsf3,
+            Subtf3,
+
+            // float/trunc.rs
+            Truncdfbf2,
+            Truncdfhf2,
+            Truncdfsf2,
+            Truncsfbf2,
+            Truncsfhf2,
+            Trunctfdf2,
+            Trunctfsf2,
+
+            // int/leading_zeros.rs
+            Clzdi2,
+            Clzsi2,
+            Clzti2,
+            Ctzdi2,
+            Ctzsi2,
+            Ctzti2,
 
             // int/mul.rs
             Muldi3,
@@ -141,6 +266,216 @@ mod tests {
             Umoddi3,
             Umodsi3,
             Umodti3,
+
+            // math.rs
+            Fma,
+            Fmaf,
+            Sqrt,
+            Sqrtf,
+            Floorf,
+            Floor,
+            Ceilf,
+            Ceil,
+            Truncf,
+            Trunc,
+            Roundf,
+            Round,
+            Fminf,
+            Fmin,
+            Fmaxf,
+            Fmax,
+            Fmodf,
+            Fmod,
+            Remainderf,
+            Remainder,
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct AeabiDcmp {
+        a: u64,  // f64
+        b: u64,  // f64
+        c: (i32, i32, i32, i32, i32, i32),
+    }
+
+    impl TestCase for AeabiDcmp {
+        fn name() -> &'static str {
+            "aeabi_dcmp"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f64(rng);
+            // Equal operands and zeros of opposite signs are unlikely to come
+            // up otherwise
+            let b = if rng.gen_weighted_bool(10) {
+                a
+            } else if rng.gen_weighted_bool(10) {
+                -a
+            } else {
+                gen_f64(rng)
+            };
+            let c = (
+                (a == b) as i32,
+                (a < b) as i32,
+                (a <= b) as i32,
+                (a >= b) as i32,
+                (a > b) as i32,
+                (a.is_nan() || b.is_nan()) as i32,
+            );
+
+            Some(AeabiDcmp { a: to_u64(a), b: to_u64(b), c })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a}, {b}), {c:?}),",
+                a = self.a,
+                b = self.b,
+                c = self.c
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::arm::{__aeabi_dcmpeq, __aeabi_dcmpge, __aeabi_dcmpgt, __aeabi_dcmple,
+                             __aeabi_dcmplt, __aeabi_dcmpun};
+
+fn mk_f64(x: u64) -> f64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u64, u64), (i32, i32, i32, i32, i32, i32))] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn aeabi_dcmp() {
+    for &((a, b), c) in TEST_CASES {
+        let (a_, b_) = (mk_f64(a), mk_f64(b));
+        let c_ = (__aeabi_dcmpeq(a_, b_),
+                  __aeabi_dcmplt(a_, b_),
+                  __aeabi_dcmple(a_, b_),
+                  __aeabi_dcmpge(a_, b_),
+                  __aeabi_dcmpgt(a_, b_),
+                  __aeabi_dcmpun(a_, b_));
+        assert_eq!(((a, b), c), ((a, b), c_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct AeabiFcmp {
+        a: u32,  // f32
+        b: u32,  // f32
+        c: (i32, i32, i32, i32, i32, i32),
+    }
+
+    impl TestCase for AeabiFcmp {
+        fn name() -> &'static str {
+            "aeabi_fcmp"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f32(rng);
+            // Equal operands and zeros of opposite signs are unlikely to come
+            // up otherwise
+            let b = if rng.gen_weighted_bool(10) {
+                a
+            } else if rng.gen_weighted_bool(10) {
+                -a
+            } else {
+                gen_f32(rng)
+            };
+            let c = (
+                (a == b) as i32,
+                (a < b) as i32,
+                (a <= b) as i32,
+                (a >= b) as i32,
+                (a > b) as i32,
+                (a.is_nan() || b.is_nan()) as i32,
+            );
+
+            Some(AeabiFcmp { a: to_u32(a), b: to_u32(b), c })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a}, {b}), {c:?}),",
+                a = self.a,
+                b = self.b,
+                c = self.c
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::arm::{__aeabi_fcmpeq, __aeabi_fcmpge, __aeabi_fcmpgt, __aeabi_fcmple,
+                             __aeabi_fcmplt, __aeabi_fcmpun};
+
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u32, u32), (i32, i32, i32, i32, i32, i32))] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn aeabi_fcmp() {
+    for &((a, b), c) in TEST_CASES {
+        let (a_, b_) = (mk_f32(a), mk_f32(b));
+        let c_ = (__aeabi_fcmpeq(a_, b_),
+                  __aeabi_fcmplt(a_, b_),
+                  __aeabi_fcmple(a_, b_),
+                  __aeabi_fcmpge(a_, b_),
+                  __aeabi_fcmpgt(a_, b_),
+                  __aeabi_fcmpun(a_, b_));
+        assert_eq!(((a, b), c), ((a, b), c_));
+    }
+}
+"
         }
     }
 
@@ -163,18 +498,19 @@ mod tests {
         {
             let a = gen_f64(rng);
             let b = gen_f64(rng);
-            let c = a + b;
-            // TODO accept NaNs. We don't do that right now because we can't check
-            // for NaN-ness on the thumb targets (due to missing intrinsics)
-            if a.is_nan() || b.is_nan() || c.is_nan() {
-                return None;
-            }
+            let c = float_nan_result(
+                to_u64(a) as u128,
+                to_u64(b) as u128,
+                to_u64(a + b) as u128,
+                52,
+                11,
+            );
 
             Some(
                 Adddf3 {
                     a: to_u64(a),
                     b: to_u64(b),
-                    c: to_u64(c),
+                    c: c as u64,
                 },
             )
         }
@@ -250,18 +586,19 @@ fn adddf3() {
         {
             let a = gen_f32(rng);
             let b = gen_f32(rng);
-            let c = a + b;
-            // TODO accept NaNs. We don't do that right now because we can't check
-            // for NaN-ness on the thumb targets (due to missing intrinsics)
-            if a.is_nan() || b.is_nan() || c.is_nan() {
-                return None;
-            }
+            let c = float_nan_result(
+                to_u32(a) as u128,
+                to_u32(b) as u128,
+                to_u32(a + b) as u128,
+                23,
+                8,
+            );
 
             Some(
                 Addsf3 {
                     a: to_u32(a),
                     b: to_u32(b),
-                    c: to_u32(c),
+                    c: c as u32,
                 },
             )
         }
@@ -318,6 +655,70 @@ fn addsf3() {
         }
     }
 
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Addtf3 {
+        a: u128, // f128
+        b: u128, // f128
+        c: u128, // f128
+    }
+
+    impl TestCase for Addtf3 {
+        fn name() -> &'static str {
+            "addtf3"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f128(rng);
+            let b = gen_f128(rng);
+            let c = f128_add(a, b);
+            // TODO accept NaNs. We don't do that right now because we can't check
+            // for NaN-ness on the thumb targets (due to missing intrinsics)
+            if f128_is_nan(a) || f128_is_nan(b) || f128_is_nan(c) {
+                return None;
+            }
+
+            Some(Addtf3 { a, b, c })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a}, {b}), {c}),",
+                a = self.a,
+                b = self.b,
+                c = self.c
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::{Float, f128};
+use compiler_builtins::float::add::__addtf3;
+
+static TEST_CASES: &[((u128, u128), u128)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn addtf3() {
+    for &((a, b), c) in TEST_CASES {
+        let c_ = __addtf3(f128::from_repr(a), f128::from_repr(b));
+        assert_eq!(((a, b), c), ((a, b), c_.repr()));
+    }
+}
+"
+        }
+    }
+
     #[derive(Eq, Hash, PartialEq)]
     pub struct Ashldi3 {
         a: u64,
@@ -551,16 +952,17 @@ fn ashrti3() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Divmoddi4 {
-        a: i64,
-        b: i64,
-        c: i64,
-        rem: i64,
+    pub struct Comparedf2 {
+        a: u64,  // f64
+        b: u64,  // f64
+        le: i32,
+        ge: i32,
+        unord: i32,
     }
 
-    impl TestCase for Divmoddi4 {
+    impl TestCase for Comparedf2 {
         fn name() -> &'static str {
-            "divmoddi4"
+            "comparedf2"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -568,35 +970,65 @@ fn ashrti3() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_i64(rng);
-            let b = gen_i64(rng);
-            if b == 0 {
-                return None;
-            }
-            let c = a / b;
-            let rem = a % b;
+            let a = gen_f64(rng);
+            // Equal operands are unlikely to come up otherwise
+            let b = if rng.gen_weighted_bool(10) { a } else { gen_f64(rng) };
+            let unordered = a.is_nan() || b.is_nan();
+            let order = if unordered {
+                0
+            } else if a < b {
+                -1
+            } else if a == b {
+                0
+            } else {
+                1
+            };
 
-            Some(Divmoddi4 { a, b, c, rem })
+            Some(
+                Comparedf2 {
+                    a: to_u64(a),
+                    b: to_u64(b),
+                    le: if unordered { 1 } else { order },
+                    ge: if unordered { -1 } else { order },
+                    unord: unordered as i32,
+                },
+            )
         }
 
         fn to_string(&self, buffer: &mut String) {
             writeln!(
                 buffer,
-                "(({a}, {b}), ({c}, {rem})),",
+                "(({a}, {b}), ({le}, {ge}, {unord})),",
                 a = self.a,
                 b = self.b,
-                c = self.c,
-                rem = self.rem
+                le = self.le,
+                ge = self.ge,
+                unord = self.unord
             )
                     .unwrap();
         }
 
         fn prologue() -> &'static str {
-            "
-use compiler_builtins::int::sdiv::__divmoddi4;
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::cmp::{__eqdf2, __gedf2, __gtdf2, __ledf2, __ltdf2, __nedf2,
+                                    __unorddf2};
 
-static TEST_CASES: &[((i64, i64), (i64, i64))] = &[
-"
+fn mk_f64(x: u64) -> f64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u64, u64), (i32, i32, i32))] = &[
+"#
         }
 
         fn epilogue() -> &'static str {
@@ -604,11 +1036,14 @@ static TEST_CASES: &[((i64, i64), (i64, i64))] = &[
 ];
 
 #[test]
-fn divmoddi4() {
-    for &((a, b), (c, rem)) in TEST_CASES {
-        let mut rem_ = 0;
-        let c_ = __divmoddi4(a, b, &mut rem_);
-        assert_eq!(((a, b), (c, rem)), ((a, b), (c_, rem_)));
+fn comparedf2() {
+    for &((a, b), (le, ge, unord)) in TEST_CASES {
+        let (a_, b_) = (mk_f64(a), mk_f64(b));
+        let le_ = (__ledf2(a_, b_), __eqdf2(a_, b_), __ltdf2(a_, b_), __nedf2(a_, b_));
+        let ge_ = (__gedf2(a_, b_), __gtdf2(a_, b_));
+        let unord_ = __unorddf2(a_, b_);
+        assert_eq!(((a, b), (le, le, le, le), (ge, ge), unord),
+                   ((a, b), le_, ge_, unord_));
     }
 }
 "
@@ -616,15 +1051,17 @@ fn divmoddi4() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Divdi3 {
-        a: i64,
-        b: i64,
-        c: i64,
+    pub struct Comparesf2 {
+        a: u32,  // f32
+        b: u32,  // f32
+        le: i32,
+        ge: i32,
+        unord: i32,
     }
 
-    impl TestCase for Divdi3 {
+    impl TestCase for Comparesf2 {
         fn name() -> &'static str {
-            "divdi3"
+            "comparesf2"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -632,33 +1069,65 @@ fn divmoddi4() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_i64(rng);
-            let b = gen_i64(rng);
-            if b == 0 {
-                return None;
-            }
-            let c = a / b;
-
-            Some(Divdi3 { a, b, c })
-        }
+            let a = gen_f32(rng);
+            // Equal operands are unlikely to come up otherwise
+            let b = if rng.gen_weighted_bool(10) { a } else { gen_f32(rng) };
+            let unordered = a.is_nan() || b.is_nan();
+            let order = if unordered {
+                0
+            } else if a < b {
+                -1
+            } else if a == b {
+                0
+            } else {
+                1
+            };
 
-        fn to_string(&self, buffer: &mut String) {
+            Some(
+                Comparesf2 {
+                    a: to_u32(a),
+                    b: to_u32(b),
+                    le: if unordered { 1 } else { order },
+                    ge: if unordered { -1 } else { order },
+                    unord: unordered as i32,
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
             writeln!(
                 buffer,
-                "(({a}, {b}), {c}),",
+                "(({a}, {b}), ({le}, {ge}, {unord})),",
                 a = self.a,
                 b = self.b,
-                c = self.c
+                le = self.le,
+                ge = self.ge,
+                unord = self.unord
             )
                     .unwrap();
         }
 
         fn prologue() -> &'static str {
-            "
-use compiler_builtins::int::sdiv::__divdi3;
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::cmp::{__eqsf2, __gesf2, __gtsf2, __lesf2, __ltsf2, __nesf2,
+                                    __unordsf2};
 
-static TEST_CASES: &[((i64, i64), i64)] = &[
-"
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u32, u32), (i32, i32, i32))] = &[
+"#
         }
 
         fn epilogue() -> &'static str {
@@ -666,10 +1135,14 @@ static TEST_CASES: &[((i64, i64), i64)] = &[
 ];
 
 #[test]
-fn divdi3() {
-    for &((a, b), c) in TEST_CASES {
-        let c_ = __divdi3(a, b);
-        assert_eq!(((a, b), c), ((a, b), c_));
+fn comparesf2() {
+    for &((a, b), (le, ge, unord)) in TEST_CASES {
+        let (a_, b_) = (mk_f32(a), mk_f32(b));
+        let le_ = (__lesf2(a_, b_), __eqsf2(a_, b_), __ltsf2(a_, b_), __nesf2(a_, b_));
+        let ge_ = (__gesf2(a_, b_), __gtsf2(a_, b_));
+        let unord_ = __unordsf2(a_, b_);
+        assert_eq!(((a, b), (le, le, le, le), (ge, ge), unord),
+                   ((a, b), le_, ge_, unord_));
     }
 }
 "
@@ -677,16 +1150,17 @@ fn divdi3() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Divmodsi4 {
-        a: i32,
-        b: i32,
-        c: i32,
-        rem: i32,
+    pub struct Comparetf2 {
+        a: u128, // f128
+        b: u128, // f128
+        le: i32,
+        ge: i32,
+        unord: i32,
     }
 
-    impl TestCase for Divmodsi4 {
+    impl TestCase for Comparetf2 {
         fn name() -> &'static str {
-            "divmodsi4"
+            "comparetf2"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -694,35 +1168,52 @@ fn divdi3() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_i32(rng);
-            let b = gen_i32(rng);
-            if b == 0 {
-                return None;
-            }
-            let c = a / b;
-            let rem = a % b;
+            let a = gen_f128(rng);
+            // Equal operands are unlikely to come up otherwise
+            let b = if rng.gen_weighted_bool(10) { a } else { gen_f128(rng) };
+            let unordered = f128_is_nan(a) || f128_is_nan(b);
+            let order = if unordered {
+                0
+            } else if f128_order_key(a) < f128_order_key(b) {
+                -1
+            } else if f128_order_key(a) == f128_order_key(b) {
+                0
+            } else {
+                1
+            };
 
-            Some(Divmodsi4 { a, b, c, rem })
+            Some(
+                Comparetf2 {
+                    a,
+                    b,
+                    le: if unordered { 1 } else { order },
+                    ge: if unordered { -1 } else { order },
+                    unord: unordered as i32,
+                },
+            )
         }
 
         fn to_string(&self, buffer: &mut String) {
             writeln!(
                 buffer,
-                "(({a}, {b}), ({c}, {rem})),",
+                "(({a}, {b}), ({le}, {ge}, {unord})),",
                 a = self.a,
                 b = self.b,
-                c = self.c,
-                rem = self.rem
+                le = self.le,
+                ge = self.ge,
+                unord = self.unord
             )
                     .unwrap();
         }
 
         fn prologue() -> &'static str {
-            "
-use compiler_builtins::int::sdiv::__divmodsi4;
+            r#"
+use compiler_builtins::float::{Float, f128};
+use compiler_builtins::float::cmp::{__eqtf2, __getf2, __gttf2, __letf2, __lttf2, __netf2,
+                                    __unordtf2};
 
-static TEST_CASES: &[((i32, i32), (i32, i32))] = &[
-"
+static TEST_CASES: &[((u128, u128), (i32, i32, i32))] = &[
+"#
         }
 
         fn epilogue() -> &'static str {
@@ -730,11 +1221,14 @@ static TEST_CASES: &[((i32, i32), (i32, i32))] = &[
 ];
 
 #[test]
-fn divmodsi4() {
-    for &((a, b), (c, rem)) in TEST_CASES {
-        let mut rem_ = 0;
-        let c_ = __divmodsi4(a, b, &mut rem_);
-        assert_eq!(((a, b), (c, rem)), ((a, b), (c_, rem_)));
+fn comparetf2() {
+    for &((a, b), (le, ge, unord)) in TEST_CASES {
+        let (a_, b_) = (f128::from_repr(a), f128::from_repr(b));
+        let le_ = (__letf2(a_, b_), __eqtf2(a_, b_), __lttf2(a_, b_), __netf2(a_, b_));
+        let ge_ = (__getf2(a_, b_), __gttf2(a_, b_));
+        let unord_ = __unordtf2(a_, b_);
+        assert_eq!(((a, b), (le, le, le, le), (ge, ge), unord),
+                   ((a, b), le_, ge_, unord_));
     }
 }
 "
@@ -742,15 +1236,18 @@ fn divmodsi4() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Divsi3 {
-        a: i32,
-        b: i32,
-        c: i32,
+    pub struct Muldc3 {
+        a: u64,  // f64
+        b: u64,  // f64
+        c: u64,  // f64
+        d: u64,  // f64
+        re: u64, // f64
+        im: u64, // f64
     }
 
-    impl TestCase for Divsi3 {
+    impl TestCase for Muldc3 {
         fn name() -> &'static str {
-            "divsi3"
+            "muldc3"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -758,33 +1255,65 @@ fn divmodsi4() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_i32(rng);
-            let b = gen_i32(rng);
-            if b == 0 {
-                return None;
-            }
-            let c = a / b;
+            let a = gen_f64(rng);
+            let b = gen_f64(rng);
+            let c = gen_f64(rng);
+            let d = gen_f64(rng);
+            let (re, im) = c_muldc3(a, b, c, d);
+            // NaNs are compared by their class only, as the C versions don't
+            // specify which NaN is returned
+            let canonical = |x: f64| if x.is_nan() { ::std::f64::NAN } else { x };
 
-            Some(Divsi3 { a, b, c })
+            Some(
+                Muldc3 {
+                    a: to_u64(a),
+                    b: to_u64(b),
+                    c: to_u64(c),
+                    d: to_u64(d),
+                    re: to_u64(canonical(re)),
+                    im: to_u64(canonical(im)),
+                },
+            )
         }
 
         fn to_string(&self, buffer: &mut String) {
             writeln!(
                 buffer,
-                "(({a}, {b}), {c}),",
+                "(({a}, {b}, {c}, {d}), ({re}, {im})),",
                 a = self.a,
                 b = self.b,
-                c = self.c
+                c = self.c,
+                d = self.d,
+                re = self.re,
+                im = self.im
             )
                     .unwrap();
         }
 
         fn prologue() -> &'static str {
-            "
-use compiler_builtins::int::sdiv::__divsi3;
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::complex::__muldc3;
 
-static TEST_CASES: &[((i32, i32), i32)] = &[
-"
+fn mk_f64(x: u64) -> f64 {
+    unsafe { mem::transmute(x) }
+}
+
+fn canonical(x: u64) -> u64 {
+    if x & !(1 << 63) > 0x7ff0_0000_0000_0000 { 0x7ff8_0000_0000_0000 } else { x }
+}
+
+static TEST_CASES: &[((u64, u64, u64, u64), (u64, u64))] = &[
+"#
         }
 
         fn epilogue() -> &'static str {
@@ -792,10 +1321,13 @@ static TEST_CASES: &[((i32, i32), i32)] = &[
 ];
 
 #[test]
-fn divsi3() {
-    for &((a, b), c) in TEST_CASES {
-        let c_ = __divsi3(a, b);
-        assert_eq!(((a, b), c), ((a, b), c_));
+fn muldc3() {
+    for &((a, b, c, d), (re, im)) in TEST_CASES {
+        let z: [u64; 2] = unsafe {
+            mem::transmute(__muldc3(mk_f64(a), mk_f64(b), mk_f64(c), mk_f64(d)))
+        };
+        let (re_, im_) = (canonical(z[0]), canonical(z[1]));
+        assert_eq!(((a, b, c, d), (re, im)), ((a, b, c, d), (re_, im_)));
     }
 }
 "
@@ -803,15 +1335,18 @@ fn divsi3() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Divti3 {
-        a: i128,
-        b: i128,
-        c: i128,
+    pub struct Mulsc3 {
+        a: u32,  // f32
+        b: u32,  // f32
+        c: u32,  // f32
+        d: u32,  // f32
+        re: u32, // f32
+        im: u32, // f32
     }
 
-    impl TestCase for Divti3 {
+    impl TestCase for Mulsc3 {
         fn name() -> &'static str {
-            "divti3"
+            "mulsc3"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -819,74 +1354,41 @@ fn divsi3() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_i128(rng);
-            let b = gen_i128(rng);
-            if b == 0 {
-                return None;
-            }
-            let c = a / b;
+            let a = gen_f32(rng);
+            let b = gen_f32(rng);
+            let c = gen_f32(rng);
+            let d = gen_f32(rng);
+            let (re, im) = c_mulsc3(a, b, c, d);
+            // NaNs are compared by their class only, as the C versions don't
+            // specify which NaN is returned
+            let canonical = |x: f32| if x.is_nan() { ::std::f32::NAN } else { x };
 
-            Some(Divti3 { a, b, c })
+            Some(
+                Mulsc3 {
+                    a: to_u32(a),
+                    b: to_u32(b),
+                    c: to_u32(c),
+                    d: to_u32(d),
+                    re: to_u32(canonical(re)),
+                    im: to_u32(canonical(im)),
+                },
+            )
         }
 
         fn to_string(&self, buffer: &mut String) {
             writeln!(
                 buffer,
-                "(({a}, {b}), {c}),",
+                "(({a}, {b}, {c}, {d}), ({re}, {im})),",
                 a = self.a,
                 b = self.b,
-                c = self.c
+                c = self.c,
+                d = self.d,
+                re = self.re,
+                im = self.im
             )
                     .unwrap();
         }
 
-        fn prologue() -> &'static str {
-            "
-use compiler_builtins::int::sdiv::__divti3;
-
-static TEST_CASES: &[((i128, i128), i128)] = &[
-"
-        }
-
-        fn epilogue() -> &'static str {
-            "
-];
-
-#[test]
-fn divti3() {
-    for &((a, b), c) in TEST_CASES {
-        let c_ = __divti3(a, b);
-        assert_eq!(((a, b), c), ((a, b), c_));
-    }
-}
-"
-        }
-    }
-
-    #[derive(Eq, Hash, PartialEq)]
-    pub struct Fixdfdi {
-        a: u64,  // f64
-        b: i64,
-    }
-
-    impl TestCase for Fixdfdi {
-        fn name() -> &'static str {
-            "fixdfdi"
-        }
-
-        fn generate<R>(rng: &mut R) -> Option<Self>
-        where
-            R: Rng,
-            Self: Sized,
-        {
-            let a = gen_f64(rng);
-            i64(a).ok().map(|b| Fixdfdi { a: to_u64(a), b })
-        }
-
-        fn to_string(&self, buffer: &mut String) {
-            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
-        }
-
         fn prologue() -> &'static str {
             r#"
 #[cfg(all(target_arch = "arm",
@@ -899,13 +1401,17 @@ use core::mem;
               target_os = "linux",
               test)))]
 use std::mem;
-use compiler_builtins::float::conv::__fixdfdi;
+use compiler_builtins::float::complex::__mulsc3;
 
-fn mk_f64(x: u64) -> f64 {
+fn mk_f32(x: u32) -> f32 {
     unsafe { mem::transmute(x) }
 }
 
-static TEST_CASES: &[((u64,), i64)] = &[
+fn canonical(x: u32) -> u32 {
+    if x & !(1 << 31) > 0x7f80_0000 { 0x7fc0_0000 } else { x }
+}
+
+static TEST_CASES: &[((u32, u32, u32, u32), (u32, u32))] = &[
 "#
         }
 
@@ -914,10 +1420,14 @@ static TEST_CASES: &[((u64,), i64)] = &[
 ];
 
 #[test]
-fn fixdfdi() {
-    for &((a,), b) in TEST_CASES {
-        let b_ = __fixdfdi(mk_f64(a));
-        assert_eq!(((a,), b), ((a,), b_));
+fn mulsc3() {
+    for &((a, b, c, d), (re, im)) in TEST_CASES {
+        // The result is a packed `u64` on x86
+        let z: [u32; 2] = unsafe {
+            mem::transmute(__mulsc3(mk_f32(a), mk_f32(b), mk_f32(c), mk_f32(d)))
+        };
+        let (re_, im_) = (canonical(z[0]), canonical(z[1]));
+        assert_eq!(((a, b, c, d), (re, im)), ((a, b, c, d), (re_, im_)));
     }
 }
 "
@@ -925,14 +1435,15 @@ fn fixdfdi() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Fixdfsi {
+    pub struct Divdf3 {
         a: u64,  // f64
-        b: i32,
+        b: u64,  // f64
+        c: u64,  // f64
     }
 
-    impl TestCase for Fixdfsi {
+    impl TestCase for Divdf3 {
         fn name() -> &'static str {
-            "fixdfsi"
+            "divdf3"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -941,11 +1452,33 @@ fn fixdfdi() {
             Self: Sized,
         {
             let a = gen_f64(rng);
-            i32(a).ok().map(|b| Fixdfsi { a: to_u64(a), b })
+            let b = gen_f64(rng);
+            let c = float_nan_result(
+                to_u64(a) as u128,
+                to_u64(b) as u128,
+                to_u64(a / b) as u128,
+                52,
+                11,
+            );
+
+            Some(
+                Divdf3 {
+                    a: to_u64(a),
+                    b: to_u64(b),
+                    c: c as u64,
+                },
+            )
         }
 
         fn to_string(&self, buffer: &mut String) {
-            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+            writeln!(
+                buffer,
+                "(({a}, {b}), {c}),",
+                a = self.a,
+                b = self.b,
+                c = self.c
+            )
+                    .unwrap();
         }
 
         fn prologue() -> &'static str {
@@ -960,13 +1493,17 @@ use core::mem;
               target_os = "linux",
               test)))]
 use std::mem;
-use compiler_builtins::float::conv::__fixdfsi;
+use compiler_builtins::float::div::__divdf3;
 
 fn mk_f64(x: u64) -> f64 {
     unsafe { mem::transmute(x) }
 }
 
-static TEST_CASES: &[((u64,), i32)] = &[
+fn to_u64(x: f64) -> u64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u64, u64), u64)] = &[
 "#
         }
 
@@ -975,10 +1512,10 @@ static TEST_CASES: &[((u64,), i32)] = &[
 ];
 
 #[test]
-fn fixdfdi() {
-    for &((a,), b) in TEST_CASES {
-        let b_ = __fixdfsi(mk_f64(a));
-        assert_eq!(((a,), b), ((a,), b_));
+fn divdf3() {
+    for &((a, b), c) in TEST_CASES {
+        let c_ = __divdf3(mk_f64(a), mk_f64(b));
+        assert_eq!(((a, b), c), ((a, b), to_u64(c_)));
     }
 }
 "
@@ -986,14 +1523,16 @@ fn fixdfdi() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Fixsfdi {
-        a: u32,  // f32
+    pub struct Divmoddi4 {
+        a: i64,
         b: i64,
+        c: i64,
+        rem: i64,
     }
 
-    impl TestCase for Fixsfdi {
+    impl TestCase for Divmoddi4 {
         fn name() -> &'static str {
-            "fixsfdi"
+            "divmoddi4"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -1001,12 +1540,8239 @@ fn fixdfdi() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_f32(rng);
-            i64(a).ok().map(|b| Fixsfdi { a: to_u32(a), b })
+            let a = gen_i64(rng);
+            let b = gen_i64(rng);
+            if b == 0 {
+                return None;
+            }
+            let c = a / b;
+            let rem = a % b;
+
+            Some(Divmoddi4 { a, b, c, rem })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a}, {b}), ({c}, {rem})),",
+                a = self.a,
+                b = self.b,
+                c = self.c,
+                rem = self.rem
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            "
+use compiler_builtins::int::sdiv::__divmoddi4;
+
+static TEST_CASES: &[((i64, i64), (i64, i64))] = &[
+"
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn divmoddi4() {
+    for &((a, b), (c, rem)) in TEST_CASES {
+        let mut rem_ = 0;
+        let c_ = __divmoddi4(a, b, &mut rem_);
+        assert_eq!(((a, b), (c, rem)), ((a, b), (c_, rem_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Divdi3 {
+        a: i64,
+        b: i64,
+        c: i64,
+    }
+
+    impl TestCase for Divdi3 {
+        fn name() -> &'static str {
+            "divdi3"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_i64(rng);
+            let b = gen_i64(rng);
+            if b == 0 {
+                return None;
+            }
+            let c = a / b;
+
+            Some(Divdi3 { a, b, c })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a}, {b}), {c}),",
+                a = self.a,
+                b = self.b,
+                c = self.c
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            "
+use compiler_builtins::int::sdiv::__divdi3;
+
+static TEST_CASES: &[((i64, i64), i64)] = &[
+"
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn divdi3() {
+    for &((a, b), c) in TEST_CASES {
+        let c_ = __divdi3(a, b);
+        assert_eq!(((a, b), c), ((a, b), c_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Divmodsi4 {
+        a: i32,
+        b: i32,
+        c: i32,
+        rem: i32,
+    }
+
+    impl TestCase for Divmodsi4 {
+        fn name() -> &'static str {
+            "divmodsi4"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_i32(rng);
+            let b = gen_i32(rng);
+            if b == 0 {
+                return None;
+            }
+            let c = a / b;
+            let rem = a % b;
+
+            Some(Divmodsi4 { a, b, c, rem })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a}, {b}), ({c}, {rem})),",
+                a = self.a,
+                b = self.b,
+                c = self.c,
+                rem = self.rem
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            "
+use compiler_builtins::int::sdiv::__divmodsi4;
+
+static TEST_CASES: &[((i32, i32), (i32, i32))] = &[
+"
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn divmodsi4() {
+    for &((a, b), (c, rem)) in TEST_CASES {
+        let mut rem_ = 0;
+        let c_ = __divmodsi4(a, b, &mut rem_);
+        assert_eq!(((a, b), (c, rem)), ((a, b), (c_, rem_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Divsf3 {
+        a: u32,  // f32
+        b: u32,  // f32
+        c: u32,  // f32
+    }
+
+    impl TestCase for Divsf3 {
+        fn name() -> &'static str {
+            "divsf3"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f32(rng);
+            let b = gen_f32(rng);
+            let c = float_nan_result(
+                to_u32(a) as u128,
+                to_u32(b) as u128,
+                to_u32(a / b) as u128,
+                23,
+                8,
+            );
+
+            Some(
+                Divsf3 {
+                    a: to_u32(a),
+                    b: to_u32(b),
+                    c: c as u32,
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a}, {b}), {c}),",
+                a = self.a,
+                b = self.b,
+                c = self.c
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::div::__divsf3;
+
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+fn to_u32(x: f32) -> u32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u32, u32), u32)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn divsf3() {
+    for &((a, b), c) in TEST_CASES {
+        let c_ = __divsf3(mk_f32(a), mk_f32(b));
+        assert_eq!(((a, b), c), ((a, b), to_u32(c_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Divtf3 {
+        a: u128, // f128
+        b: u128, // f128
+        c: u128, // f128
+    }
+
+    impl TestCase for Divtf3 {
+        fn name() -> &'static str {
+            "divtf3"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f128(rng);
+            let b = gen_f128(rng);
+            let c = f128_div(a, b);
+            // TODO accept NaNs. We don't do that right now because we can't check
+            // for NaN-ness on the thumb targets (due to missing intrinsics)
+            if f128_is_nan(a) || f128_is_nan(b) || f128_is_nan(c) {
+                return None;
+            }
+
+            Some(Divtf3 { a, b, c })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a}, {b}), {c}),",
+                a = self.a,
+                b = self.b,
+                c = self.c
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::{Float, f128};
+use compiler_builtins::float::div::__divtf3;
+
+static TEST_CASES: &[((u128, u128), u128)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn divtf3() {
+    for &((a, b), c) in TEST_CASES {
+        let c_ = __divtf3(f128::from_repr(a), f128::from_repr(b));
+        assert_eq!(((a, b), c), ((a, b), c_.repr()));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Divsi3 {
+        a: i32,
+        b: i32,
+        c: i32,
+    }
+
+    impl TestCase for Divsi3 {
+        fn name() -> &'static str {
+            "divsi3"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_i32(rng);
+            let b = gen_i32(rng);
+            if b == 0 {
+                return None;
+            }
+            let c = a / b;
+
+            Some(Divsi3 { a, b, c })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a}, {b}), {c}),",
+                a = self.a,
+                b = self.b,
+                c = self.c
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            "
+use compiler_builtins::int::sdiv::__divsi3;
+
+static TEST_CASES: &[((i32, i32), i32)] = &[
+"
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn divsi3() {
+    for &((a, b), c) in TEST_CASES {
+        let c_ = __divsi3(a, b);
+        assert_eq!(((a, b), c), ((a, b), c_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Divti3 {
+        a: i128,
+        b: i128,
+        c: i128,
+    }
+
+    impl TestCase for Divti3 {
+        fn name() -> &'static str {
+            "divti3"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_i128(rng);
+            let b = gen_i128(rng);
+            if b == 0 {
+                return None;
+            }
+            let c = a / b;
+
+            Some(Divti3 { a, b, c })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a}, {b}), {c}),",
+                a = self.a,
+                b = self.b,
+                c = self.c
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            "
+use compiler_builtins::int::sdiv::__divti3;
+
+static TEST_CASES: &[((i128, i128), i128)] = &[
+"
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn divti3() {
+    for &((a, b), c) in TEST_CASES {
+        let c_ = __divti3(a, b);
+        assert_eq!(((a, b), c), ((a, b), c_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Extendbfsf2 {
+        a: u16,  // bf16
+        b: u32,  // f32
+    }
+
+    impl TestCase for Extendbfsf2 {
+        fn name() -> &'static str {
+            "extendbfsf2"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = rng.gen();
+            let b = if bf16_is_nan(a) {
+                float_convert_nan(a as u128, 7, 8, 23, 8) as u32
+            } else {
+                to_u32(bf16_to_f64(a) as f32)
+            };
+
+            Some(Extendbfsf2 { a, b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::extend::__extendbfsf2;
+
+fn to_u32(x: f32) -> u32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u16,), u32)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn extendbfsf2() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = to_u32(__extendbfsf2(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Extendhfsf2 {
+        a: u16,  // f16
+        b: u32,  // f32
+    }
+
+    impl TestCase for Extendhfsf2 {
+        fn name() -> &'static str {
+            "extendhfsf2"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f16(rng);
+            // TODO accept NaNs. We don't do that right now because we can't check
+            // for NaN-ness on the thumb targets (due to missing intrinsics)
+            if f16_is_nan(a) {
+                return None;
+            }
+
+            Some(
+                Extendhfsf2 {
+                    a,
+                    b: to_u32(f16_to_f64(a) as f32),
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::extend::__extendhfsf2;
+
+fn to_u32(x: f32) -> u32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u16,), u32)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn extendhfsf2() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __extendhfsf2(a);
+        assert_eq!(((a,), b), ((a,), to_u32(b_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Extendsfdf2 {
+        a: u32,  // f32
+        b: u64,  // f64
+    }
+
+    impl TestCase for Extendsfdf2 {
+        fn name() -> &'static str {
+            "extendsfdf2"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f32(rng);
+            let b = if a.is_nan() {
+                float_convert_nan(to_u32(a) as u128, 23, 8, 52, 11) as u64
+            } else {
+                to_u64(a as f64)
+            };
+
+            Some(
+                Extendsfdf2 {
+                    a: to_u32(a),
+                    b,
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::extend::__extendsfdf2;
+
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+fn to_u64(x: f64) -> u64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u32,), u64)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn extendsfdf2() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __extendsfdf2(mk_f32(a));
+        assert_eq!(((a,), b), ((a,), to_u64(b_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Extenddftf2 {
+        a: u64,  // f64,
+        b: u128, // f128,
+    }
+
+    impl TestCase for Extenddftf2 {
+        fn name() -> &'static str {
+            "extenddftf2"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f64(rng);
+            // TODO accept NaNs. We don't do that right now because we can't check
+            // for NaN-ness on the thumb targets (due to missing intrinsics)
+            if a.is_nan() {
+                return None;
+            }
+
+            Some(
+                Extenddftf2 {
+                    a: to_u64(a),
+                    b: f64_to_f128(a),
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::Float;
+use compiler_builtins::float::extend::__extenddftf2;
+
+fn mk_f64(x: u64) -> f64 {
+    unsafe { mem::transmute(x) }
+}
+static TEST_CASES: &[((u64,), u128)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn extenddftf2() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __extenddftf2(mk_f64(a)).repr();
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Extendsftf2 {
+        a: u32,  // f32,
+        b: u128, // f128,
+    }
+
+    impl TestCase for Extendsftf2 {
+        fn name() -> &'static str {
+            "extendsftf2"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f32(rng);
+            // TODO accept NaNs. We don't do that right now because we can't check
+            // for NaN-ness on the thumb targets (due to missing intrinsics)
+            if a.is_nan() {
+                return None;
+            }
+
+            Some(
+                Extendsftf2 {
+                    a: to_u32(a),
+                    b: f64_to_f128(a as f64),
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::Float;
+use compiler_builtins::float::extend::__extendsftf2;
+
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+static TEST_CASES: &[((u32,), u128)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn extendsftf2() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __extendsftf2(mk_f32(a)).repr();
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Addsf3Rounding {
+        mode: RoundingMode,
+        a: u32, // f32
+        b: u32, // f32
+        c: u32, // f32
+    }
+
+    impl TestCase for Addsf3Rounding {
+        fn name() -> &'static str {
+            "addsf3_rounding"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let mode = gen_rounding_mode(rng);
+            let a = gen_f32(rng);
+            let b = gen_f32(rng);
+            // TODO accept NaNs. We don't do that right now because we can't check
+            // for NaN-ness on the thumb targets (due to missing intrinsics)
+            if a.is_nan() || b.is_nan() {
+                return None;
+            }
+            let (c, _) = float_add(
+                to_u32(a) as u128,
+                to_u32(b) as u128,
+                23,
+                8,
+                mode,
+            );
+            let c = c as u32;
+            if unsafe { mem::transmute::<_, f32>(c) }.is_nan() {
+                return None;
+            }
+
+            Some(
+                Addsf3Rounding {
+                    mode,
+                    a: to_u32(a),
+                    b: to_u32(b),
+                    c,
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "((RoundingMode::{mode:?}, {a}, {b}), {c}),",
+                mode = self.mode,
+                a = self.a,
+                b = self.b,
+                c = self.c
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::fenv::{RoundingMode, set_rounding_mode};
+use compiler_builtins::float::add::__addsf3;
+
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+fn to_u32(x: f32) -> u32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((RoundingMode, u32, u32), u32)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn addsf3_rounding() {
+    for &((mode, a, b), c) in TEST_CASES {
+        set_rounding_mode(mode);
+        let c_ = __addsf3(mk_f32(a), mk_f32(b));
+        set_rounding_mode(RoundingMode::ToNearest);
+        assert_eq!(((mode, a, b), c), ((mode, a, b), to_u32(c_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Subdf3Rounding {
+        mode: RoundingMode,
+        a: u64, // f64
+        b: u64, // f64
+        c: u64, // f64
+    }
+
+    impl TestCase for Subdf3Rounding {
+        fn name() -> &'static str {
+            "subdf3_rounding"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let mode = gen_rounding_mode(rng);
+            let a = gen_f64(rng);
+            let b = gen_f64(rng);
+            // TODO accept NaNs. We don't do that right now because we can't check
+            // for NaN-ness on the thumb targets (due to missing intrinsics)
+            if a.is_nan() || b.is_nan() {
+                return None;
+            }
+            let (c, _) = float_add(
+                to_u64(a) as u128,
+                to_u64(b) as u128 ^ 1 << 63,
+                52,
+                11,
+                mode,
+            );
+            let c = c as u64;
+            if unsafe { mem::transmute::<_, f64>(c) }.is_nan() {
+                return None;
+            }
+
+            Some(
+                Subdf3Rounding {
+                    mode,
+                    a: to_u64(a),
+                    b: to_u64(b),
+                    c,
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "((RoundingMode::{mode:?}, {a}, {b}), {c}),",
+                mode = self.mode,
+                a = self.a,
+                b = self.b,
+                c = self.c
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::fenv::{RoundingMode, set_rounding_mode};
+use compiler_builtins::float::sub::__subdf3;
+
+fn mk_f64(x: u64) -> f64 {
+    unsafe { mem::transmute(x) }
+}
+
+fn to_u64(x: f64) -> u64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((RoundingMode, u64, u64), u64)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn subdf3_rounding() {
+    for &((mode, a, b), c) in TEST_CASES {
+        set_rounding_mode(mode);
+        let c_ = __subdf3(mk_f64(a), mk_f64(b));
+        set_rounding_mode(RoundingMode::ToNearest);
+        assert_eq!(((mode, a, b), c), ((mode, a, b), to_u64(c_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Addsf3Except {
+        a: u32, // f32
+        b: u32, // f32
+        c: u32, // f32
+        flags: u32,
+    }
+
+    impl TestCase for Addsf3Except {
+        fn name() -> &'static str {
+            "addsf3_except"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            // `gen_f32` mostly returns denormals, also try any bit pattern
+            let a = if rng.gen() { to_u32(gen_f32(rng)) } else { rng.gen() };
+            let b = if rng.gen() { to_u32(gen_f32(rng)) } else { rng.gen() };
+            let (c, flags) = float_add(a as u128, b as u128, 23, 8, RoundingMode::ToNearest);
+
+            Some(
+                Addsf3Except {
+                    a,
+                    b,
+                    c: c as u32,
+                    flags,
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a}, {b}), ({c}, {flags})),",
+                a = self.a,
+                b = self.b,
+                c = self.c,
+                flags = self.flags
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::add::__addsf3;
+use compiler_builtins::float::fenv::{ALL_EXCEPTIONS, clear_except, test_except};
+
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+fn to_u32(x: f32) -> u32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u32, u32), (u32, u32))] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn addsf3_except() {
+    for &((a, b), (c, flags)) in TEST_CASES {
+        clear_except(ALL_EXCEPTIONS);
+        let c_ = to_u32(__addsf3(mk_f32(a), mk_f32(b)));
+        let flags_ = test_except(ALL_EXCEPTIONS);
+        assert_eq!(((a, b), (c, flags)), ((a, b), (c_, flags_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Comparesf2Except {
+        a: u32, // f32
+        b: u32, // f32
+        signaling: u32,
+        quiet: u32,
+    }
+
+    impl TestCase for Comparesf2Except {
+        fn name() -> &'static str {
+            "comparesf2_except"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            // `gen_f32` rarely returns signaling NaNs, also try any NaN
+            let a = if rng.gen_weighted_bool(4) {
+                rng.gen::<u32>() | 0x7f80_0000
+            } else {
+                to_u32(gen_f32(rng))
+            };
+            let b = if rng.gen_weighted_bool(4) {
+                rng.gen::<u32>() | 0x7f80_0000
+            } else {
+                to_u32(gen_f32(rng))
+            };
+            let (a_is_nan, a_is_signaling) = float_classify_nan(a as u128, 23, 8);
+            let (b_is_nan, b_is_signaling) = float_classify_nan(b as u128, 23, 8);
+
+            Some(
+                Comparesf2Except {
+                    a,
+                    b,
+                    signaling: if a_is_nan || b_is_nan { INVALID } else { 0 },
+                    quiet: if a_is_signaling || b_is_signaling { INVALID } else { 0 },
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a}, {b}), ({signaling}, {quiet})),",
+                a = self.a,
+                b = self.b,
+                signaling = self.signaling,
+                quiet = self.quiet
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::cmp::{__eqsf2, __gesf2, __gtsf2, __lesf2, __ltsf2, __nesf2,
+                                    __unordsf2};
+use compiler_builtins::float::fenv::{ALL_EXCEPTIONS, clear_except, test_except};
+
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+/// Returns the exceptions raised by `f`
+fn except<F: FnOnce() -> i32>(f: F) -> u32 {
+    clear_except(ALL_EXCEPTIONS);
+    f();
+    test_except(ALL_EXCEPTIONS)
+}
+
+static TEST_CASES: &[((u32, u32), (u32, u32))] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn comparesf2_except() {
+    for &((a, b), (signaling, quiet)) in TEST_CASES {
+        let (a_, b_) = (mk_f32(a), mk_f32(b));
+        let signaling_ = (except(|| __lesf2(a_, b_)),
+                          except(|| __gesf2(a_, b_)),
+                          except(|| __ltsf2(a_, b_)),
+                          except(|| __gtsf2(a_, b_)));
+        let quiet_ = (except(|| __eqsf2(a_, b_)),
+                      except(|| __nesf2(a_, b_)),
+                      except(|| __unordsf2(a_, b_)));
+        assert_eq!(((a, b), (signaling, signaling, signaling, signaling), (quiet, quiet, quiet)),
+                   ((a, b), signaling_, quiet_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Divdf3Except {
+        a: u64, // f64
+        b: u64, // f64
+        c: u64, // f64
+        flags: u32,
+    }
+
+    impl TestCase for Divdf3Except {
+        fn name() -> &'static str {
+            "divdf3_except"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            // `gen_f64` mostly returns denormals, also try any bit pattern
+            let a = if rng.gen() { to_u64(gen_f64(rng)) } else { rng.gen() };
+            let b = if rng.gen() { to_u64(gen_f64(rng)) } else { rng.gen() };
+            let (c, flags) = float_div(a as u128, b as u128, 52, 11);
+
+            Some(
+                Divdf3Except {
+                    a,
+                    b,
+                    c: c as u64,
+                    flags,
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a}, {b}), ({c}, {flags})),",
+                a = self.a,
+                b = self.b,
+                c = self.c,
+                flags = self.flags
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::div::__divdf3;
+use compiler_builtins::float::fenv::{ALL_EXCEPTIONS, clear_except, test_except};
+
+fn mk_f64(x: u64) -> f64 {
+    unsafe { mem::transmute(x) }
+}
+
+fn to_u64(x: f64) -> u64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u64, u64), (u64, u32))] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn divdf3_except() {
+    for &((a, b), (c, flags)) in TEST_CASES {
+        clear_except(ALL_EXCEPTIONS);
+        let c_ = to_u64(__divdf3(mk_f64(a), mk_f64(b)));
+        let flags_ = test_except(ALL_EXCEPTIONS);
+        assert_eq!(((a, b), (c, flags)), ((a, b), (c_, flags_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Extendsfdf2Except {
+        a: u32, // f32
+        b: u64, // f64
+        flags: u32,
+    }
+
+    impl TestCase for Extendsfdf2Except {
+        fn name() -> &'static str {
+            "extendsfdf2_except"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            // `gen_f32` rarely returns signaling NaNs, also try any NaN
+            let a = if rng.gen_weighted_bool(4) {
+                rng.gen::<u32>() | 0x7f80_0000
+            } else {
+                to_u32(gen_f32(rng))
+            };
+            let (is_nan, is_signaling) = float_classify_nan(a as u128, 23, 8);
+            let b = if is_nan {
+                float_convert_nan(a as u128, 23, 8, 52, 11) as u64
+            } else {
+                to_u64(mk_f32(a) as f64)
+            };
+
+            Some(
+                Extendsfdf2Except {
+                    a,
+                    b,
+                    flags: if is_signaling { INVALID } else { 0 },
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a},), ({b}, {flags})),",
+                a = self.a,
+                b = self.b,
+                flags = self.flags
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::extend::__extendsfdf2;
+use compiler_builtins::float::fenv::{ALL_EXCEPTIONS, clear_except, test_except};
+
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+fn to_u64(x: f64) -> u64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u32,), (u64, u32))] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn extendsfdf2_except() {
+    for &((a,), (b, flags)) in TEST_CASES {
+        clear_except(ALL_EXCEPTIONS);
+        let b_ = to_u64(__extendsfdf2(mk_f32(a)));
+        let flags_ = test_except(ALL_EXCEPTIONS);
+        assert_eq!(((a,), (b, flags)), ((a,), (b_, flags_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct FixdfsiExcept {
+        a: u64, // f64
+        b: i32,
+        flags: u32,
+    }
+
+    impl TestCase for FixdfsiExcept {
+        fn name() -> &'static str {
+            "fixdfsi_except"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            // Most random floats are out of range, so also try integers with
+            // a random fraction
+            let a = if rng.gen() {
+                gen_f64(rng)
+            } else {
+                gen_i32(rng) as f64 + rng.gen_range(-1.0, 1.0)
+            };
+            let t = a.trunc();
+            let (b, flags) = if a.is_nan() {
+                // NaNs saturate like infinities of the same sign
+                let negative = to_u64(a) >> 63 != 0;
+                (if negative { ::std::i32::MIN } else { ::std::i32::MAX }, INVALID)
+            } else if t < -2147483648.0 {
+                (::std::i32::MIN, INVALID)
+            } else if t >= 2147483648.0 {
+                (::std::i32::MAX, INVALID)
+            } else {
+                (t as i32, if t != a { INEXACT } else { 0 })
+            };
+
+            Some(
+                FixdfsiExcept {
+                    a: to_u64(a),
+                    b,
+                    flags,
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a},), ({b}, {flags})),",
+                a = self.a,
+                b = self.b,
+                flags = self.flags
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__fixdfsi;
+use compiler_builtins::float::fenv::{ALL_EXCEPTIONS, clear_except, test_except};
+
+fn mk_f64(x: u64) -> f64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u64,), (i32, u32))] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixdfsi_except() {
+    for &((a,), (b, flags)) in TEST_CASES {
+        clear_except(ALL_EXCEPTIONS);
+        let b_ = __fixdfsi(mk_f64(a));
+        let flags_ = test_except(ALL_EXCEPTIONS);
+        assert_eq!(((a,), (b, flags)), ((a,), (b_, flags_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct FixunssfdiExcept {
+        a: u32, // f32
+        b: u64,
+        flags: u32,
+    }
+
+    impl TestCase for FixunssfdiExcept {
+        fn name() -> &'static str {
+            "fixunssfdi_except"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            // Most random floats are out of range, so also try integers with
+            // a random fraction
+            let a = if rng.gen() {
+                gen_f32(rng)
+            } else {
+                gen_u64(rng) as f32 + rng.gen_range(-1.0, 1.0)
+            };
+            let t = a.trunc();
+            let (b, flags) = if a.is_nan() {
+                // NaNs saturate like infinities of the same sign
+                let negative = to_u32(a) >> 31 != 0;
+                (if negative { 0 } else { ::std::u64::MAX }, INVALID)
+            } else if t <= -1.0 {
+                // Negative integers are out of range
+                (0, INVALID)
+            } else if t >= 18446744073709551616.0 {
+                (::std::u64::MAX, INVALID)
+            } else {
+                (t as u64, if t != a { INEXACT } else { 0 })
+            };
+
+            Some(
+                FixunssfdiExcept {
+                    a: to_u32(a),
+                    b,
+                    flags,
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a},), ({b}, {flags})),",
+                a = self.a,
+                b = self.b,
+                flags = self.flags
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__fixunssfdi;
+use compiler_builtins::float::fenv::{ALL_EXCEPTIONS, clear_except, test_except};
+
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u32,), (u64, u32))] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixunssfdi_except() {
+    for &((a,), (b, flags)) in TEST_CASES {
+        clear_except(ALL_EXCEPTIONS);
+        let b_ = __fixunssfdi(mk_f32(a));
+        let flags_ = test_except(ALL_EXCEPTIONS);
+        assert_eq!(((a,), (b, flags)), ((a,), (b_, flags_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct FloatdidfExcept {
+        a: i64,
+        b: u64, // f64
+        flags: u32,
+    }
+
+    impl TestCase for FloatdidfExcept {
+        fn name() -> &'static str {
+            "floatdidf_except"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_i64(rng);
+            let (b, flags) = round_float_except(
+                a < 0,
+                a.wrapping_abs() as u64 as u128,
+                0,
+                false,
+                52,
+                11,
+                RoundingMode::ToNearest,
+            );
+
+            Some(
+                FloatdidfExcept {
+                    a,
+                    b: b as u64,
+                    flags,
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a},), ({b}, {flags})),",
+                a = self.a,
+                b = self.b,
+                flags = self.flags
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__floatdidf;
+use compiler_builtins::float::fenv::{ALL_EXCEPTIONS, clear_except, test_except};
+
+fn to_u64(x: f64) -> u64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((i64,), (u64, u32))] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn floatdidf_except() {
+    for &((a,), (b, flags)) in TEST_CASES {
+        clear_except(ALL_EXCEPTIONS);
+        let b_ = to_u64(__floatdidf(a));
+        let flags_ = test_except(ALL_EXCEPTIONS);
+        assert_eq!(((a,), (b, flags)), ((a,), (b_, flags_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct FloatdisfExcept {
+        a: i64,
+        b: u32, // f32
+        flags: u32,
+    }
+
+    impl TestCase for FloatdisfExcept {
+        fn name() -> &'static str {
+            "floatdisf_except"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_i64(rng);
+            let (b, flags) = round_float_except(
+                a < 0,
+                a.wrapping_abs() as u64 as u128,
+                0,
+                false,
+                23,
+                8,
+                RoundingMode::ToNearest,
+            );
+
+            Some(
+                FloatdisfExcept {
+                    a,
+                    b: b as u32,
+                    flags,
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a},), ({b}, {flags})),",
+                a = self.a,
+                b = self.b,
+                flags = self.flags
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__floatdisf;
+use compiler_builtins::float::fenv::{ALL_EXCEPTIONS, clear_except, test_except};
+
+fn to_u32(x: f32) -> u32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((i64,), (u32, u32))] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn floatdisf_except() {
+    for &((a,), (b, flags)) in TEST_CASES {
+        clear_except(ALL_EXCEPTIONS);
+        let b_ = to_u32(__floatdisf(a));
+        let flags_ = test_except(ALL_EXCEPTIONS);
+        assert_eq!(((a,), (b, flags)), ((a,), (b_, flags_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct FloatuntisfExcept {
+        a: u128,
+        b: u32, // f32
+        flags: u32,
+    }
+
+    impl TestCase for FloatuntisfExcept {
+        fn name() -> &'static str {
+            "floatuntisf_except"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_u128(rng);
+            let (b, flags) = round_float_except(
+                false,
+                a as u128,
+                0,
+                false,
+                23,
+                8,
+                RoundingMode::ToNearest,
+            );
+
+            Some(
+                FloatuntisfExcept {
+                    a,
+                    b: b as u32,
+                    flags,
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a},), ({b}, {flags})),",
+                a = self.a,
+                b = self.b,
+                flags = self.flags
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__floatuntisf;
+use compiler_builtins::float::fenv::{ALL_EXCEPTIONS, clear_except, test_except};
+
+fn to_u32(x: f32) -> u32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u128,), (u32, u32))] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn floatuntisf_except() {
+    for &((a,), (b, flags)) in TEST_CASES {
+        clear_except(ALL_EXCEPTIONS);
+        let b_ = to_u32(__floatuntisf(a));
+        let flags_ = test_except(ALL_EXCEPTIONS);
+        assert_eq!(((a,), (b, flags)), ((a,), (b_, flags_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Mulsf3Except {
+        a: u32, // f32
+        b: u32, // f32
+        c: u32, // f32
+        flags: u32,
+    }
+
+    impl TestCase for Mulsf3Except {
+        fn name() -> &'static str {
+            "mulsf3_except"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            // `gen_f32` mostly returns denormals, also try any bit pattern
+            let a = if rng.gen() { to_u32(gen_f32(rng)) } else { rng.gen() };
+            let b = if rng.gen() { to_u32(gen_f32(rng)) } else { rng.gen() };
+            let (c, flags) = float_mul(a as u128, b as u128, 23, 8);
+
+            Some(
+                Mulsf3Except {
+                    a,
+                    b,
+                    c: c as u32,
+                    flags,
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a}, {b}), ({c}, {flags})),",
+                a = self.a,
+                b = self.b,
+                c = self.c,
+                flags = self.flags
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::mul::__mulsf3;
+use compiler_builtins::float::fenv::{ALL_EXCEPTIONS, clear_except, test_except};
+
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+fn to_u32(x: f32) -> u32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u32, u32), (u32, u32))] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn mulsf3_except() {
+    for &((a, b), (c, flags)) in TEST_CASES {
+        clear_except(ALL_EXCEPTIONS);
+        let c_ = to_u32(__mulsf3(mk_f32(a), mk_f32(b)));
+        let flags_ = test_except(ALL_EXCEPTIONS);
+        assert_eq!(((a, b), (c, flags)), ((a, b), (c_, flags_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Powixf2Except {
+        a: u128, // f80
+        b: i32,
+        c: u128, // f80
+        flags: u32,
+    }
+
+    impl TestCase for Powixf2Except {
+        fn name() -> &'static str {
+            "powixf2_except"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f80(rng);
+            // Small exponents, most large ones overflow or underflow
+            let b = if rng.gen() { gen_i32(rng) } else { rng.gen_range(-64, 64) };
+            let (c, flags) = f80_powi(a, b);
+            let c = if f80_is_nan(c) { F80_NAN } else { c };
+
+            Some(
+                Powixf2Except {
+                    a,
+                    b,
+                    c,
+                    flags,
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a}, {b}), ({c}, {flags})),",
+                a = self.a,
+                b = self.b,
+                c = self.c,
+                flags = self.flags
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::f80;
+use compiler_builtins::float::pow::__rust_powixf2;
+use compiler_builtins::float::fenv::{ALL_EXCEPTIONS, clear_except, test_except};
+
+// Any NaN is accepted for a NaN result
+fn canonical(x: f80) -> u128 {
+    if x.is_nan() { 0xffff_c000_0000_0000_0000 } else { x.repr() }
+}
+
+static TEST_CASES: &[((u128, i32), (u128, u32))] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn powixf2_except() {
+    for &((a, b), (c, flags)) in TEST_CASES {
+        let mut c_ = f80::from_repr(0);
+        clear_except(ALL_EXCEPTIONS);
+        __rust_powixf2(&mut c_, &f80::from_repr(a), b);
+        let flags_ = test_except(ALL_EXCEPTIONS);
+        assert_eq!(((a, b), (c, flags)), ((a, b), (canonical(c_), flags_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Truncdfsf2Except {
+        a: u64, // f64
+        b: u32, // f32
+        flags: u32,
+    }
+
+    impl TestCase for Truncdfsf2Except {
+        fn name() -> &'static str {
+            "truncdfsf2_except"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            // `gen_f64` rarely returns signaling NaNs or numbers around the
+            // range of `f32`, so also try any bit pattern, any NaN, and `f32`s
+            // with random bits below their precision
+            let a = match rng.gen_range(0, 4) {
+                0 => to_u64(gen_f64(rng)),
+                1 => rng.gen(),
+                2 => rng.gen::<u64>() | 0x7ff0_0000_0000_0000,
+                _ => to_u64(mk_f32(rng.gen()) as f64) ^ rng.gen::<u64>() & 0x1fff_ffff,
+            };
+            let (is_nan, is_signaling) = float_classify_nan(a as u128, 52, 11);
+            let (b, flags) = if is_nan {
+                let b = float_convert_nan(a as u128, 52, 11, 23, 8);
+                (b, if is_signaling { INVALID } else { 0 })
+            } else if mk_f64(a).is_infinite() {
+                (to_u32(mk_f64(a) as f32) as u128, 0)
+            } else {
+                let (sign, significand, exponent) = float_unpack(a as u128, 52, 11);
+                let mode = RoundingMode::ToNearest;
+                round_float_except(sign, significand, exponent, false, 23, 8, mode)
+            };
+
+            Some(
+                Truncdfsf2Except {
+                    a,
+                    b: b as u32,
+                    flags,
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a},), ({b}, {flags})),",
+                a = self.a,
+                b = self.b,
+                flags = self.flags
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::trunc::__truncdfsf2;
+use compiler_builtins::float::fenv::{ALL_EXCEPTIONS, clear_except, test_except};
+
+fn mk_f64(x: u64) -> f64 {
+    unsafe { mem::transmute(x) }
+}
+
+fn to_u32(x: f32) -> u32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u64,), (u32, u32))] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn truncdfsf2_except() {
+    for &((a,), (b, flags)) in TEST_CASES {
+        clear_except(ALL_EXCEPTIONS);
+        let b_ = to_u32(__truncdfsf2(mk_f64(a)));
+        let flags_ = test_except(ALL_EXCEPTIONS);
+        assert_eq!(((a,), (b, flags)), ((a,), (b_, flags_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Addsf3FlushToZero {
+        a: u32, // f32
+        b: u32, // f32
+        c: u32, // f32
+    }
+
+    impl TestCase for Addsf3FlushToZero {
+        fn name() -> &'static str {
+            "addsf3_flush_to_zero"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            // `gen_f32` mostly returns denormals, also try any bit pattern
+            let a = if rng.gen() { to_u32(gen_f32(rng)) } else { rng.gen() };
+            let b = if rng.gen() { to_u32(gen_f32(rng)) } else { rng.gen() };
+            let c = float_flush_to_zero(
+                |a, b| float_add(a, b, 23, 8, RoundingMode::ToNearest),
+                a as u128,
+                b as u128,
+                23,
+                8,
+            );
+
+            Some(
+                Addsf3FlushToZero {
+                    a,
+                    b,
+                    c: c as u32,
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a}, {b}), {c}),",
+                a = self.a,
+                b = self.b,
+                c = self.c
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::fenv::set_flush_to_zero;
+use compiler_builtins::float::add::__addsf3;
+
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+fn to_u32(x: f32) -> u32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u32, u32), u32)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn addsf3_flush_to_zero() {
+    for &((a, b), c) in TEST_CASES {
+        set_flush_to_zero(true);
+        let c_ = __addsf3(mk_f32(a), mk_f32(b));
+        set_flush_to_zero(false);
+        assert_eq!(((a, b), c), ((a, b), to_u32(c_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Divsf3FlushToZero {
+        a: u32, // f32
+        b: u32, // f32
+        c: u32, // f32
+    }
+
+    impl TestCase for Divsf3FlushToZero {
+        fn name() -> &'static str {
+            "divsf3_flush_to_zero"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            // `gen_f32` mostly returns denormals, also try any bit pattern
+            let a = if rng.gen() { to_u32(gen_f32(rng)) } else { rng.gen() };
+            let b = if rng.gen() { to_u32(gen_f32(rng)) } else { rng.gen() };
+            let c = float_flush_to_zero(
+                |a, b| float_div(a, b, 23, 8),
+                a as u128,
+                b as u128,
+                23,
+                8,
+            );
+
+            Some(
+                Divsf3FlushToZero {
+                    a,
+                    b,
+                    c: c as u32,
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a}, {b}), {c}),",
+                a = self.a,
+                b = self.b,
+                c = self.c
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::fenv::set_flush_to_zero;
+use compiler_builtins::float::div::__divsf3;
+
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+fn to_u32(x: f32) -> u32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u32, u32), u32)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn divsf3_flush_to_zero() {
+    for &((a, b), c) in TEST_CASES {
+        set_flush_to_zero(true);
+        let c_ = __divsf3(mk_f32(a), mk_f32(b));
+        set_flush_to_zero(false);
+        assert_eq!(((a, b), c), ((a, b), to_u32(c_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Muldf3FlushToZero {
+        a: u64, // f64
+        b: u64, // f64
+        c: u64, // f64
+    }
+
+    impl TestCase for Muldf3FlushToZero {
+        fn name() -> &'static str {
+            "muldf3_flush_to_zero"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            // `gen_f64` mostly returns denormals, also try any bit pattern
+            let a = if rng.gen() { to_u64(gen_f64(rng)) } else { rng.gen() };
+            let b = if rng.gen() { to_u64(gen_f64(rng)) } else { rng.gen() };
+            let c = float_flush_to_zero(
+                |a, b| float_mul(a, b, 52, 11),
+                a as u128,
+                b as u128,
+                52,
+                11,
+            );
+
+            Some(
+                Muldf3FlushToZero {
+                    a,
+                    b,
+                    c: c as u64,
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a}, {b}), {c}),",
+                a = self.a,
+                b = self.b,
+                c = self.c
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::fenv::set_flush_to_zero;
+use compiler_builtins::float::mul::__muldf3;
+
+fn mk_f64(x: u64) -> f64 {
+    unsafe { mem::transmute(x) }
+}
+
+fn to_u64(x: f64) -> u64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u64, u64), u64)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn muldf3_flush_to_zero() {
+    for &((a, b), c) in TEST_CASES {
+        set_flush_to_zero(true);
+        let c_ = __muldf3(mk_f64(a), mk_f64(b));
+        set_flush_to_zero(false);
+        assert_eq!(((a, b), c), ((a, b), to_u64(c_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct FloattisfRounding {
+        mode: RoundingMode,
+        a: i128,
+        b: u32, // f32
+    }
+
+    impl TestCase for FloattisfRounding {
+        fn name() -> &'static str {
+            "floattisf_rounding"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let mode = gen_rounding_mode(rng);
+            let a = gen_i128(rng);
+            let b = round_float(a < 0, a.wrapping_abs() as u128, 0, false, 23, 8, mode) as u32;
+            Some(FloattisfRounding { mode, a, b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "((RoundingMode::{mode:?}, {a}), {b}),",
+                mode = self.mode,
+                a = self.a,
+                b = self.b
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__floattisf;
+use compiler_builtins::float::fenv::{RoundingMode, set_rounding_mode};
+
+fn to_u32(x: f32) -> u32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((RoundingMode, i128), u32)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn floattisf_rounding() {
+    for &((mode, a), b) in TEST_CASES {
+        set_rounding_mode(mode);
+        let b_ = __floattisf(a);
+        set_rounding_mode(RoundingMode::ToNearest);
+        assert_eq!(((mode, a), b), ((mode, a), to_u32(b_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct FloatuntidfRounding {
+        mode: RoundingMode,
+        a: u128,
+        b: u64, // f64
+    }
+
+    impl TestCase for FloatuntidfRounding {
+        fn name() -> &'static str {
+            "floatuntidf_rounding"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let mode = gen_rounding_mode(rng);
+            let a = gen_u128(rng);
+            let b = round_float(false, a, 0, false, 52, 11, mode) as u64;
+            Some(FloatuntidfRounding { mode, a, b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "((RoundingMode::{mode:?}, {a}), {b}),",
+                mode = self.mode,
+                a = self.a,
+                b = self.b
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__floatuntidf;
+use compiler_builtins::float::fenv::{RoundingMode, set_rounding_mode};
+
+fn to_u64(x: f64) -> u64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((RoundingMode, u128), u64)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn floatuntidf_rounding() {
+    for &((mode, a), b) in TEST_CASES {
+        set_rounding_mode(mode);
+        let b_ = __floatuntidf(a);
+        set_rounding_mode(RoundingMode::ToNearest);
+        assert_eq!(((mode, a), b), ((mode, a), to_u64(b_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct FloattixfRounding {
+        mode: RoundingMode,
+        a: i128,
+        b: u128, // f80
+    }
+
+    impl TestCase for FloattixfRounding {
+        fn name() -> &'static str {
+            "floattixf_rounding"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let mode = gen_rounding_mode(rng);
+            let a = gen_i128(rng);
+            let b = f80_round(a < 0, a.wrapping_abs() as u128, 0, false, mode);
+            Some(FloattixfRounding { mode, a, b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "((RoundingMode::{mode:?}, {a}), {b}),",
+                mode = self.mode,
+                a = self.a,
+                b = self.b
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::f80;
+use compiler_builtins::float::conv::__rust_floattixf;
+use compiler_builtins::float::fenv::{RoundingMode, set_rounding_mode};
+
+static TEST_CASES: &[((RoundingMode, i128), u128)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn floattixf_rounding() {
+    for &((mode, a), b) in TEST_CASES {
+        let mut b_ = f80::from_repr(0);
+        set_rounding_mode(mode);
+        __rust_floattixf(&mut b_, a);
+        set_rounding_mode(RoundingMode::ToNearest);
+        let b_ = b_.repr();
+        assert_eq!(((mode, a), b), ((mode, a), b_));
+    }
+}
+"
+        }
+    }
+
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Fixdfdi {
+        a: u64,  // f64
+        b: i64,
+    }
+
+    impl TestCase for Fixdfdi {
+        fn name() -> &'static str {
+            "fixdfdi"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f64(rng);
+            i64(a).ok().map(|b| Fixdfdi { a: to_u64(a), b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__fixdfdi;
+
+fn mk_f64(x: u64) -> f64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u64,), i64)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixdfdi() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __fixdfdi(mk_f64(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Fixdfsi {
+        a: u64,  // f64
+        b: i32,
+    }
+
+    impl TestCase for Fixdfsi {
+        fn name() -> &'static str {
+            "fixdfsi"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f64(rng);
+            i32(a).ok().map(|b| Fixdfsi { a: to_u64(a), b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__fixdfsi;
+
+fn mk_f64(x: u64) -> f64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u64,), i32)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixdfdi() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __fixdfsi(mk_f64(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Fixsfdi {
+        a: u32,  // f32
+        b: i64,
+    }
+
+    impl TestCase for Fixsfdi {
+        fn name() -> &'static str {
+            "fixsfdi"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f32(rng);
+            i64(a).ok().map(|b| Fixsfdi { a: to_u32(a), b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__fixsfdi;
+
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u32,), i64)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixsfdi() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __fixsfdi(mk_f32(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Fixsfsi {
+        a: u32,  // f32
+        b: i32,
+    }
+
+    impl TestCase for Fixsfsi {
+        fn name() -> &'static str {
+            "fixsfsi"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f32(rng);
+            i32(a).ok().map(|b| Fixsfsi { a: to_u32(a), b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__fixsfsi;
+
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u32,), i32)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixsfsi() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __fixsfsi(mk_f32(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Fixsfti {
+        a: u32,  // f32
+        b: i128,
+    }
+
+    impl TestCase for Fixsfti {
+        fn name() -> &'static str {
+            "fixsfti"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f32(rng);
+            i128(a).ok().map(|b| Fixsfti { a: to_u32(a), b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__fixsfti;
+
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u32,), i128)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixsfti() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __fixsfti(mk_f32(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Fixdfti {
+        a: u64,  // f64
+        b: i128,
+    }
+
+    impl TestCase for Fixdfti {
+        fn name() -> &'static str {
+            "fixdfti"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f64(rng);
+            i128(a).ok().map(|b| Fixdfti { a: to_u64(a), b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__fixdfti;
+
+fn mk_f64(x: u64) -> f64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u64,), i128)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixdfti() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __fixdfti(mk_f64(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Fixtfdi {
+        a: u128, // f128,
+        b: i64,
+    }
+
+    impl TestCase for Fixtfdi {
+        fn name() -> &'static str {
+            "fixtfdi"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f128(rng);
+            f128_to_i128(a).and_then(|b| i64(b).ok()).map(|b| Fixtfdi { a, b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::{Float, f128};
+use compiler_builtins::float::conv::__fixtfdi;
+static TEST_CASES: &[((u128,), i64)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixtfdi() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __fixtfdi(f128::from_repr(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Fixtfsi {
+        a: u128, // f128,
+        b: i32,
+    }
+
+    impl TestCase for Fixtfsi {
+        fn name() -> &'static str {
+            "fixtfsi"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f128(rng);
+            f128_to_i128(a).and_then(|b| i32(b).ok()).map(|b| Fixtfsi { a, b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::{Float, f128};
+use compiler_builtins::float::conv::__fixtfsi;
+static TEST_CASES: &[((u128,), i32)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixtfsi() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __fixtfsi(f128::from_repr(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Fixtfti {
+        a: u128, // f128,
+        b: i128,
+    }
+
+    impl TestCase for Fixtfti {
+        fn name() -> &'static str {
+            "fixtfti"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f128(rng);
+            f128_to_i128(a).map(|b| Fixtfti { a, b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::{Float, f128};
+use compiler_builtins::float::conv::__fixtfti;
+static TEST_CASES: &[((u128,), i128)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixtfti() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __fixtfti(f128::from_repr(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Fixxfdi {
+        a: u128, // f80,
+        b: i64,
+    }
+
+    impl TestCase for Fixxfdi {
+        fn name() -> &'static str {
+            "fixxfdi"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f80(rng);
+            f80_to_i128(a).and_then(|b| i64(b).ok()).map(|b| Fixxfdi { a, b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::f80;
+use compiler_builtins::float::conv::__rust_fixxfdi;
+static TEST_CASES: &[((u128,), i64)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixxfdi() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __rust_fixxfdi(&f80::from_repr(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Fixxfti {
+        a: u128, // f80,
+        b: i128,
+    }
+
+    impl TestCase for Fixxfti {
+        fn name() -> &'static str {
+            "fixxfti"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f80(rng);
+            f80_to_i128(a).map(|b| Fixxfti { a, b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::f80;
+use compiler_builtins::float::conv::__rust_fixxfti;
+static TEST_CASES: &[((u128,), i128)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixxfti() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __rust_fixxfti(&f80::from_repr(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Fixunsdfdi {
+        a: u64,  // f64
+        b: u64,
+    }
+
+    impl TestCase for Fixunsdfdi {
+        fn name() -> &'static str {
+            "fixunsdfdi"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f64(rng);
+            u64(a).ok().map(|b| Fixunsdfdi { a: to_u64(a), b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__fixunsdfdi;
+
+fn mk_f64(x: u64) -> f64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u64,), u64)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixunsdfdi() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __fixunsdfdi(mk_f64(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Fixunsdfsi {
+        a: u64,  // f64
+        b: u32,
+    }
+
+    impl TestCase for Fixunsdfsi {
+        fn name() -> &'static str {
+            "fixunsdfsi"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f64(rng);
+            u32(a).ok().map(|b| Fixunsdfsi { a: to_u64(a), b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__fixunsdfsi;
+
+fn mk_f64(x: u64) -> f64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u64,), u32)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixunsdfdi() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __fixunsdfsi(mk_f64(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Fixunssfdi {
+        a: u32,  // f32
+        b: u64,
+    }
+
+    impl TestCase for Fixunssfdi {
+        fn name() -> &'static str {
+            "fixunssfdi"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f32(rng);
+            u64(a).ok().map(|b| Fixunssfdi { a: to_u32(a), b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__fixunssfdi;
+
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u32,), u64)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixunssfdi() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __fixunssfdi(mk_f32(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Fixunssfsi {
+        a: u32,  // f32
+        b: u32,
+    }
+
+    impl TestCase for Fixunssfsi {
+        fn name() -> &'static str {
+            "fixunssfsi"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f32(rng);
+            u32(a).ok().map(|b| Fixunssfsi { a: to_u32(a), b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__fixunssfsi;
+
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u32,), u32)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixunssfsi() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __fixunssfsi(mk_f32(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Fixunssfti {
+        a: u32,  // f32
+        b: u128,
+    }
+
+    impl TestCase for Fixunssfti {
+        fn name() -> &'static str {
+            "fixunssfti"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f32(rng);
+            u128(a).ok().map(|b| Fixunssfti { a: to_u32(a), b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__fixunssfti;
+
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u32,), u128)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixunssfti() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __fixunssfti(mk_f32(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Fixunsdfti  {
+        a: u64,  // f64
+        b: u128,
+    }
+
+    impl TestCase for Fixunsdfti {
+        fn name() -> &'static str {
+            "fixunsdfti"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f64(rng);
+            u128(a).ok().map(|b| Fixunsdfti { a: to_u64(a), b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__fixunsdfti;
+
+fn mk_f64(x: u64) -> f64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u64,), u128)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixunsdfti() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __fixunsdfti(mk_f64(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Fixunstfdi {
+        a: u128, // f128,
+        b: u64,
+    }
+
+    impl TestCase for Fixunstfdi {
+        fn name() -> &'static str {
+            "fixunstfdi"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f128(rng);
+            f128_to_u128(a).and_then(|b| u64(b).ok()).map(|b| Fixunstfdi { a, b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::{Float, f128};
+use compiler_builtins::float::conv::__fixunstfdi;
+static TEST_CASES: &[((u128,), u64)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixunstfdi() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __fixunstfdi(f128::from_repr(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Fixunstfsi {
+        a: u128, // f128,
+        b: u32,
+    }
+
+    impl TestCase for Fixunstfsi {
+        fn name() -> &'static str {
+            "fixunstfsi"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f128(rng);
+            f128_to_u128(a).and_then(|b| u32(b).ok()).map(|b| Fixunstfsi { a, b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::{Float, f128};
+use compiler_builtins::float::conv::__fixunstfsi;
+static TEST_CASES: &[((u128,), u32)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixunstfsi() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __fixunstfsi(f128::from_repr(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Fixunstfti {
+        a: u128, // f128,
+        b: u128,
+    }
+
+    impl TestCase for Fixunstfti {
+        fn name() -> &'static str {
+            "fixunstfti"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f128(rng);
+            f128_to_u128(a).map(|b| Fixunstfti { a, b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::{Float, f128};
+use compiler_builtins::float::conv::__fixunstfti;
+static TEST_CASES: &[((u128,), u128)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixunstfti() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __fixunstfti(f128::from_repr(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Fixunsxfdi {
+        a: u128, // f80,
+        b: u64,
+    }
+
+    impl TestCase for Fixunsxfdi {
+        fn name() -> &'static str {
+            "fixunsxfdi"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f80(rng);
+            f80_to_u128(a).and_then(|b| u64(b).ok()).map(|b| Fixunsxfdi { a, b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::f80;
+use compiler_builtins::float::conv::__rust_fixunsxfdi;
+static TEST_CASES: &[((u128,), u64)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixunsxfdi() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __rust_fixunsxfdi(&f80::from_repr(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Fixunsxfsi {
+        a: u128, // f80,
+        b: u32,
+    }
+
+    impl TestCase for Fixunsxfsi {
+        fn name() -> &'static str {
+            "fixunsxfsi"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f80(rng);
+            f80_to_u128(a).and_then(|b| u32(b).ok()).map(|b| Fixunsxfsi { a, b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::f80;
+use compiler_builtins::float::conv::__rust_fixunsxfsi;
+static TEST_CASES: &[((u128,), u32)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixunsxfsi() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __rust_fixunsxfsi(&f80::from_repr(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Fixunsxfti {
+        a: u128, // f80,
+        b: u128,
+    }
+
+    impl TestCase for Fixunsxfti {
+        fn name() -> &'static str {
+            "fixunsxfti"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f80(rng);
+            f80_to_u128(a).map(|b| Fixunsxfti { a, b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::f80;
+use compiler_builtins::float::conv::__rust_fixunsxfti;
+static TEST_CASES: &[((u128,), u128)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixunsxfti() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __rust_fixunsxfti(&f80::from_repr(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct FixsfsiSat {
+        a: u32, // f32
+        b: i32,
+    }
+
+    impl TestCase for FixsfsiSat {
+        fn name() -> &'static str {
+            "fixsfsi_sat"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = match rng.gen_range(0, 3) {
+                0 => gen_f32(rng),
+                // Any bit pattern, including infinities and NaNs
+                1 => mk_f32(rng.gen()),
+                // A magnitude from 1 to out of range of the integer type
+                _ => {
+                    let exponent = rng.gen_range(127, 256);
+                    mk_f32(rng.gen::<u32>() & 0x807f_ffff | exponent << 23)
+                }
+            };
+            // `as` saturates and converts NaN to 0
+            let b = a as i32;
+
+            Some(FixsfsiSat { a: to_u32(a), b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__rust_fixsfsi_sat;
+
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u32,), i32)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixsfsi_sat() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __rust_fixsfsi_sat(mk_f32(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct FixsfdiSat {
+        a: u32, // f32
+        b: i64,
+    }
+
+    impl TestCase for FixsfdiSat {
+        fn name() -> &'static str {
+            "fixsfdi_sat"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = match rng.gen_range(0, 3) {
+                0 => gen_f32(rng),
+                // Any bit pattern, including infinities and NaNs
+                1 => mk_f32(rng.gen()),
+                // A magnitude from 1 to out of range of the integer type
+                _ => {
+                    let exponent = rng.gen_range(127, 256);
+                    mk_f32(rng.gen::<u32>() & 0x807f_ffff | exponent << 23)
+                }
+            };
+            // `as` saturates and converts NaN to 0
+            let b = a as i64;
+
+            Some(FixsfdiSat { a: to_u32(a), b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__rust_fixsfdi_sat;
+
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u32,), i64)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixsfdi_sat() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __rust_fixsfdi_sat(mk_f32(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct FixsftiSat {
+        a: u32, // f32
+        b: i128,
+    }
+
+    impl TestCase for FixsftiSat {
+        fn name() -> &'static str {
+            "fixsfti_sat"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = match rng.gen_range(0, 3) {
+                0 => gen_f32(rng),
+                // Any bit pattern, including infinities and NaNs
+                1 => mk_f32(rng.gen()),
+                // A magnitude from 1 to out of range of the integer type
+                _ => {
+                    let exponent = rng.gen_range(127, 256);
+                    mk_f32(rng.gen::<u32>() & 0x807f_ffff | exponent << 23)
+                }
+            };
+            // `as` saturates and converts NaN to 0
+            let b = a as i128;
+
+            Some(FixsftiSat { a: to_u32(a), b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__rust_fixsfti_sat;
+
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u32,), i128)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixsfti_sat() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __rust_fixsfti_sat(mk_f32(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct FixunssfsiSat {
+        a: u32, // f32
+        b: u32,
+    }
+
+    impl TestCase for FixunssfsiSat {
+        fn name() -> &'static str {
+            "fixunssfsi_sat"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = match rng.gen_range(0, 3) {
+                0 => gen_f32(rng),
+                // Any bit pattern, including infinities and NaNs
+                1 => mk_f32(rng.gen()),
+                // A magnitude from 1 to out of range of the integer type
+                _ => {
+                    let exponent = rng.gen_range(127, 256);
+                    mk_f32(rng.gen::<u32>() & 0x807f_ffff | exponent << 23)
+                }
+            };
+            // `as` saturates and converts NaN to 0
+            let b = a as u32;
+
+            Some(FixunssfsiSat { a: to_u32(a), b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__rust_fixunssfsi_sat;
+
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u32,), u32)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixunssfsi_sat() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __rust_fixunssfsi_sat(mk_f32(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct FixunssfdiSat {
+        a: u32, // f32
+        b: u64,
+    }
+
+    impl TestCase for FixunssfdiSat {
+        fn name() -> &'static str {
+            "fixunssfdi_sat"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = match rng.gen_range(0, 3) {
+                0 => gen_f32(rng),
+                // Any bit pattern, including infinities and NaNs
+                1 => mk_f32(rng.gen()),
+                // A magnitude from 1 to out of range of the integer type
+                _ => {
+                    let exponent = rng.gen_range(127, 256);
+                    mk_f32(rng.gen::<u32>() & 0x807f_ffff | exponent << 23)
+                }
+            };
+            // `as` saturates and converts NaN to 0
+            let b = a as u64;
+
+            Some(FixunssfdiSat { a: to_u32(a), b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__rust_fixunssfdi_sat;
+
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u32,), u64)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixunssfdi_sat() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __rust_fixunssfdi_sat(mk_f32(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct FixunssftiSat {
+        a: u32, // f32
+        b: u128,
+    }
+
+    impl TestCase for FixunssftiSat {
+        fn name() -> &'static str {
+            "fixunssfti_sat"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = match rng.gen_range(0, 3) {
+                0 => gen_f32(rng),
+                // Any bit pattern, including infinities and NaNs
+                1 => mk_f32(rng.gen()),
+                // A magnitude from 1 to out of range of the integer type
+                _ => {
+                    let exponent = rng.gen_range(127, 256);
+                    mk_f32(rng.gen::<u32>() & 0x807f_ffff | exponent << 23)
+                }
+            };
+            // `as` saturates and converts NaN to 0
+            let b = a as u128;
+
+            Some(FixunssftiSat { a: to_u32(a), b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__rust_fixunssfti_sat;
+
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u32,), u128)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixunssfti_sat() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __rust_fixunssfti_sat(mk_f32(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct FixdfsiSat {
+        a: u64, // f64
+        b: i32,
+    }
+
+    impl TestCase for FixdfsiSat {
+        fn name() -> &'static str {
+            "fixdfsi_sat"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = match rng.gen_range(0, 3) {
+                0 => gen_f64(rng),
+                // Any bit pattern, including infinities and NaNs
+                1 => mk_f64(rng.gen()),
+                // A magnitude from 1 to out of range of the integer type
+                _ => {
+                    let exponent = rng.gen_range(1023, 1153);
+                    mk_f64(rng.gen::<u64>() & 0x800f_ffff_ffff_ffff | exponent << 52)
+                }
+            };
+            // `as` saturates and converts NaN to 0
+            let b = a as i32;
+
+            Some(FixdfsiSat { a: to_u64(a), b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__rust_fixdfsi_sat;
+
+fn mk_f64(x: u64) -> f64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u64,), i32)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixdfsi_sat() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __rust_fixdfsi_sat(mk_f64(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct FixdfdiSat {
+        a: u64, // f64
+        b: i64,
+    }
+
+    impl TestCase for FixdfdiSat {
+        fn name() -> &'static str {
+            "fixdfdi_sat"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = match rng.gen_range(0, 3) {
+                0 => gen_f64(rng),
+                // Any bit pattern, including infinities and NaNs
+                1 => mk_f64(rng.gen()),
+                // A magnitude from 1 to out of range of the integer type
+                _ => {
+                    let exponent = rng.gen_range(1023, 1153);
+                    mk_f64(rng.gen::<u64>() & 0x800f_ffff_ffff_ffff | exponent << 52)
+                }
+            };
+            // `as` saturates and converts NaN to 0
+            let b = a as i64;
+
+            Some(FixdfdiSat { a: to_u64(a), b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__rust_fixdfdi_sat;
+
+fn mk_f64(x: u64) -> f64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u64,), i64)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixdfdi_sat() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __rust_fixdfdi_sat(mk_f64(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct FixdftiSat {
+        a: u64, // f64
+        b: i128,
+    }
+
+    impl TestCase for FixdftiSat {
+        fn name() -> &'static str {
+            "fixdfti_sat"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = match rng.gen_range(0, 3) {
+                0 => gen_f64(rng),
+                // Any bit pattern, including infinities and NaNs
+                1 => mk_f64(rng.gen()),
+                // A magnitude from 1 to out of range of the integer type
+                _ => {
+                    let exponent = rng.gen_range(1023, 1153);
+                    mk_f64(rng.gen::<u64>() & 0x800f_ffff_ffff_ffff | exponent << 52)
+                }
+            };
+            // `as` saturates and converts NaN to 0
+            let b = a as i128;
+
+            Some(FixdftiSat { a: to_u64(a), b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__rust_fixdfti_sat;
+
+fn mk_f64(x: u64) -> f64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u64,), i128)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixdfti_sat() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __rust_fixdfti_sat(mk_f64(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct FixunsdfsiSat {
+        a: u64, // f64
+        b: u32,
+    }
+
+    impl TestCase for FixunsdfsiSat {
+        fn name() -> &'static str {
+            "fixunsdfsi_sat"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = match rng.gen_range(0, 3) {
+                0 => gen_f64(rng),
+                // Any bit pattern, including infinities and NaNs
+                1 => mk_f64(rng.gen()),
+                // A magnitude from 1 to out of range of the integer type
+                _ => {
+                    let exponent = rng.gen_range(1023, 1153);
+                    mk_f64(rng.gen::<u64>() & 0x800f_ffff_ffff_ffff | exponent << 52)
+                }
+            };
+            // `as` saturates and converts NaN to 0
+            let b = a as u32;
+
+            Some(FixunsdfsiSat { a: to_u64(a), b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__rust_fixunsdfsi_sat;
+
+fn mk_f64(x: u64) -> f64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u64,), u32)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixunsdfsi_sat() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __rust_fixunsdfsi_sat(mk_f64(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct FixunsdfdiSat {
+        a: u64, // f64
+        b: u64,
+    }
+
+    impl TestCase for FixunsdfdiSat {
+        fn name() -> &'static str {
+            "fixunsdfdi_sat"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = match rng.gen_range(0, 3) {
+                0 => gen_f64(rng),
+                // Any bit pattern, including infinities and NaNs
+                1 => mk_f64(rng.gen()),
+                // A magnitude from 1 to out of range of the integer type
+                _ => {
+                    let exponent = rng.gen_range(1023, 1153);
+                    mk_f64(rng.gen::<u64>() & 0x800f_ffff_ffff_ffff | exponent << 52)
+                }
+            };
+            // `as` saturates and converts NaN to 0
+            let b = a as u64;
+
+            Some(FixunsdfdiSat { a: to_u64(a), b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__rust_fixunsdfdi_sat;
+
+fn mk_f64(x: u64) -> f64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u64,), u64)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixunsdfdi_sat() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __rust_fixunsdfdi_sat(mk_f64(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct FixunsdftiSat {
+        a: u64, // f64
+        b: u128,
+    }
+
+    impl TestCase for FixunsdftiSat {
+        fn name() -> &'static str {
+            "fixunsdfti_sat"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = match rng.gen_range(0, 3) {
+                0 => gen_f64(rng),
+                // Any bit pattern, including infinities and NaNs
+                1 => mk_f64(rng.gen()),
+                // A magnitude from 1 to out of range of the integer type
+                _ => {
+                    let exponent = rng.gen_range(1023, 1153);
+                    mk_f64(rng.gen::<u64>() & 0x800f_ffff_ffff_ffff | exponent << 52)
+                }
+            };
+            // `as` saturates and converts NaN to 0
+            let b = a as u128;
+
+            Some(FixunsdftiSat { a: to_u64(a), b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__rust_fixunsdfti_sat;
+
+fn mk_f64(x: u64) -> f64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u64,), u128)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixunsdfti_sat() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __rust_fixunsdfti_sat(mk_f64(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct FixtfsiSat {
+        a: u128, // f128
+        b: i32,
+    }
+
+    impl TestCase for FixtfsiSat {
+        fn name() -> &'static str {
+            "fixtfsi_sat"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f128(rng);
+            let b = f128_to_int_sat(a, true, 32) as i32;
+
+            Some(FixtfsiSat { a, b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::{Float, f128};
+use compiler_builtins::float::conv::__rust_fixtfsi_sat;
+
+static TEST_CASES: &[((u128,), i32)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixtfsi_sat() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __rust_fixtfsi_sat(f128::from_repr(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct FixtfdiSat {
+        a: u128, // f128
+        b: i64,
+    }
+
+    impl TestCase for FixtfdiSat {
+        fn name() -> &'static str {
+            "fixtfdi_sat"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f128(rng);
+            let b = f128_to_int_sat(a, true, 64) as i64;
+
+            Some(FixtfdiSat { a, b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::{Float, f128};
+use compiler_builtins::float::conv::__rust_fixtfdi_sat;
+
+static TEST_CASES: &[((u128,), i64)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixtfdi_sat() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __rust_fixtfdi_sat(f128::from_repr(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct FixtftiSat {
+        a: u128, // f128
+        b: i128,
+    }
+
+    impl TestCase for FixtftiSat {
+        fn name() -> &'static str {
+            "fixtfti_sat"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f128(rng);
+            let b = f128_to_int_sat(a, true, 128) as i128;
+
+            Some(FixtftiSat { a, b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::{Float, f128};
+use compiler_builtins::float::conv::__rust_fixtfti_sat;
+
+static TEST_CASES: &[((u128,), i128)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixtfti_sat() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __rust_fixtfti_sat(f128::from_repr(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct FixunstfsiSat {
+        a: u128, // f128
+        b: u32,
+    }
+
+    impl TestCase for FixunstfsiSat {
+        fn name() -> &'static str {
+            "fixunstfsi_sat"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f128(rng);
+            let b = f128_to_int_sat(a, false, 32) as u32;
+
+            Some(FixunstfsiSat { a, b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::{Float, f128};
+use compiler_builtins::float::conv::__rust_fixunstfsi_sat;
+
+static TEST_CASES: &[((u128,), u32)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixunstfsi_sat() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __rust_fixunstfsi_sat(f128::from_repr(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct FixunstfdiSat {
+        a: u128, // f128
+        b: u64,
+    }
+
+    impl TestCase for FixunstfdiSat {
+        fn name() -> &'static str {
+            "fixunstfdi_sat"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f128(rng);
+            let b = f128_to_int_sat(a, false, 64) as u64;
+
+            Some(FixunstfdiSat { a, b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::{Float, f128};
+use compiler_builtins::float::conv::__rust_fixunstfdi_sat;
+
+static TEST_CASES: &[((u128,), u64)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixunstfdi_sat() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __rust_fixunstfdi_sat(f128::from_repr(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct FixunstftiSat {
+        a: u128, // f128
+        b: u128,
+    }
+
+    impl TestCase for FixunstftiSat {
+        fn name() -> &'static str {
+            "fixunstfti_sat"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f128(rng);
+            let b = f128_to_int_sat(a, false, 128) as u128;
+
+            Some(FixunstftiSat { a, b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::{Float, f128};
+use compiler_builtins::float::conv::__rust_fixunstfti_sat;
+
+static TEST_CASES: &[((u128,), u128)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixunstfti_sat() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __rust_fixunstfti_sat(f128::from_repr(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct FixxfsiSat {
+        a: u128, // f80
+        b: i32,
+    }
+
+    impl TestCase for FixxfsiSat {
+        fn name() -> &'static str {
+            "fixxfsi_sat"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f80(rng);
+            let b = f80_to_int_sat(a, true, 32) as i32;
+
+            Some(FixxfsiSat { a, b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::f80;
+use compiler_builtins::float::conv::__rust_fixxfsi_sat;
+
+static TEST_CASES: &[((u128,), i32)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixxfsi_sat() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __rust_fixxfsi_sat(&f80::from_repr(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct FixxfdiSat {
+        a: u128, // f80
+        b: i64,
+    }
+
+    impl TestCase for FixxfdiSat {
+        fn name() -> &'static str {
+            "fixxfdi_sat"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f80(rng);
+            let b = f80_to_int_sat(a, true, 64) as i64;
+
+            Some(FixxfdiSat { a, b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::f80;
+use compiler_builtins::float::conv::__rust_fixxfdi_sat;
+
+static TEST_CASES: &[((u128,), i64)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixxfdi_sat() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __rust_fixxfdi_sat(&f80::from_repr(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct FixxftiSat {
+        a: u128, // f80
+        b: i128,
+    }
+
+    impl TestCase for FixxftiSat {
+        fn name() -> &'static str {
+            "fixxfti_sat"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f80(rng);
+            let b = f80_to_int_sat(a, true, 128) as i128;
+
+            Some(FixxftiSat { a, b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::f80;
+use compiler_builtins::float::conv::__rust_fixxfti_sat;
+
+static TEST_CASES: &[((u128,), i128)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixxfti_sat() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __rust_fixxfti_sat(&f80::from_repr(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct FixunsxfsiSat {
+        a: u128, // f80
+        b: u32,
+    }
+
+    impl TestCase for FixunsxfsiSat {
+        fn name() -> &'static str {
+            "fixunsxfsi_sat"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f80(rng);
+            let b = f80_to_int_sat(a, false, 32) as u32;
+
+            Some(FixunsxfsiSat { a, b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::f80;
+use compiler_builtins::float::conv::__rust_fixunsxfsi_sat;
+
+static TEST_CASES: &[((u128,), u32)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixunsxfsi_sat() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __rust_fixunsxfsi_sat(&f80::from_repr(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct FixunsxfdiSat {
+        a: u128, // f80
+        b: u64,
+    }
+
+    impl TestCase for FixunsxfdiSat {
+        fn name() -> &'static str {
+            "fixunsxfdi_sat"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f80(rng);
+            let b = f80_to_int_sat(a, false, 64) as u64;
+
+            Some(FixunsxfdiSat { a, b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::f80;
+use compiler_builtins::float::conv::__rust_fixunsxfdi_sat;
+
+static TEST_CASES: &[((u128,), u64)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixunsxfdi_sat() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __rust_fixunsxfdi_sat(&f80::from_repr(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct FixunsxftiSat {
+        a: u128, // f80
+        b: u128,
+    }
+
+    impl TestCase for FixunsxftiSat {
+        fn name() -> &'static str {
+            "fixunsxfti_sat"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f80(rng);
+            let b = f80_to_int_sat(a, false, 128) as u128;
+
+            Some(FixunsxftiSat { a, b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::f80;
+use compiler_builtins::float::conv::__rust_fixunsxfti_sat;
+
+static TEST_CASES: &[((u128,), u128)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn fixunsxfti_sat() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __rust_fixunsxfti_sat(&f80::from_repr(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Llroundf {
+        a: u32, // f32
+        b: i64,
+    }
+
+    impl TestCase for Llroundf {
+        fn name() -> &'static str {
+            "llroundf"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = match rng.gen_range(0, 4) {
+                0 => gen_f32(rng),
+                // Any bit pattern, including infinities and NaNs
+                1 => mk_f32(rng.gen()),
+                // Halfway between two integers
+                2 => rng.gen_range(-64, 64) as f32 + 0.5,
+                // A magnitude from 1/8 to out of range of an `i64`
+                _ => {
+                    let exponent = rng.gen_range(127 - 3, 127 + 66);
+                    mk_f32(rng.gen::<u32>() & 0x807f_ffff | exponent << 23)
+                }
+            };
+            // `round` rounds ties away from zero
+            let b = f64_to_i64((a as f64).round());
+
+            Some(Llroundf { a: to_u32(a), b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv;
+
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u32,), i64)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn llroundf() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = conv::llroundf(mk_f32(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Llround {
+        a: u64, // f64
+        b: i64,
+    }
+
+    impl TestCase for Llround {
+        fn name() -> &'static str {
+            "llround"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = match rng.gen_range(0, 4) {
+                0 => gen_f64(rng),
+                // Any bit pattern, including infinities and NaNs
+                1 => mk_f64(rng.gen()),
+                // Halfway between two integers
+                2 => rng.gen_range(-64, 64) as f64 + 0.5,
+                // A magnitude from 1/8 to out of range of an `i64`
+                _ => {
+                    let exponent = rng.gen_range(1023 - 3, 1023 + 66);
+                    mk_f64(rng.gen::<u64>() & 0x800f_ffff_ffff_ffff | exponent << 52)
+                }
+            };
+            // `round` rounds ties away from zero
+            let b = f64_to_i64((a as f64).round());
+
+            Some(Llround { a: to_u64(a), b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv;
+
+fn mk_f64(x: u64) -> f64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u64,), i64)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn llround() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = conv::llround(mk_f64(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Llrintf {
+        mode: RoundingMode,
+        a: u32, // f32
+        b: i64,
+    }
+
+    impl TestCase for Llrintf {
+        fn name() -> &'static str {
+            "llrintf"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let mode = gen_rounding_mode(rng);
+            let a = match rng.gen_range(0, 4) {
+                0 => gen_f32(rng),
+                // Any bit pattern, including infinities and NaNs
+                1 => mk_f32(rng.gen()),
+                // Halfway between two integers
+                2 => rng.gen_range(-64, 64) as f32 + 0.5,
+                // A magnitude from 1/8 to out of range of an `i64`
+                _ => {
+                    let exponent = rng.gen_range(127 - 3, 127 + 66);
+                    mk_f32(rng.gen::<u32>() & 0x807f_ffff | exponent << 23)
+                }
+            };
+            let b = f64_to_i64(f64_round_integral(a as f64, mode));
+
+            Some(Llrintf { mode, a: to_u32(a), b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "((RoundingMode::{mode:?}, {a}), {b}),",
+                mode = self.mode,
+                a = self.a,
+                b = self.b
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv;
+use compiler_builtins::float::fenv::{RoundingMode, set_rounding_mode};
+
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((RoundingMode, u32), i64)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn llrintf() {
+    for &((mode, a), b) in TEST_CASES {
+        set_rounding_mode(mode);
+        let b_ = conv::llrintf(mk_f32(a));
+        set_rounding_mode(RoundingMode::ToNearest);
+        assert_eq!(((mode, a), b), ((mode, a), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Llrint {
+        mode: RoundingMode,
+        a: u64, // f64
+        b: i64,
+    }
+
+    impl TestCase for Llrint {
+        fn name() -> &'static str {
+            "llrint"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let mode = gen_rounding_mode(rng);
+            let a = match rng.gen_range(0, 4) {
+                0 => gen_f64(rng),
+                // Any bit pattern, including infinities and NaNs
+                1 => mk_f64(rng.gen()),
+                // Halfway between two integers
+                2 => rng.gen_range(-64, 64) as f64 + 0.5,
+                // A magnitude from 1/8 to out of range of an `i64`
+                _ => {
+                    let exponent = rng.gen_range(1023 - 3, 1023 + 66);
+                    mk_f64(rng.gen::<u64>() & 0x800f_ffff_ffff_ffff | exponent << 52)
+                }
+            };
+            let b = f64_to_i64(f64_round_integral(a as f64, mode));
+
+            Some(Llrint { mode, a: to_u64(a), b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "((RoundingMode::{mode:?}, {a}), {b}),",
+                mode = self.mode,
+                a = self.a,
+                b = self.b
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv;
+use compiler_builtins::float::fenv::{RoundingMode, set_rounding_mode};
+
+fn mk_f64(x: u64) -> f64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((RoundingMode, u64), i64)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn llrint() {
+    for &((mode, a), b) in TEST_CASES {
+        set_rounding_mode(mode);
+        let b_ = conv::llrint(mk_f64(a));
+        set_rounding_mode(RoundingMode::ToNearest);
+        assert_eq!(((mode, a), b), ((mode, a), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Llroundevenf {
+        a: u32, // f32
+        b: i64,
+    }
+
+    impl TestCase for Llroundevenf {
+        fn name() -> &'static str {
+            "llroundevenf"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = match rng.gen_range(0, 4) {
+                0 => gen_f32(rng),
+                // Any bit pattern, including infinities and NaNs
+                1 => mk_f32(rng.gen()),
+                // Halfway between two integers
+                2 => rng.gen_range(-64, 64) as f32 + 0.5,
+                // A magnitude from 1/8 to out of range of an `i64`
+                _ => {
+                    let exponent = rng.gen_range(127 - 3, 127 + 66);
+                    mk_f32(rng.gen::<u32>() & 0x807f_ffff | exponent << 23)
+                }
+            };
+            let b = f64_to_i64(f64_round_integral(a as f64, RoundingMode::ToNearest));
+
+            Some(Llroundevenf { a: to_u32(a), b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__rust_llroundevenf;
+
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u32,), i64)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn llroundevenf() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __rust_llroundevenf(mk_f32(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Llroundeven {
+        a: u64, // f64
+        b: i64,
+    }
+
+    impl TestCase for Llroundeven {
+        fn name() -> &'static str {
+            "llroundeven"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = match rng.gen_range(0, 4) {
+                0 => gen_f64(rng),
+                // Any bit pattern, including infinities and NaNs
+                1 => mk_f64(rng.gen()),
+                // Halfway between two integers
+                2 => rng.gen_range(-64, 64) as f64 + 0.5,
+                // A magnitude from 1/8 to out of range of an `i64`
+                _ => {
+                    let exponent = rng.gen_range(1023 - 3, 1023 + 66);
+                    mk_f64(rng.gen::<u64>() & 0x800f_ffff_ffff_ffff | exponent << 52)
+                }
+            };
+            let b = f64_to_i64(f64_round_integral(a as f64, RoundingMode::ToNearest));
+
+            Some(Llroundeven { a: to_u64(a), b })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__rust_llroundeven;
+
+fn mk_f64(x: u64) -> f64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u64,), i64)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn llroundeven() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __rust_llroundeven(mk_f64(a));
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Floatdidf {
+        a: i64,
+        b: u64, // f64
+    }
+
+    impl TestCase for Floatdidf {
+        fn name() -> &'static str {
+            "floatdidf"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_i64(rng);
+            Some(
+                Floatdidf {
+                    a,
+                    b: to_u64(f64(a)),
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__floatdidf;
+
+fn to_u64(x: f64) -> u64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((i64,), u64)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn floatdidf() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __floatdidf(a);
+        assert_eq!(((a,), b), ((a,), to_u64(b_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Floatdisf {
+        a: i64,
+        b: u32, // f32
+    }
+
+    impl TestCase for Floatdisf {
+        fn name() -> &'static str {
+            "floatdisf"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_i64(rng);
+            Some(
+                Floatdisf {
+                    a,
+                    b: to_u32(f32(a)),
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__floatdisf;
+
+fn to_u32(x: f32) -> u32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((i64,), u32)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn floatdisf() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __floatdisf(a);
+        assert_eq!(((a,), b), ((a,), to_u32(b_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Floatsidf {
+        a: i32,
+        b: u64, // f64
+    }
+
+    impl TestCase for Floatsidf {
+        fn name() -> &'static str {
+            "floatsidf"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_i32(rng);
+            Some(
+                Floatsidf {
+                    a,
+                    b: to_u64(f64(a)),
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__floatsidf;
+
+fn to_u64(x: f64) -> u64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((i32,), u64)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn floatsidf() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __floatsidf(a);
+        assert_eq!(((a,), b), ((a,), to_u64(b_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Floatsisf {
+        a: i32,
+        b: u32, // f32
+    }
+
+    impl TestCase for Floatsisf {
+        fn name() -> &'static str {
+            "floatsisf"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_i32(rng);
+            Some(
+                Floatsisf {
+                    a,
+                    b: to_u32(f32(a)),
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__floatsisf;
+
+fn to_u32(x: f32) -> u32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((i32,), u32)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn floatsisf() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __floatsisf(a);
+        assert_eq!(((a,), b), ((a,), to_u32(b_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Floattisf {
+        a: i128,
+        b: u32, // f32
+    }
+
+    impl TestCase for Floattisf {
+        fn name() -> &'static str {
+            "floattisf"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_i128(rng);
+            Some(
+                Floattisf {
+                    a,
+                    b: to_u32(f32(a)),
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__floattisf;
+
+fn to_u32(x: f32) -> u32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((i128,), u32)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn floattisf() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __floattisf(a);
+        assert_eq!(((a,), b), ((a,), to_u32(b_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Floattidf {
+        a: i128,
+        b: u64, // f64
+    }
+
+    impl TestCase for Floattidf {
+        fn name() -> &'static str {
+            "floattidf"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_i128(rng);
+            Some(
+                Floattidf {
+                    a,
+                    b: to_u64(f64(a)),
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__floattidf;
+
+fn to_u64(x: f64) -> u64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((i128,), u64)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn floattidf() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __floattidf(a);
+        let g_b = to_u64(b_);
+        let diff = if g_b > b { g_b - b } else { b - g_b };
+        assert_eq!(((a,), b, g_b, true), ((a,), b, g_b, diff <= 1));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Floatditf {
+        a: i64,
+        b: u128, // f128,
+    }
+
+    impl TestCase for Floatditf {
+        fn name() -> &'static str {
+            "floatditf"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_i64(rng);
+            Some(
+                Floatditf {
+                    a,
+                    b: i128_to_f128(a as i128),
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::Float;
+use compiler_builtins::float::conv::__floatditf;
+static TEST_CASES: &[((i64,), u128)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn floatditf() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __floatditf(a).repr();
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Floatsitf {
+        a: i32,
+        b: u128, // f128,
+    }
+
+    impl TestCase for Floatsitf {
+        fn name() -> &'static str {
+            "floatsitf"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_i32(rng);
+            Some(
+                Floatsitf {
+                    a,
+                    b: i128_to_f128(a as i128),
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::Float;
+use compiler_builtins::float::conv::__floatsitf;
+static TEST_CASES: &[((i32,), u128)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn floatsitf() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __floatsitf(a).repr();
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Floattitf {
+        a: i128,
+        b: u128, // f128,
+    }
+
+    impl TestCase for Floattitf {
+        fn name() -> &'static str {
+            "floattitf"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_i128(rng);
+            Some(
+                Floattitf {
+                    a,
+                    b: i128_to_f128(a),
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::Float;
+use compiler_builtins::float::conv::__floattitf;
+static TEST_CASES: &[((i128,), u128)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn floattitf() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __floattitf(a).repr();
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Floatdixf {
+        a: i64,
+        b: u128, // f80,
+    }
+
+    impl TestCase for Floatdixf {
+        fn name() -> &'static str {
+            "floatdixf"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_i64(rng);
+            Some(
+                Floatdixf {
+                    a,
+                    b: i128_to_f80(a as i128),
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::f80;
+use compiler_builtins::float::conv::__rust_floatdixf;
+static TEST_CASES: &[((i64,), u128)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn floatdixf() {
+    for &((a,), b) in TEST_CASES {
+        let mut b_ = f80::from_repr(0);
+        __rust_floatdixf(&mut b_, a);
+        let b_ = b_.repr();
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Floattixf {
+        a: i128,
+        b: u128, // f80,
+    }
+
+    impl TestCase for Floattixf {
+        fn name() -> &'static str {
+            "floattixf"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_i128(rng);
+            Some(
+                Floattixf {
+                    a,
+                    b: i128_to_f80(a),
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::f80;
+use compiler_builtins::float::conv::__rust_floattixf;
+static TEST_CASES: &[((i128,), u128)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn floattixf() {
+    for &((a,), b) in TEST_CASES {
+        let mut b_ = f80::from_repr(0);
+        __rust_floattixf(&mut b_, a);
+        let b_ = b_.repr();
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Floatundidf {
+        a: u64,
+        b: u64, // f64
+    }
+
+    impl TestCase for Floatundidf {
+        fn name() -> &'static str {
+            "floatundidf"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_u64(rng);
+            Some(
+                Floatundidf {
+                    a,
+                    b: to_u64(f64(a)),
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__floatundidf;
+
+fn to_u64(x: f64) -> u64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u64,), u64)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn floatundidf() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __floatundidf(a);
+        assert_eq!(((a,), b), ((a,), to_u64(b_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Floatundisf {
+        a: u64,
+        b: u32, // f32
+    }
+
+    impl TestCase for Floatundisf {
+        fn name() -> &'static str {
+            "floatundisf"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_u64(rng);
+            Some(
+                Floatundisf {
+                    a,
+                    b: to_u32(f32(a)),
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__floatundisf;
+
+fn to_u32(x: f32) -> u32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u64,), u32)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn floatundisf() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __floatundisf(a);
+        assert_eq!(((a,), b), ((a,), to_u32(b_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Floatunsidf {
+        a: u32,
+        b: u64, // f64
+    }
+
+    impl TestCase for Floatunsidf {
+        fn name() -> &'static str {
+            "floatunsidf"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_u32(rng);
+            Some(
+                Floatunsidf {
+                    a,
+                    b: to_u64(f64(a)),
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__floatunsidf;
+
+fn to_u64(x: f64) -> u64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u32,), u64)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn floatunsidf() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __floatunsidf(a);
+        assert_eq!(((a,), b), ((a,), to_u64(b_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Floatunsisf {
+        a: u32,
+        b: u32, // f32
+    }
+
+    impl TestCase for Floatunsisf {
+        fn name() -> &'static str {
+            "floatunsisf"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_u32(rng);
+            Some(
+                Floatunsisf {
+                    a,
+                    b: to_u32(f32(a)),
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__floatunsisf;
+
+fn to_u32(x: f32) -> u32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u32,), u32)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn floatunsisf() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __floatunsisf(a);
+        assert_eq!(((a,), b), ((a,), to_u32(b_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Floatuntisf {
+        a: u128,
+        b: u32, // f32
+    }
+
+    impl TestCase for Floatuntisf {
+        fn name() -> &'static str {
+            "floatuntisf"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_u128(rng);
+            let f_a = f32(a);
+            f_a.ok().map(|f| {
+                Floatuntisf {
+                    a,
+                    b: to_u32(f),
+                }
+            })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__floatuntisf;
+
+fn to_u32(x: f32) -> u32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u128,), u32)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn floatuntisf() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __floatuntisf(a);
+        assert_eq!(((a,), b), ((a,), to_u32(b_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Floatuntidf {
+        a: u128,
+        b: u64, // f64
+    }
+
+    impl TestCase for Floatuntidf {
+        fn name() -> &'static str {
+            "floatuntidf"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_u128(rng);
+            Some(
+                Floatuntidf {
+                    a,
+                    b: to_u64(f64(a)),
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::conv::__floatuntidf;
+
+fn to_u64(x: f64) -> u64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u128,), u64)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn floatuntidf() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __floatuntidf(a);
+        let g_b = to_u64(b_);
+        let diff = if g_b > b { g_b - b } else { b - g_b };
+        assert_eq!(((a,), b, g_b, true), ((a,), b, g_b, diff <= 1));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Floatunditf {
+        a: u64,
+        b: u128, // f128,
+    }
+
+    impl TestCase for Floatunditf {
+        fn name() -> &'static str {
+            "floatunditf"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_u64(rng);
+            Some(
+                Floatunditf {
+                    a,
+                    b: u128_to_f128(a as u128),
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::Float;
+use compiler_builtins::float::conv::__floatunditf;
+static TEST_CASES: &[((u64,), u128)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn floatunditf() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __floatunditf(a).repr();
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Floatunsitf {
+        a: u32,
+        b: u128, // f128,
+    }
+
+    impl TestCase for Floatunsitf {
+        fn name() -> &'static str {
+            "floatunsitf"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_u32(rng);
+            Some(
+                Floatunsitf {
+                    a,
+                    b: u128_to_f128(a as u128),
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::Float;
+use compiler_builtins::float::conv::__floatunsitf;
+static TEST_CASES: &[((u32,), u128)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn floatunsitf() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __floatunsitf(a).repr();
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Floatuntitf {
+        a: u128,
+        b: u128, // f128,
+    }
+
+    impl TestCase for Floatuntitf {
+        fn name() -> &'static str {
+            "floatuntitf"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_u128(rng);
+            Some(
+                Floatuntitf {
+                    a,
+                    b: u128_to_f128(a),
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::Float;
+use compiler_builtins::float::conv::__floatuntitf;
+static TEST_CASES: &[((u128,), u128)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn floatuntitf() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = __floatuntitf(a).repr();
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Floatundixf {
+        a: u64,
+        b: u128, // f80,
+    }
+
+    impl TestCase for Floatundixf {
+        fn name() -> &'static str {
+            "floatundixf"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_u64(rng);
+            Some(
+                Floatundixf {
+                    a,
+                    b: u128_to_f80(a as u128),
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::f80;
+use compiler_builtins::float::conv::__rust_floatundixf;
+static TEST_CASES: &[((u64,), u128)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn floatundixf() {
+    for &((a,), b) in TEST_CASES {
+        let mut b_ = f80::from_repr(0);
+        __rust_floatundixf(&mut b_, a);
+        let b_ = b_.repr();
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Floatuntixf {
+        a: u128,
+        b: u128, // f80,
+    }
+
+    impl TestCase for Floatuntixf {
+        fn name() -> &'static str {
+            "floatuntixf"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_u128(rng);
+            Some(
+                Floatuntixf {
+                    a,
+                    b: u128_to_f80(a),
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::f80;
+use compiler_builtins::float::conv::__rust_floatuntixf;
+static TEST_CASES: &[((u128,), u128)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn floatuntixf() {
+    for &((a,), b) in TEST_CASES {
+        let mut b_ = f80::from_repr(0);
+        __rust_floatuntixf(&mut b_, a);
+        let b_ = b_.repr();
+        assert_eq!(((a,), b), ((a,), b_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Moddi3 {
+        a: i64,
+        b: i64,
+        c: i64,
+    }
+
+    impl TestCase for Moddi3 {
+        fn name() -> &'static str {
+            "moddi3"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_i64(rng);
+            let b = gen_i64(rng);
+            if b == 0 {
+                return None;
+            }
+            let c = a % b;
+
+            Some(Moddi3 { a, b, c })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a}, {b}), {c}),",
+                a = self.a,
+                b = self.b,
+                c = self.c
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            "
+use compiler_builtins::int::sdiv::__moddi3;
+
+static TEST_CASES: &[((i64, i64), i64)] = &[
+"
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn moddi3() {
+    for &((a, b), c) in TEST_CASES {
+        let c_ = __moddi3(a, b);
+        assert_eq!(((a, b), c), ((a, b), c_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Modsi3 {
+        a: i32,
+        b: i32,
+        c: i32,
+    }
+
+    impl TestCase for Modsi3 {
+        fn name() -> &'static str {
+            "modsi3"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_i32(rng);
+            let b = gen_i32(rng);
+            if b == 0 {
+                return None;
+            }
+            let c = a % b;
+
+            Some(Modsi3 { a, b, c })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a}, {b}), {c}),",
+                a = self.a,
+                b = self.b,
+                c = self.c
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            "
+use compiler_builtins::int::sdiv::__modsi3;
+
+static TEST_CASES: &[((i32, i32), i32)] = &[
+"
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn modsi3() {
+    for &((a, b), c) in TEST_CASES {
+        let c_ = __modsi3(a, b);
+        assert_eq!(((a, b), c), ((a, b), c_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Modti3 {
+        a: i128,
+        b: i128,
+        c: i128,
+    }
+
+    impl TestCase for Modti3 {
+        fn name() -> &'static str {
+            "modti3"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_i128(rng);
+            let b = gen_i128(rng);
+            if b == 0 {
+                return None;
+            }
+            let c = a % b;
+
+            Some(Modti3 { a, b, c })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a}, {b}), {c}),",
+                a = self.a,
+                b = self.b,
+                c = self.c
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            "
+use compiler_builtins::int::sdiv::__modti3;
+
+static TEST_CASES: &[((i128, i128), i128)] = &[
+"
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn modti3() {
+    for &((a, b), c) in TEST_CASES {
+        let c_ = __modti3(a, b);
+        assert_eq!(((a, b), c), ((a, b), c_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Muldf3 {
+        a: u64,  // f64
+        b: u64,  // f64
+        c: u64,  // f64
+    }
+
+    impl TestCase for Muldf3 {
+        fn name() -> &'static str {
+            "muldf3"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f64(rng);
+            let b = gen_f64(rng);
+            let c = float_nan_result(
+                to_u64(a) as u128,
+                to_u64(b) as u128,
+                to_u64(a * b) as u128,
+                52,
+                11,
+            );
+
+            Some(
+                Muldf3 {
+                    a: to_u64(a),
+                    b: to_u64(b),
+                    c: c as u64,
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a}, {b}), {c}),",
+                a = self.a,
+                b = self.b,
+                c = self.c
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::mul::__muldf3;
+
+fn mk_f64(x: u64) -> f64 {
+    unsafe { mem::transmute(x) }
+}
+
+fn to_u64(x: f64) -> u64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u64, u64), u64)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn muldf3() {
+    for &((a, b), c) in TEST_CASES {
+        let c_ = __muldf3(mk_f64(a), mk_f64(b));
+        assert_eq!(((a, b), c), ((a, b), to_u64(c_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Clzsi2 {
+        a: u32,
+        c: i32,
+    }
+
+    impl TestCase for Clzsi2 {
+        fn name() -> &'static str {
+            "clzsi2"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            // Shift a random number to spread the results over all counts
+            let a = gen_u32(rng) >> (rng.gen::<u8>() % 32) as u32;
+            let c = a.leading_zeros() as i32;
+
+            Some(Clzsi2 { a, c })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "({a}, {c}),",
+                a = self.a,
+                c = self.c
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            "
+use compiler_builtins::int::leading_zeros::__clzsi2;
+
+static TEST_CASES: &[(u32, i32)] = &[
+"
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn clzsi2() {
+    for &(a, c) in TEST_CASES {
+        let c_ = __clzsi2(a);
+        assert_eq!((a, c), (a, c_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Clzdi2 {
+        a: u64,
+        c: i32,
+    }
+
+    impl TestCase for Clzdi2 {
+        fn name() -> &'static str {
+            "clzdi2"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            // Shift a random number to spread the results over all counts
+            let a = gen_u64(rng) >> (rng.gen::<u8>() % 64) as u32;
+            let c = a.leading_zeros() as i32;
+
+            Some(Clzdi2 { a, c })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "({a}, {c}),",
+                a = self.a,
+                c = self.c
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            "
+use compiler_builtins::int::leading_zeros::__clzdi2;
+
+static TEST_CASES: &[(u64, i32)] = &[
+"
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn clzdi2() {
+    for &(a, c) in TEST_CASES {
+        let c_ = __clzdi2(a);
+        assert_eq!((a, c), (a, c_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Clzti2 {
+        a: u128,
+        c: i32,
+    }
+
+    impl TestCase for Clzti2 {
+        fn name() -> &'static str {
+            "clzti2"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            // Shift a random number to spread the results over all counts
+            let a = gen_u128(rng) >> (rng.gen::<u8>() % 128) as u32;
+            let c = a.leading_zeros() as i32;
+
+            Some(Clzti2 { a, c })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "({a}, {c}),",
+                a = self.a,
+                c = self.c
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            "
+use compiler_builtins::int::leading_zeros::__clzti2;
+
+static TEST_CASES: &[(u128, i32)] = &[
+"
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn clzti2() {
+    for &(a, c) in TEST_CASES {
+        let c_ = __clzti2(a);
+        assert_eq!((a, c), (a, c_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Ctzsi2 {
+        a: u32,
+        c: i32,
+    }
+
+    impl TestCase for Ctzsi2 {
+        fn name() -> &'static str {
+            "ctzsi2"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            // Shift a random number to spread the results over all counts
+            let a = gen_u32(rng) << (rng.gen::<u8>() % 32) as u32;
+            let c = a.trailing_zeros() as i32;
+
+            Some(Ctzsi2 { a, c })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "({a}, {c}),",
+                a = self.a,
+                c = self.c
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            "
+use compiler_builtins::int::leading_zeros::__ctzsi2;
+
+static TEST_CASES: &[(u32, i32)] = &[
+"
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn ctzsi2() {
+    for &(a, c) in TEST_CASES {
+        let c_ = __ctzsi2(a);
+        assert_eq!((a, c), (a, c_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Ctzdi2 {
+        a: u64,
+        c: i32,
+    }
+
+    impl TestCase for Ctzdi2 {
+        fn name() -> &'static str {
+            "ctzdi2"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            // Shift a random number to spread the results over all counts
+            let a = gen_u64(rng) << (rng.gen::<u8>() % 64) as u32;
+            let c = a.trailing_zeros() as i32;
+
+            Some(Ctzdi2 { a, c })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "({a}, {c}),",
+                a = self.a,
+                c = self.c
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            "
+use compiler_builtins::int::leading_zeros::__ctzdi2;
+
+static TEST_CASES: &[(u64, i32)] = &[
+"
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn ctzdi2() {
+    for &(a, c) in TEST_CASES {
+        let c_ = __ctzdi2(a);
+        assert_eq!((a, c), (a, c_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Ctzti2 {
+        a: u128,
+        c: i32,
+    }
+
+    impl TestCase for Ctzti2 {
+        fn name() -> &'static str {
+            "ctzti2"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            // Shift a random number to spread the results over all counts
+            let a = gen_u128(rng) << (rng.gen::<u8>() % 128) as u32;
+            let c = a.trailing_zeros() as i32;
+
+            Some(Ctzti2 { a, c })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "({a}, {c}),",
+                a = self.a,
+                c = self.c
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            "
+use compiler_builtins::int::leading_zeros::__ctzti2;
+
+static TEST_CASES: &[(u128, i32)] = &[
+"
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn ctzti2() {
+    for &(a, c) in TEST_CASES {
+        let c_ = __ctzti2(a);
+        assert_eq!((a, c), (a, c_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    struct Muldi3 {
+        a: u64,
+        b: u64,
+        c: u64,
+    }
+
+    impl TestCase for Muldi3 {
+        fn name() -> &'static str {
+            "muldi3"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_u64(rng);
+            let b = gen_u64(rng);
+            let c = a.wrapping_mul(b);
+
+            Some(Muldi3 { a, b, c })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a}, {b}), {c}),",
+                a = self.a,
+                b = self.b,
+                c = self.c
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            "
+use compiler_builtins::int::mul::__muldi3;
+
+static TEST_CASES: &[((u64, u64), u64)] = &[
+"
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn muldi3() {
+    for &((a, b), c) in TEST_CASES {
+        let c_ = __muldi3(a, b);
+        assert_eq!(((a, b), c), ((a, b), c_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Mulodi4 {
+        a: i64,
+        b: i64,
+        c: i64,
+        overflow: u32,
+    }
+
+    impl TestCase for Mulodi4 {
+        fn name() -> &'static str {
+            "mulodi4"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+        {
+            let a = gen_i64(rng);
+            let b = gen_i64(rng);
+            let c = a.wrapping_mul(b);
+            let overflow = if a.checked_mul(b).is_some() { 0 } else { 1 };
+
+            Some(Mulodi4 { a, b, c, overflow })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a}, {b}), ({c}, {overflow})),",
+                a = self.a,
+                b = self.b,
+                c = self.c,
+                overflow = self.overflow
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            "
+use compiler_builtins::int::mul::__mulodi4;
+
+static TEST_CASES: &[((i64, i64), (i64, i32))] = &[
+"
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn mulodi4() {
+    let mut overflow_ = 2;
+    for &((a, b), (c, overflow)) in TEST_CASES {
+        let c_ = __mulodi4(a, b, &mut overflow_);
+        assert_eq!(((a, b), (c, overflow)), ((a, b), (c_, overflow_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Mulosi4 {
+        a: i32,
+        b: i32,
+        c: i32,
+        overflow: u32,
+    }
+
+    impl TestCase for Mulosi4 {
+        fn name() -> &'static str {
+            "mulosi4"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+        {
+            let a = gen_i32(rng);
+            let b = gen_i32(rng);
+            let c = a.wrapping_mul(b);
+            let overflow = if a.checked_mul(b).is_some() { 0 } else { 1 };
+
+            Some(Mulosi4 { a, b, c, overflow })
+        }
+
+        fn prologue() -> &'static str {
+            "
+use compiler_builtins::int::mul::__mulosi4;
+
+static TEST_CASES: &[((i32, i32), (i32, i32))] = &[
+"
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn mulosi4() {
+    let mut overflow_ = 2;
+    for &((a, b), (c, overflow)) in TEST_CASES {
+        let c_ = __mulosi4(a, b, &mut overflow_);
+        assert_eq!(((a, b), (c, overflow)), ((a, b), (c_, overflow_)));
+    }
+}
+"
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a}, {b}), ({c}, {overflow})),",
+                a = self.a,
+                b = self.b,
+                c = self.c,
+                overflow = self.overflow
+            )
+                    .unwrap();
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Muloti4 {
+        a: i128,
+        b: i128,
+        c: i128,
+        overflow: u32,
+    }
+
+    impl TestCase for Muloti4 {
+        fn name() -> &'static str {
+            "muloti4"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+        {
+            let a = gen_i128(rng);
+            let b = gen_i128(rng);
+            let c = a.wrapping_mul(b);
+            let overflow = if a.checked_mul(b).is_some() { 0 } else { 1 };
+
+            Some(Muloti4 { a, b, c, overflow })
+        }
+
+        fn prologue() -> &'static str {
+            "
+use compiler_builtins::int::mul::__muloti4;
+
+static TEST_CASES: &[((i128, i128), (i128, i32))] = &[
+"
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn muloti4() {
+    let mut overflow_ = 2;
+    for &((a, b), (c, overflow)) in TEST_CASES {
+        let c_ = __muloti4(a, b, &mut overflow_);
+        assert_eq!(((a, b), (c, overflow)), ((a, b), (c_, overflow_)));
+    }
+}
+"
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a}, {b}), ({c}, {overflow})),",
+                a = self.a,
+                b = self.b,
+                c = self.c,
+                overflow = self.overflow
+            )
+                    .unwrap();
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Mulsf3 {
+        a: u32,  // f32
+        b: u32,  // f32
+        c: u32,  // f32
+    }
+
+    impl TestCase for Mulsf3 {
+        fn name() -> &'static str {
+            "mulsf3"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f32(rng);
+            let b = gen_f32(rng);
+            let c = float_nan_result(
+                to_u32(a) as u128,
+                to_u32(b) as u128,
+                to_u32(a * b) as u128,
+                23,
+                8,
+            );
+
+            Some(
+                Mulsf3 {
+                    a: to_u32(a),
+                    b: to_u32(b),
+                    c: c as u32,
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a}, {b}), {c}),",
+                a = self.a,
+                b = self.b,
+                c = self.c
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::float::mul::__mulsf3;
+
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+fn to_u32(x: f32) -> u32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u32, u32), u32)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn mulsf3() {
+    for &((a, b), c) in TEST_CASES {
+        let c_ = __mulsf3(mk_f32(a), mk_f32(b));
+        assert_eq!(((a, b), c), ((a, b), to_u32(c_)));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Multf3 {
+        a: u128, // f128
+        b: u128, // f128
+        c: u128, // f128
+    }
+
+    impl TestCase for Multf3 {
+        fn name() -> &'static str {
+            "multf3"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f128(rng);
+            let b = gen_f128(rng);
+            let c = f128_mul(a, b);
+            // TODO accept NaNs. We don't do that right now because we can't check
+            // for NaN-ness on the thumb targets (due to missing intrinsics)
+            if f128_is_nan(a) || f128_is_nan(b) || f128_is_nan(c) {
+                return None;
+            }
+
+            Some(Multf3 { a, b, c })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a}, {b}), {c}),",
+                a = self.a,
+                b = self.b,
+                c = self.c
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            r#"
+use compiler_builtins::float::{Float, f128};
+use compiler_builtins::float::mul::__multf3;
+
+static TEST_CASES: &[((u128, u128), u128)] = &[
+"#
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn multf3() {
+    for &((a, b), c) in TEST_CASES {
+        let c_ = __multf3(f128::from_repr(a), f128::from_repr(b));
+        assert_eq!(((a, b), c), ((a, b), c_.repr()));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Multi3 {
+        a: i128,
+        b: i128,
+        c: i128,
+    }
+
+    impl TestCase for Multi3 {
+        fn name() -> &'static str {
+            "multi3"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_i128(rng);
+            let b = gen_i128(rng);
+            let c = a.wrapping_mul(b);
+
+            Some(Multi3 { a, b, c })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a}, {b}), {c}),",
+                a = self.a,
+                b = self.b,
+                c = self.c
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            "
+use compiler_builtins::int::mul::__multi3;
+
+static TEST_CASES: &[((i128, i128), i128)] = &[
+"
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn multi3() {
+    for &((a, b), c) in TEST_CASES {
+        let c_ = __multi3(a, b);
+        assert_eq!(((a, b), c), ((a, b), c_));
+    }
+}
+"
+        }
+    }
+
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Powidf2 {
+        a: u64,  // f64
+        b: i32,
+        c: u64,  // f64
+    }
+
+    impl TestCase for Powidf2 {
+        fn name() -> &'static str {
+            "powidf2"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_f64(rng);
+            let b = gen_i32(rng);
+            let c = a.powi(b);
+            // TODO accept NaNs. We don't do that right now because we can't check
+            // for NaN-ness on the thumb targets
+            if a.is_nan() || c.is_nan() {
+                return None;
+            }
+
+            Some(
+                Powidf2 {
+                    a: to_u64(a),
+                    b,
+                    c: to_u64(c),
+                },
+            )
         }
 
         fn to_string(&self, buffer: &mut String) {
-            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+            writeln!(
+                buffer,
+                "(({a}, {b}), {c}),",
+                a = self.a,
+                b = self.b,
+                c = self.c
+            )
+                    .unwrap();
         }
 
         fn prologue() -> &'static str {
@@ -1021,13 +9787,17 @@ use core::mem;
               target_os = "linux",
               test)))]
 use std::mem;
-use compiler_builtins::float::conv::__fixsfdi;
+use compiler_builtins::float::pow::__powidf2;
 
-fn mk_f32(x: u32) -> f32 {
+fn mk_f64(x: u64) -> f64 {
     unsafe { mem::transmute(x) }
 }
 
-static TEST_CASES: &[((u32,), i64)] = &[
+fn to_u64(x: f64) -> u64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u64, i32), u64)] = &[
 "#
         }
 
@@ -1036,10 +9806,10 @@ static TEST_CASES: &[((u32,), i64)] = &[
 ];
 
 #[test]
-fn fixsfdi() {
-    for &((a,), b) in TEST_CASES {
-        let b_ = __fixsfdi(mk_f32(a));
-        assert_eq!(((a,), b), ((a,), b_));
+fn powidf2() {
+    for &((a, b), c) in TEST_CASES {
+        let c_ = __powidf2(mk_f64(a), b);
+        assert_eq!(((a, b), c), ((a, b), to_u64(c_)));
     }
 }
 "
@@ -1047,14 +9817,15 @@ fn fixsfdi() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Fixsfsi {
+    pub struct Powisf2 {
         a: u32,  // f32
         b: i32,
+        c: u32,  // f32
     }
 
-    impl TestCase for Fixsfsi {
+    impl TestCase for Powisf2 {
         fn name() -> &'static str {
-            "fixsfsi"
+            "powisf2"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -1063,11 +9834,32 @@ fn fixsfdi() {
             Self: Sized,
         {
             let a = gen_f32(rng);
-            i32(a).ok().map(|b| Fixsfsi { a: to_u32(a), b })
+            let b = gen_i32(rng);
+            let c = a.powi(b);
+            // TODO accept NaNs. We don't do that right now because we can't check
+            // for NaN-ness on the thumb targets
+            if a.is_nan() || c.is_nan() {
+                return None;
+            }
+
+            Some(
+                Powisf2 {
+                    a: to_u32(a),
+                    b,
+                    c: to_u32(c),
+                },
+            )
         }
 
         fn to_string(&self, buffer: &mut String) {
-            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+            writeln!(
+                buffer,
+                "(({a}, {b}), {c}),",
+                a = self.a,
+                b = self.b,
+                c = self.c
+            )
+                    .unwrap();
         }
 
         fn prologue() -> &'static str {
@@ -1082,13 +9874,17 @@ use core::mem;
               target_os = "linux",
               test)))]
 use std::mem;
-use compiler_builtins::float::conv::__fixsfsi;
+use compiler_builtins::float::pow::__powisf2;
 
 fn mk_f32(x: u32) -> f32 {
     unsafe { mem::transmute(x) }
 }
 
-static TEST_CASES: &[((u32,), i32)] = &[
+fn to_u32(x: f32) -> u32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u32, i32), u32)] = &[
 "#
         }
 
@@ -1097,10 +9893,10 @@ static TEST_CASES: &[((u32,), i32)] = &[
 ];
 
 #[test]
-fn fixsfsi() {
-    for &((a,), b) in TEST_CASES {
-        let b_ = __fixsfsi(mk_f32(a));
-        assert_eq!(((a,), b), ((a,), b_));
+fn powisf2() {
+    for &((a, b), c) in TEST_CASES {
+        let c_ = __powisf2(mk_f32(a), b);
+        assert_eq!(((a, b), c), ((a, b), to_u32(c_)));
     }
 }
 "
@@ -1108,14 +9904,15 @@ fn fixsfsi() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Fixsfti {
-        a: u32,  // f32
-        b: i128,
+    pub struct Powixf2 {
+        a: u128, // f80
+        b: i32,
+        c: u128, // f80
     }
 
-    impl TestCase for Fixsfti {
+    impl TestCase for Powixf2 {
         fn name() -> &'static str {
-            "fixsfti"
+            "powixf2"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -1123,33 +9920,41 @@ fn fixsfsi() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_f32(rng);
-            i128(a).ok().map(|b| Fixsfti { a: to_u32(a), b })
+            let a = gen_f80(rng);
+            let b = gen_i32(rng);
+            let (c, _) = f80_powi(a, b);
+            // TODO accept NaNs. We don't do that right now because we can't check
+            // for NaN-ness on the thumb targets
+            if f80_is_nan(a) || f80_is_nan(c) {
+                return None;
+            }
+
+            Some(
+                Powixf2 {
+                    a,
+                    b,
+                    c,
+                },
+            )
         }
 
         fn to_string(&self, buffer: &mut String) {
-            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+            writeln!(
+                buffer,
+                "(({a}, {b}), {c}),",
+                a = self.a,
+                b = self.b,
+                c = self.c
+            )
+                    .unwrap();
         }
 
         fn prologue() -> &'static str {
             r#"
-#[cfg(all(target_arch = "arm",
-          not(any(target_env = "gnu", target_env = "musl")),
-          target_os = "linux",
-          test))]
-use core::mem;
-#[cfg(not(all(target_arch = "arm",
-              not(any(target_env = "gnu", target_env = "musl")),
-              target_os = "linux",
-              test)))]
-use std::mem;
-use compiler_builtins::float::conv::__fixsfti;
-
-fn mk_f32(x: u32) -> f32 {
-    unsafe { mem::transmute(x) }
-}
+use compiler_builtins::float::f80;
+use compiler_builtins::float::pow::__rust_powixf2;
 
-static TEST_CASES: &[((u32,), i128)] = &[
+static TEST_CASES: &[((u128, i32), u128)] = &[
 "#
         }
 
@@ -1158,10 +9963,11 @@ static TEST_CASES: &[((u32,), i128)] = &[
 ];
 
 #[test]
-fn fixsfti() {
-    for &((a,), b) in TEST_CASES {
-        let b_ = __fixsfti(mk_f32(a));
-        assert_eq!(((a,), b), ((a,), b_));
+fn powixf2() {
+    for &((a, b), c) in TEST_CASES {
+        let mut c_ = f80::from_repr(0);
+        __rust_powixf2(&mut c_, &f80::from_repr(a), b);
+        assert_eq!(((a, b), c), ((a, b), c_.repr()));
     }
 }
 "
@@ -1169,14 +9975,15 @@ fn fixsfti() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Fixdfti {
-        a: u64,  // f64
-        b: i128,
+    pub struct Lshrdi3 {
+        a: u64,
+        b: u32,
+        c: u64,
     }
 
-    impl TestCase for Fixdfti {
+    impl TestCase for Lshrdi3 {
         fn name() -> &'static str {
-            "fixdfti"
+            "lshrdi3"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -1184,34 +9991,30 @@ fn fixsfti() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_f64(rng);
-            i128(a).ok().map(|b| Fixdfti { a: to_u64(a), b })
+            let a = gen_u64(rng);
+            let b = (rng.gen::<u8>() % 64) as u32;
+            let c = a >> b;
+
+            Some(Lshrdi3 { a, b, c })
         }
 
         fn to_string(&self, buffer: &mut String) {
-            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+            writeln!(
+                buffer,
+                "(({a}, {b}), {c}),",
+                a = self.a,
+                b = self.b,
+                c = self.c
+            )
+                    .unwrap();
         }
 
         fn prologue() -> &'static str {
-            r#"
-#[cfg(all(target_arch = "arm",
-          not(any(target_env = "gnu", target_env = "musl")),
-          target_os = "linux",
-          test))]
-use core::mem;
-#[cfg(not(all(target_arch = "arm",
-              not(any(target_env = "gnu", target_env = "musl")),
-              target_os = "linux",
-              test)))]
-use std::mem;
-use compiler_builtins::float::conv::__fixdfti;
-
-fn mk_f64(x: u64) -> f64 {
-    unsafe { mem::transmute(x) }
-}
+            "
+use compiler_builtins::int::shift::__lshrdi3;
 
-static TEST_CASES: &[((u64,), i128)] = &[
-"#
+static TEST_CASES: &[((u64, u32), u64)] = &[
+"
         }
 
         fn epilogue() -> &'static str {
@@ -1219,10 +10022,10 @@ static TEST_CASES: &[((u64,), i128)] = &[
 ];
 
 #[test]
-fn fixdfti() {
-    for &((a,), b) in TEST_CASES {
-        let b_ = __fixdfti(mk_f64(a));
-        assert_eq!(((a,), b), ((a,), b_));
+fn lshrdi3() {
+    for &((a, b), c) in TEST_CASES {
+        let c_ = __lshrdi3(a, b);
+        assert_eq!(((a, b), c), ((a, b), c_));
     }
 }
 "
@@ -1230,14 +10033,15 @@ fn fixdfti() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Fixunsdfdi {
-        a: u64,  // f64
-        b: u64,
+    pub struct Lshrti3 {
+        a: u128,
+        b: u32,
+        c: u128,
     }
 
-    impl TestCase for Fixunsdfdi {
+    impl TestCase for Lshrti3 {
         fn name() -> &'static str {
-            "fixunsdfdi"
+            "lshrti3"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -1245,34 +10049,30 @@ fn fixdfti() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_f64(rng);
-            u64(a).ok().map(|b| Fixunsdfdi { a: to_u64(a), b })
-        }
+            let a = gen_u128(rng);
+            let b = (rng.gen::<u8>() % 128) as u32;
+            let c = a >> b;
 
-        fn to_string(&self, buffer: &mut String) {
-            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+            Some(Lshrti3 { a, b, c })
         }
 
-        fn prologue() -> &'static str {
-            r#"
-#[cfg(all(target_arch = "arm",
-          not(any(target_env = "gnu", target_env = "musl")),
-          target_os = "linux",
-          test))]
-use core::mem;
-#[cfg(not(all(target_arch = "arm",
-              not(any(target_env = "gnu", target_env = "musl")),
-              target_os = "linux",
-              test)))]
-use std::mem;
-use compiler_builtins::float::conv::__fixunsdfdi;
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a}, {b}), {c}),",
+                a = self.a,
+                b = self.b,
+                c = self.c
+            )
+                    .unwrap();
+        }
 
-fn mk_f64(x: u64) -> f64 {
-    unsafe { mem::transmute(x) }
-}
+        fn prologue() -> &'static str {
+            "
+use compiler_builtins::int::shift::__lshrti3;
 
-static TEST_CASES: &[((u64,), u64)] = &[
-"#
+static TEST_CASES: &[((u128, u32), u128)] = &[
+"
         }
 
         fn epilogue() -> &'static str {
@@ -1280,10 +10080,10 @@ static TEST_CASES: &[((u64,), u64)] = &[
 ];
 
 #[test]
-fn fixunsdfdi() {
-    for &((a,), b) in TEST_CASES {
-        let b_ = __fixunsdfdi(mk_f64(a));
-        assert_eq!(((a,), b), ((a,), b_));
+fn lshrti3() {
+    for &((a, b), c) in TEST_CASES {
+        let c_ = __lshrti3(a, b);
+        assert_eq!(((a, b), c), ((a, b), c_));
     }
 }
 "
@@ -1291,14 +10091,15 @@ fn fixunsdfdi() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Fixunsdfsi {
+    pub struct Subdf3 {
         a: u64,  // f64
-        b: u32,
+        b: u64,  // f64
+        c: u64,  // f64
     }
 
-    impl TestCase for Fixunsdfsi {
+    impl TestCase for Subdf3 {
         fn name() -> &'static str {
-            "fixunsdfsi"
+            "subdf3"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -1307,11 +10108,34 @@ fn fixunsdfdi() {
             Self: Sized,
         {
             let a = gen_f64(rng);
-            u32(a).ok().map(|b| Fixunsdfsi { a: to_u64(a), b })
+            let b = gen_f64(rng);
+            // The subtraction adds `-b`, which is also the NaN it propagates
+            let c = float_nan_result(
+                to_u64(a) as u128,
+                to_u64(-b) as u128,
+                to_u64(a - b) as u128,
+                52,
+                11,
+            );
+
+            Some(
+                Subdf3 {
+                    a: to_u64(a),
+                    b: to_u64(b),
+                    c: c as u64,
+                },
+            )
         }
 
         fn to_string(&self, buffer: &mut String) {
-            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+            writeln!(
+                buffer,
+                "(({a}, {b}), {c}),",
+                a = self.a,
+                b = self.b,
+                c = self.c
+            )
+                    .unwrap();
         }
 
         fn prologue() -> &'static str {
@@ -1326,13 +10150,17 @@ use core::mem;
               target_os = "linux",
               test)))]
 use std::mem;
-use compiler_builtins::float::conv::__fixunsdfsi;
+use compiler_builtins::float::sub::__subdf3;
 
 fn mk_f64(x: u64) -> f64 {
     unsafe { mem::transmute(x) }
 }
 
-static TEST_CASES: &[((u64,), u32)] = &[
+fn to_u64(x: f64) -> u64 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u64, u64), u64)] = &[
 "#
         }
 
@@ -1341,10 +10169,10 @@ static TEST_CASES: &[((u64,), u32)] = &[
 ];
 
 #[test]
-fn fixunsdfdi() {
-    for &((a,), b) in TEST_CASES {
-        let b_ = __fixunsdfsi(mk_f64(a));
-        assert_eq!(((a,), b), ((a,), b_));
+fn subdf3() {
+    for &((a, b), c) in TEST_CASES {
+        let c_ = __subdf3(mk_f64(a), mk_f64(b));
+        assert_eq!(((a, b), c), ((a, b), to_u64(c_)));
     }
 }
 "
@@ -1352,14 +10180,15 @@ fn fixunsdfdi() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Fixunssfdi {
+    pub struct Subsf3 {
         a: u32,  // f32
-        b: u64,
+        b: u32,  // f32
+        c: u32,  // f32
     }
 
-    impl TestCase for Fixunssfdi {
+    impl TestCase for Subsf3 {
         fn name() -> &'static str {
-            "fixunssfdi"
+            "subsf3"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -1368,11 +10197,34 @@ fn fixunsdfdi() {
             Self: Sized,
         {
             let a = gen_f32(rng);
-            u64(a).ok().map(|b| Fixunssfdi { a: to_u32(a), b })
+            let b = gen_f32(rng);
+            // The subtraction adds `-b`, which is also the NaN it propagates
+            let c = float_nan_result(
+                to_u32(a) as u128,
+                to_u32(-b) as u128,
+                to_u32(a - b) as u128,
+                23,
+                8,
+            );
+
+            Some(
+                Subsf3 {
+                    a: to_u32(a),
+                    b: to_u32(b),
+                    c: c as u32,
+                },
+            )
         }
 
         fn to_string(&self, buffer: &mut String) {
-            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+            writeln!(
+                buffer,
+                "(({a}, {b}), {c}),",
+                a = self.a,
+                b = self.b,
+                c = self.c
+            )
+                    .unwrap();
         }
 
         fn prologue() -> &'static str {
@@ -1387,13 +10239,17 @@ use core::mem;
               target_os = "linux",
               test)))]
 use std::mem;
-use compiler_builtins::float::conv::__fixunssfdi;
+use compiler_builtins::float::sub::__subsf3;
 
 fn mk_f32(x: u32) -> f32 {
     unsafe { mem::transmute(x) }
 }
 
-static TEST_CASES: &[((u32,), u64)] = &[
+fn to_u32(x: f32) -> u32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u32, u32), u32)] = &[
 "#
         }
 
@@ -1402,10 +10258,10 @@ static TEST_CASES: &[((u32,), u64)] = &[
 ];
 
 #[test]
-fn fixunssfdi() {
-    for &((a,), b) in TEST_CASES {
-        let b_ = __fixunssfdi(mk_f32(a));
-        assert_eq!(((a,), b), ((a,), b_));
+fn subsf3() {
+    for &((a, b), c) in TEST_CASES {
+        let c_ = __subsf3(mk_f32(a), mk_f32(b));
+        assert_eq!(((a, b), c), ((a, b), to_u32(c_)));
     }
 }
 "
@@ -1413,14 +10269,15 @@ fn fixunssfdi() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Fixunssfsi {
-        a: u32,  // f32
-        b: u32,
+    pub struct Subtf3 {
+        a: u128, // f128
+        b: u128, // f128
+        c: u128, // f128
     }
 
-    impl TestCase for Fixunssfsi {
+    impl TestCase for Subtf3 {
         fn name() -> &'static str {
-            "fixunssfsi"
+            "subtf3"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -1428,33 +10285,35 @@ fn fixunssfdi() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_f32(rng);
-            u32(a).ok().map(|b| Fixunssfsi { a: to_u32(a), b })
+            let a = gen_f128(rng);
+            let b = gen_f128(rng);
+            let c = f128_sub(a, b);
+            // TODO accept NaNs. We don't do that right now because we can't check
+            // for NaN-ness on the thumb targets (due to missing intrinsics)
+            if f128_is_nan(a) || f128_is_nan(b) || f128_is_nan(c) {
+                return None;
+            }
+
+            Some(Subtf3 { a, b, c })
         }
 
         fn to_string(&self, buffer: &mut String) {
-            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+            writeln!(
+                buffer,
+                "(({a}, {b}), {c}),",
+                a = self.a,
+                b = self.b,
+                c = self.c
+            )
+                    .unwrap();
         }
 
         fn prologue() -> &'static str {
             r#"
-#[cfg(all(target_arch = "arm",
-          not(any(target_env = "gnu", target_env = "musl")),
-          target_os = "linux",
-          test))]
-use core::mem;
-#[cfg(not(all(target_arch = "arm",
-              not(any(target_env = "gnu", target_env = "musl")),
-              target_os = "linux",
-              test)))]
-use std::mem;
-use compiler_builtins::float::conv::__fixunssfsi;
-
-fn mk_f32(x: u32) -> f32 {
-    unsafe { mem::transmute(x) }
-}
+use compiler_builtins::float::{Float, f128};
+use compiler_builtins::float::sub::__subtf3;
 
-static TEST_CASES: &[((u32,), u32)] = &[
+static TEST_CASES: &[((u128, u128), u128)] = &[
 "#
         }
 
@@ -1463,10 +10322,10 @@ static TEST_CASES: &[((u32,), u32)] = &[
 ];
 
 #[test]
-fn fixunssfsi() {
-    for &((a,), b) in TEST_CASES {
-        let b_ = __fixunssfsi(mk_f32(a));
-        assert_eq!(((a,), b), ((a,), b_));
+fn subtf3() {
+    for &((a, b), c) in TEST_CASES {
+        let c_ = __subtf3(f128::from_repr(a), f128::from_repr(b));
+        assert_eq!(((a, b), c), ((a, b), c_.repr()));
     }
 }
 "
@@ -1474,14 +10333,14 @@ fn fixunssfsi() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Fixunssfti {
-        a: u32,  // f32
-        b: u128,
+    pub struct Truncdfbf2 {
+        a: u64,  // f64
+        b: u16,  // bf16
     }
 
-    impl TestCase for Fixunssfti {
+    impl TestCase for Truncdfbf2 {
         fn name() -> &'static str {
-            "fixunssfti"
+            "truncdfbf2"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -1489,8 +10348,25 @@ fn fixunssfsi() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_f32(rng);
-            u128(a).ok().map(|b| Fixunssfti { a: to_u32(a), b })
+            let a = match rng.gen_range(0, 4) {
+                0 => gen_f64(rng),
+                1 => mk_f64(rng.gen()),
+                // In the range of `bf16`, from below its denormals to above
+                // its largest number
+                2 => {
+                    let exponent = rng.gen_range(1023 - 140, 1023 + 130);
+                    mk_f64(rng.gen::<u64>() & 0x800f_ffff_ffff_ffff | exponent << 52)
+                }
+                // Halfway between two `bf16`s
+                _ => mk_f32(rng.gen::<u32>() & 0xffff_0000 | 0x8000) as f64,
+            };
+            let b = if a.is_nan() {
+                float_convert_nan(to_u64(a) as u128, 52, 11, 7, 8) as u16
+            } else {
+                f64_to_bf16(a)
+            };
+
+            Some(Truncdfbf2 { a: to_u64(a), b })
         }
 
         fn to_string(&self, buffer: &mut String) {
@@ -1509,13 +10385,13 @@ use core::mem;
               target_os = "linux",
               test)))]
 use std::mem;
-use compiler_builtins::float::conv::__fixunssfti;
+use compiler_builtins::float::trunc::__truncdfbf2;
 
-fn mk_f32(x: u32) -> f32 {
+fn mk_f64(x: u64) -> f64 {
     unsafe { mem::transmute(x) }
 }
 
-static TEST_CASES: &[((u32,), u128)] = &[
+static TEST_CASES: &[((u64,), u16)] = &[
 "#
         }
 
@@ -1524,9 +10400,9 @@ static TEST_CASES: &[((u32,), u128)] = &[
 ];
 
 #[test]
-fn fixunssfti() {
+fn truncdfbf2() {
     for &((a,), b) in TEST_CASES {
-        let b_ = __fixunssfti(mk_f32(a));
+        let b_ = __truncdfbf2(mk_f64(a));
         assert_eq!(((a,), b), ((a,), b_));
     }
 }
@@ -1535,14 +10411,14 @@ fn fixunssfti() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Fixunsdfti  {
+    pub struct Truncdfhf2 {
         a: u64,  // f64
-        b: u128,
+        b: u16,  // f16
     }
 
-    impl TestCase for Fixunsdfti {
+    impl TestCase for Truncdfhf2 {
         fn name() -> &'static str {
-            "fixunsdfti"
+            "truncdfhf2"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -1551,7 +10427,18 @@ fn fixunssfti() {
             Self: Sized,
         {
             let a = gen_f64(rng);
-            u128(a).ok().map(|b| Fixunsdfti { a: to_u64(a), b })
+            // TODO accept NaNs. We don't do that right now because we can't check
+            // for NaN-ness on the thumb targets (due to missing intrinsics)
+            if a.is_nan() {
+                return None;
+            }
+
+            Some(
+                Truncdfhf2 {
+                    a: to_u64(a),
+                    b: f64_to_f16(a),
+                },
+            )
         }
 
         fn to_string(&self, buffer: &mut String) {
@@ -1570,13 +10457,13 @@ use core::mem;
               target_os = "linux",
               test)))]
 use std::mem;
-use compiler_builtins::float::conv::__fixunsdfti;
+use compiler_builtins::float::trunc::__truncdfhf2;
 
 fn mk_f64(x: u64) -> f64 {
     unsafe { mem::transmute(x) }
 }
 
-static TEST_CASES: &[((u64,), u128)] = &[
+static TEST_CASES: &[((u64,), u16)] = &[
 "#
         }
 
@@ -1585,9 +10472,9 @@ static TEST_CASES: &[((u64,), u128)] = &[
 ];
 
 #[test]
-fn fixunsdfti() {
+fn truncdfhf2() {
     for &((a,), b) in TEST_CASES {
-        let b_ = __fixunsdfti(mk_f64(a));
+        let b_ = __truncdfhf2(mk_f64(a));
         assert_eq!(((a,), b), ((a,), b_));
     }
 }
@@ -1596,14 +10483,14 @@ fn fixunsdfti() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Floatdidf {
-        a: i64,
-        b: u64, // f64
+    pub struct Truncdfsf2 {
+        a: u64,  // f64
+        b: u32,  // f32
     }
 
-    impl TestCase for Floatdidf {
+    impl TestCase for Truncdfsf2 {
         fn name() -> &'static str {
-            "floatdidf"
+            "truncdfsf2"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -1611,11 +10498,17 @@ fn fixunsdfti() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_i64(rng);
+            let a = gen_f64(rng);
+            let b = if a.is_nan() {
+                float_convert_nan(to_u64(a) as u128, 52, 11, 23, 8) as u32
+            } else {
+                to_u32(a as f32)
+            };
+
             Some(
-                Floatdidf {
-                    a,
-                    b: to_u64(f64(a)),
+                Truncdfsf2 {
+                    a: to_u64(a),
+                    b,
                 },
             )
         }
@@ -1636,13 +10529,17 @@ use core::mem;
               target_os = "linux",
               test)))]
 use std::mem;
-use compiler_builtins::float::conv::__floatdidf;
+use compiler_builtins::float::trunc::__truncdfsf2;
 
-fn to_u64(x: f64) -> u64 {
+fn mk_f64(x: u64) -> f64 {
     unsafe { mem::transmute(x) }
 }
 
-static TEST_CASES: &[((i64,), u64)] = &[
+fn to_u32(x: f32) -> u32 {
+    unsafe { mem::transmute(x) }
+}
+
+static TEST_CASES: &[((u64,), u32)] = &[
 "#
         }
 
@@ -1651,10 +10548,10 @@ static TEST_CASES: &[((i64,), u64)] = &[
 ];
 
 #[test]
-fn floatdidf() {
+fn truncdfsf2() {
     for &((a,), b) in TEST_CASES {
-        let b_ = __floatdidf(a);
-        assert_eq!(((a,), b), ((a,), to_u64(b_)));
+        let b_ = __truncdfsf2(mk_f64(a));
+        assert_eq!(((a,), b), ((a,), to_u32(b_)));
     }
 }
 "
@@ -1662,14 +10559,14 @@ fn floatdidf() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Floatsidf {
-        a: i32,
-        b: u64, // f64
+    pub struct Truncsfhf2 {
+        a: u32,  // f32
+        b: u16,  // f16
     }
 
-    impl TestCase for Floatsidf {
+    impl TestCase for Truncsfhf2 {
         fn name() -> &'static str {
-            "floatsidf"
+            "truncsfhf2"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -1677,11 +10574,17 @@ fn floatdidf() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_i32(rng);
+            let a = gen_f32(rng);
+            // TODO accept NaNs. We don't do that right now because we can't check
+            // for NaN-ness on the thumb targets (due to missing intrinsics)
+            if a.is_nan() {
+                return None;
+            }
+
             Some(
-                Floatsidf {
-                    a,
-                    b: to_u64(f64(a)),
+                Truncsfhf2 {
+                    a: to_u32(a),
+                    b: f64_to_f16(a as f64),
                 },
             )
         }
@@ -1702,13 +10605,13 @@ use core::mem;
               target_os = "linux",
               test)))]
 use std::mem;
-use compiler_builtins::float::conv::__floatsidf;
+use compiler_builtins::float::trunc::__truncsfhf2;
 
-fn to_u64(x: f64) -> u64 {
+fn mk_f32(x: u32) -> f32 {
     unsafe { mem::transmute(x) }
 }
 
-static TEST_CASES: &[((i32,), u64)] = &[
+static TEST_CASES: &[((u32,), u16)] = &[
 "#
         }
 
@@ -1717,10 +10620,10 @@ static TEST_CASES: &[((i32,), u64)] = &[
 ];
 
 #[test]
-fn floatsidf() {
+fn truncsfhf2() {
     for &((a,), b) in TEST_CASES {
-        let b_ = __floatsidf(a);
-        assert_eq!(((a,), b), ((a,), to_u64(b_)));
+        let b_ = __truncsfhf2(mk_f32(a));
+        assert_eq!(((a,), b), ((a,), b_));
     }
 }
 "
@@ -1728,14 +10631,14 @@ fn floatsidf() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Floatsisf {
-        a: i32,
-        b: u32, // f32
+    pub struct Truncsfbf2 {
+        a: u32,  // f32
+        b: u16,  // bf16
     }
 
-    impl TestCase for Floatsisf {
+    impl TestCase for Truncsfbf2 {
         fn name() -> &'static str {
-            "floatsisf"
+            "truncsfbf2"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -1743,13 +10646,20 @@ fn floatsidf() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_i32(rng);
-            Some(
-                Floatsisf {
-                    a,
-                    b: to_u32(f32(a)),
-                },
-            )
+            let a = match rng.gen_range(0, 3) {
+                0 => gen_f32(rng),
+                1 => mk_f32(rng.gen()),
+                // Halfway between two `bf16`s, or a NaN whose payload is
+                // truncated away
+                _ => mk_f32(rng.gen::<u32>() & 0xffff_0000 | 0x8000),
+            };
+            let b = if a.is_nan() {
+                float_convert_nan(to_u32(a) as u128, 23, 8, 7, 8) as u16
+            } else {
+                f64_to_bf16(a as f64)
+            };
+
+            Some(Truncsfbf2 { a: to_u32(a), b })
         }
 
         fn to_string(&self, buffer: &mut String) {
@@ -1768,13 +10678,13 @@ use core::mem;
               target_os = "linux",
               test)))]
 use std::mem;
-use compiler_builtins::float::conv::__floatsisf;
+use compiler_builtins::float::trunc::__truncsfbf2;
 
-fn to_u32(x: f32) -> u32 {
+fn mk_f32(x: u32) -> f32 {
     unsafe { mem::transmute(x) }
 }
 
-static TEST_CASES: &[((i32,), u32)] = &[
+static TEST_CASES: &[((u32,), u16)] = &[
 "#
         }
 
@@ -1783,10 +10693,10 @@ static TEST_CASES: &[((i32,), u32)] = &[
 ];
 
 #[test]
-fn floatsisf() {
+fn truncsfbf2() {
     for &((a,), b) in TEST_CASES {
-        let b_ = __floatsisf(a);
-        assert_eq!(((a,), b), ((a,), to_u32(b_)));
+        let b_ = __truncsfbf2(mk_f32(a));
+        assert_eq!(((a,), b), ((a,), b_));
     }
 }
 "
@@ -1794,14 +10704,14 @@ fn floatsisf() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Floattisf {
-        a: i128,
-        b: u32, // f32
+    pub struct Trunctfdf2 {
+        a: u128, // f128,
+        b: u64,  // f64,
     }
 
-    impl TestCase for Floattisf {
+    impl TestCase for Trunctfdf2 {
         fn name() -> &'static str {
-            "floattisf"
+            "trunctfdf2"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -1809,13 +10719,14 @@ fn floatsisf() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_i128(rng);
-            Some(
-                Floattisf {
-                    a,
-                    b: to_u32(f32(a)),
-                },
-            )
+            let a = gen_f128(rng);
+            // TODO accept NaNs. We don't do that right now because we can't check
+            // for NaN-ness on the thumb targets (due to missing intrinsics)
+            if f128_is_nan(a) {
+                return None;
+            }
+
+            Some(Trunctfdf2 { a, b: f128_to_f64(a) })
         }
 
         fn to_string(&self, buffer: &mut String) {
@@ -1834,13 +10745,13 @@ use core::mem;
               target_os = "linux",
               test)))]
 use std::mem;
-use compiler_builtins::float::conv::__floattisf;
+use compiler_builtins::float::{Float, f128};
+use compiler_builtins::float::trunc::__trunctfdf2;
 
-fn to_u32(x: f32) -> u32 {
+fn to_u64(x: f64) -> u64 {
     unsafe { mem::transmute(x) }
 }
-
-static TEST_CASES: &[((i128,), u32)] = &[
+static TEST_CASES: &[((u128,), u64)] = &[
 "#
         }
 
@@ -1849,10 +10760,10 @@ static TEST_CASES: &[((i128,), u32)] = &[
 ];
 
 #[test]
-fn floattisf() {
+fn trunctfdf2() {
     for &((a,), b) in TEST_CASES {
-        let b_ = __floattisf(a);
-        assert_eq!(((a,), b), ((a,), to_u32(b_)));
+        let b_ = to_u64(__trunctfdf2(f128::from_repr(a)));
+        assert_eq!(((a,), b), ((a,), b_));
     }
 }
 "
@@ -1860,14 +10771,14 @@ fn floattisf() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Floattidf {
-        a: i128,
-        b: u64, // f64
+    pub struct Trunctfsf2 {
+        a: u128, // f128,
+        b: u32,  // f32,
     }
 
-    impl TestCase for Floattidf {
+    impl TestCase for Trunctfsf2 {
         fn name() -> &'static str {
-            "floattidf"
+            "trunctfsf2"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -1875,13 +10786,14 @@ fn floattisf() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_i128(rng);
-            Some(
-                Floattidf {
-                    a,
-                    b: to_u64(f64(a)),
-                },
-            )
+            let a = gen_f128(rng);
+            // TODO accept NaNs. We don't do that right now because we can't check
+            // for NaN-ness on the thumb targets (due to missing intrinsics)
+            if f128_is_nan(a) {
+                return None;
+            }
+
+            Some(Trunctfsf2 { a, b: f128_to_f32(a) })
         }
 
         fn to_string(&self, buffer: &mut String) {
@@ -1900,13 +10812,13 @@ use core::mem;
               target_os = "linux",
               test)))]
 use std::mem;
-use compiler_builtins::float::conv::__floattidf;
+use compiler_builtins::float::{Float, f128};
+use compiler_builtins::float::trunc::__trunctfsf2;
 
-fn to_u64(x: f64) -> u64 {
+fn to_u32(x: f32) -> u32 {
     unsafe { mem::transmute(x) }
 }
-
-static TEST_CASES: &[((i128,), u64)] = &[
+static TEST_CASES: &[((u128,), u32)] = &[
 "#
         }
 
@@ -1915,12 +10827,10 @@ static TEST_CASES: &[((i128,), u64)] = &[
 ];
 
 #[test]
-fn floattidf() {
+fn trunctfsf2() {
     for &((a,), b) in TEST_CASES {
-        let b_ = __floattidf(a);
-        let g_b = to_u64(b_);
-        let diff = if g_b > b { g_b - b } else { b - g_b };
-        assert_eq!(((a,), b, g_b, true), ((a,), b, g_b, diff <= 1));
+        let b_ = to_u32(__trunctfsf2(f128::from_repr(a)));
+        assert_eq!(((a,), b), ((a,), b_));
     }
 }
 "
@@ -1928,14 +10838,15 @@ fn floattidf() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Floatundidf {
+    pub struct Udivdi3 {
         a: u64,
-        b: u64, // f64
+        b: u64,
+        c: u64,
     }
 
-    impl TestCase for Floatundidf {
+    impl TestCase for Udivdi3 {
         fn name() -> &'static str {
-            "floatundidf"
+            "udivdi3"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -1944,38 +10855,96 @@ fn floattidf() {
             Self: Sized,
         {
             let a = gen_u64(rng);
-            Some(
-                Floatundidf {
-                    a,
-                    b: to_u64(f64(a)),
-                },
-            )
+            let b = gen_u64(rng);
+            if b == 0 {
+                return None;
+            }
+            let c = a / b;
+
+            Some(Udivdi3 { a, b, c })
         }
 
         fn to_string(&self, buffer: &mut String) {
-            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+            writeln!(
+                buffer,
+                "(({a}, {b}), {c}),",
+                a = self.a,
+                b = self.b,
+                c = self.c
+            )
+                    .unwrap();
         }
 
         fn prologue() -> &'static str {
-            r#"
-#[cfg(all(target_arch = "arm",
-          not(any(target_env = "gnu", target_env = "musl")),
-          target_os = "linux",
-          test))]
-use core::mem;
-#[cfg(not(all(target_arch = "arm",
-              not(any(target_env = "gnu", target_env = "musl")),
-              target_os = "linux",
-              test)))]
-use std::mem;
-use compiler_builtins::float::conv::__floatundidf;
+            "
+use compiler_builtins::int::udiv::__udivdi3;
 
-fn to_u64(x: f64) -> u64 {
-    unsafe { mem::transmute(x) }
+static TEST_CASES: &[((u64, u64), u64)] = &[
+"
+        }
+
+        fn epilogue() -> &'static str {
+            "
+];
+
+#[test]
+fn udivdi3() {
+    for &((a, b), c) in TEST_CASES {
+        let c_ = __udivdi3(a, b);
+        assert_eq!(((a, b), c), ((a, b), c_));
+    }
 }
+"
+        }
+    }
 
-static TEST_CASES: &[((u64,), u64)] = &[
-"#
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Udivmoddi4 {
+        a: u64,
+        b: u64,
+        c: u64,
+        rem: u64,
+    }
+
+    impl TestCase for Udivmoddi4 {
+        fn name() -> &'static str {
+            "udivmoddi4"
+        }
+
+        fn generate<R>(rng: &mut R) -> Option<Self>
+        where
+            R: Rng,
+            Self: Sized,
+        {
+            let a = gen_u64(rng);
+            let b = gen_u64(rng);
+            if b == 0 {
+                return None;
+            }
+            let c = a / b;
+            let rem = a % b;
+
+            Some(Udivmoddi4 { a, b, c, rem })
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(
+                buffer,
+                "(({a}, {b}), ({c}, {rem})),",
+                a = self.a,
+                b = self.b,
+                c = self.c,
+                rem = self.rem
+            )
+                    .unwrap();
+        }
+
+        fn prologue() -> &'static str {
+            "
+use compiler_builtins::int::udiv::__udivmoddi4;
+
+static TEST_CASES: &[((u64, u64), (u64, u64))] = &[
+"
         }
 
         fn epilogue() -> &'static str {
@@ -1983,10 +10952,11 @@ static TEST_CASES: &[((u64,), u64)] = &[
 ];
 
 #[test]
-fn floatundidf() {
-    for &((a,), b) in TEST_CASES {
-        let b_ = __floatundidf(a);
-        assert_eq!(((a,), b), ((a,), to_u64(b_)));
+fn udivmoddi4() {
+    for &((a, b), (c, rem)) in TEST_CASES {
+        let mut rem_ = 0;
+        let c_ = __udivmoddi4(a, b, Some(&mut rem_));
+        assert_eq!(((a, b), (c, rem)), ((a, b), (c_, rem_)));
     }
 }
 "
@@ -1994,14 +10964,16 @@ fn floatundidf() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Floatunsidf {
+    pub struct Udivmodsi4 {
         a: u32,
-        b: u64, // f64
+        b: u32,
+        c: u32,
+        rem: u32,
     }
 
-    impl TestCase for Floatunsidf {
+    impl TestCase for Udivmodsi4 {
         fn name() -> &'static str {
-            "floatunsidf"
+            "udivmodsi4"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -2010,38 +10982,34 @@ fn floatundidf() {
             Self: Sized,
         {
             let a = gen_u32(rng);
-            Some(
-                Floatunsidf {
-                    a,
-                    b: to_u64(f64(a)),
-                },
-            )
+            let b = gen_u32(rng);
+            if b == 0 {
+                return None;
+            }
+            let c = a / b;
+            let rem = a % b;
+
+            Some(Udivmodsi4 { a, b, c, rem })
         }
 
         fn to_string(&self, buffer: &mut String) {
-            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+            writeln!(
+                buffer,
+                "(({a}, {b}), ({c}, {rem})),",
+                a = self.a,
+                b = self.b,
+                c = self.c,
+                rem = self.rem
+            )
+                    .unwrap();
         }
 
         fn prologue() -> &'static str {
-            r#"
-#[cfg(all(target_arch = "arm",
-          not(any(target_env = "gnu", target_env = "musl")),
-          target_os = "linux",
-          test))]
-use core::mem;
-#[cfg(not(all(target_arch = "arm",
-              not(any(target_env = "gnu", target_env = "musl")),
-              target_os = "linux",
-              test)))]
-use std::mem;
-use compiler_builtins::float::conv::__floatunsidf;
-
-fn to_u64(x: f64) -> u64 {
-    unsafe { mem::transmute(x) }
-}
+            "
+use compiler_builtins::int::udiv::__udivmodsi4;
 
-static TEST_CASES: &[((u32,), u64)] = &[
-"#
+static TEST_CASES: &[((u32, u32), (u32, u32))] = &[
+"
         }
 
         fn epilogue() -> &'static str {
@@ -2049,10 +11017,11 @@ static TEST_CASES: &[((u32,), u64)] = &[
 ];
 
 #[test]
-fn floatunsidf() {
-    for &((a,), b) in TEST_CASES {
-        let b_ = __floatunsidf(a);
-        assert_eq!(((a,), b), ((a,), to_u64(b_)));
+fn udivmodsi4() {
+    for &((a, b), (c, rem)) in TEST_CASES {
+        let mut rem_ = 0;
+        let c_ = __udivmodsi4(a, b, Some(&mut rem_));
+        assert_eq!(((a, b), (c, rem)), ((a, b), (c_, rem_)));
     }
 }
 "
@@ -2060,14 +11029,16 @@ fn floatunsidf() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Floatunsisf {
-        a: u32,
-        b: u32, // f32
+    pub struct Udivmodti4 {
+        a: u128,
+        b: u128,
+        c: u128,
+        rem: u128,
     }
 
-    impl TestCase for Floatunsisf {
+    impl TestCase for Udivmodti4 {
         fn name() -> &'static str {
-            "floatunsisf"
+            "udivmodti4"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -2075,39 +11046,35 @@ fn floatunsidf() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_u32(rng);
-            Some(
-                Floatunsisf {
-                    a,
-                    b: to_u32(f32(a)),
-                },
-            )
+            let a = gen_u128(rng);
+            let b = gen_u128(rng);
+            if b == 0 {
+                return None;
+            }
+            let c = a / b;
+            let rem = a % b;
+
+            Some(Udivmodti4 { a, b, c, rem })
         }
 
         fn to_string(&self, buffer: &mut String) {
-            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+            writeln!(
+                buffer,
+                "(({a}, {b}), ({c}, {rem})),",
+                a = self.a,
+                b = self.b,
+                c = self.c,
+                rem = self.rem
+            )
+                    .unwrap();
         }
 
         fn prologue() -> &'static str {
-            r#"
-#[cfg(all(target_arch = "arm",
-          not(any(target_env = "gnu", target_env = "musl")),
-          target_os = "linux",
-          test))]
-use core::mem;
-#[cfg(not(all(target_arch = "arm",
-              not(any(target_env = "gnu", target_env = "musl")),
-              target_os = "linux",
-              test)))]
-use std::mem;
-use compiler_builtins::float::conv::__floatunsisf;
-
-fn to_u32(x: f32) -> u32 {
-    unsafe { mem::transmute(x) }
-}
+            "
+use compiler_builtins::int::udiv::__udivmodti4;
 
-static TEST_CASES: &[((u32,), u32)] = &[
-"#
+static TEST_CASES: &[((u128, u128), (u128, u128))] = &[
+"
         }
 
         fn epilogue() -> &'static str {
@@ -2115,10 +11082,11 @@ static TEST_CASES: &[((u32,), u32)] = &[
 ];
 
 #[test]
-fn floatunsisf() {
-    for &((a,), b) in TEST_CASES {
-        let b_ = __floatunsisf(a);
-        assert_eq!(((a,), b), ((a,), to_u32(b_)));
+fn udivmodti4() {
+    for &((a, b), (c, rem)) in TEST_CASES {
+        let mut rem_ = 0;
+        let c_ = __udivmodti4(a, b, Some(&mut rem_));
+        assert_eq!(((a, b), (c, rem)), ((a, b), (c_, rem_)));
     }
 }
 "
@@ -2126,14 +11094,15 @@ fn floatunsisf() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Floatuntisf {
-        a: u128,
-        b: u32, // f32
+    pub struct Udivsi3 {
+        a: u32,
+        b: u32,
+        c: u32,
     }
 
-    impl TestCase for Floatuntisf {
+    impl TestCase for Udivsi3 {
         fn name() -> &'static str {
-            "floatuntisf"
+            "udivsi3"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -2141,40 +11110,33 @@ fn floatunsisf() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_u128(rng);
-            let f_a = f32(a);
-            f_a.ok().map(|f| {
-                Floatuntisf {
-                    a,
-                    b: to_u32(f),
-                }
-            })
+            let a = gen_u32(rng);
+            let b = gen_u32(rng);
+            if b == 0 {
+                return None;
+            }
+            let c = a / b;
+
+            Some(Udivsi3 { a, b, c })
         }
 
         fn to_string(&self, buffer: &mut String) {
-            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+            writeln!(
+                buffer,
+                "(({a}, {b}), {c}),",
+                a = self.a,
+                b = self.b,
+                c = self.c
+            )
+                    .unwrap();
         }
 
         fn prologue() -> &'static str {
-            r#"
-#[cfg(all(target_arch = "arm",
-          not(any(target_env = "gnu", target_env = "musl")),
-          target_os = "linux",
-          test))]
-use core::mem;
-#[cfg(not(all(target_arch = "arm",
-              not(any(target_env = "gnu", target_env = "musl")),
-              target_os = "linux",
-              test)))]
-use std::mem;
-use compiler_builtins::float::conv::__floatuntisf;
-
-fn to_u32(x: f32) -> u32 {
-    unsafe { mem::transmute(x) }
-}
+            "
+use compiler_builtins::int::udiv::__udivsi3;
 
-static TEST_CASES: &[((u128,), u32)] = &[
-"#
+static TEST_CASES: &[((u32, u32), u32)] = &[
+"
         }
 
         fn epilogue() -> &'static str {
@@ -2182,10 +11144,10 @@ static TEST_CASES: &[((u128,), u32)] = &[
 ];
 
 #[test]
-fn floatuntisf() {
-    for &((a,), b) in TEST_CASES {
-        let b_ = __floatuntisf(a);
-        assert_eq!(((a,), b), ((a,), to_u32(b_)));
+fn udivsi3() {
+    for &((a, b), c) in TEST_CASES {
+        let c_ = __udivsi3(a, b);
+        assert_eq!(((a, b), c), ((a, b), c_));
     }
 }
 "
@@ -2193,14 +11155,15 @@ fn floatuntisf() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Floatuntidf {
+    pub struct Udivti3 {
         a: u128,
-        b: u64, // f64
+        b: u128,
+        c: u128,
     }
 
-    impl TestCase for Floatuntidf {
+    impl TestCase for Udivti3 {
         fn name() -> &'static str {
-            "floatuntidf"
+            "udivti3"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -2209,38 +11172,32 @@ fn floatuntisf() {
             Self: Sized,
         {
             let a = gen_u128(rng);
-            Some(
-                Floatuntidf {
-                    a,
-                    b: to_u64(f64(a)),
-                },
-            )
+            let b = gen_u128(rng);
+            if b == 0 {
+                return None;
+            }
+            let c = a / b;
+
+            Some(Udivti3 { a, b, c })
         }
 
         fn to_string(&self, buffer: &mut String) {
-            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
+            writeln!(
+                buffer,
+                "(({a}, {b}), {c}),",
+                a = self.a,
+                b = self.b,
+                c = self.c
+            )
+                    .unwrap();
         }
 
         fn prologue() -> &'static str {
-            r#"
-#[cfg(all(target_arch = "arm",
-          not(any(target_env = "gnu", target_env = "musl")),
-          target_os = "linux",
-          test))]
-use core::mem;
-#[cfg(not(all(target_arch = "arm",
-              not(any(target_env = "gnu", target_env = "musl")),
-              target_os = "linux",
-              test)))]
-use std::mem;
-use compiler_builtins::float::conv::__floatuntidf;
-
-fn to_u64(x: f64) -> u64 {
-    unsafe { mem::transmute(x) }
-}
+            "
+use compiler_builtins::int::udiv::__udivti3;
 
-static TEST_CASES: &[((u128,), u64)] = &[
-"#
+static TEST_CASES: &[((u128, u128), u128)] = &[
+"
         }
 
         fn epilogue() -> &'static str {
@@ -2248,12 +11205,10 @@ static TEST_CASES: &[((u128,), u64)] = &[
 ];
 
 #[test]
-fn floatuntidf() {
-    for &((a,), b) in TEST_CASES {
-        let b_ = __floatuntidf(a);
-        let g_b = to_u64(b_);
-        let diff = if g_b > b { g_b - b } else { b - g_b };
-        assert_eq!(((a,), b, g_b, true), ((a,), b, g_b, diff <= 1));
+fn udivti3() {
+    for &((a, b), c) in TEST_CASES {
+        let c_ = __udivti3(a, b);
+        assert_eq!(((a, b), c), ((a, b), c_));
     }
 }
 "
@@ -2261,15 +11216,15 @@ fn floatuntidf() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Moddi3 {
-        a: i64,
-        b: i64,
-        c: i64,
+    pub struct Umoddi3 {
+        a: u64,
+        b: u64,
+        c: u64,
     }
 
-    impl TestCase for Moddi3 {
+    impl TestCase for Umoddi3 {
         fn name() -> &'static str {
-            "moddi3"
+            "umoddi3"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -2277,14 +11232,14 @@ fn floatuntidf() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_i64(rng);
-            let b = gen_i64(rng);
+            let a = gen_u64(rng);
+            let b = gen_u64(rng);
             if b == 0 {
                 return None;
             }
             let c = a % b;
 
-            Some(Moddi3 { a, b, c })
+            Some(Umoddi3 { a, b, c })
         }
 
         fn to_string(&self, buffer: &mut String) {
@@ -2300,9 +11255,9 @@ fn floatuntidf() {
 
         fn prologue() -> &'static str {
             "
-use compiler_builtins::int::sdiv::__moddi3;
+use compiler_builtins::int::udiv::__umoddi3;
 
-static TEST_CASES: &[((i64, i64), i64)] = &[
+static TEST_CASES: &[((u64, u64), u64)] = &[
 "
         }
 
@@ -2311,9 +11266,9 @@ static TEST_CASES: &[((i64, i64), i64)] = &[
 ];
 
 #[test]
-fn moddi3() {
+fn umoddi3() {
     for &((a, b), c) in TEST_CASES {
-        let c_ = __moddi3(a, b);
+        let c_ = __umoddi3(a, b);
         assert_eq!(((a, b), c), ((a, b), c_));
     }
 }
@@ -2322,15 +11277,15 @@ fn moddi3() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Modsi3 {
-        a: i32,
-        b: i32,
-        c: i32,
+    pub struct Umodsi3 {
+        a: u32,
+        b: u32,
+        c: u32,
     }
 
-    impl TestCase for Modsi3 {
+    impl TestCase for Umodsi3 {
         fn name() -> &'static str {
-            "modsi3"
+            "umodsi3"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -2338,14 +11293,14 @@ fn moddi3() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_i32(rng);
-            let b = gen_i32(rng);
+            let a = gen_u32(rng);
+            let b = gen_u32(rng);
             if b == 0 {
                 return None;
             }
             let c = a % b;
 
-            Some(Modsi3 { a, b, c })
+            Some(Umodsi3 { a, b, c })
         }
 
         fn to_string(&self, buffer: &mut String) {
@@ -2361,9 +11316,9 @@ fn moddi3() {
 
         fn prologue() -> &'static str {
             "
-use compiler_builtins::int::sdiv::__modsi3;
+use compiler_builtins::int::udiv::__umodsi3;
 
-static TEST_CASES: &[((i32, i32), i32)] = &[
+static TEST_CASES: &[((u32, u32), u32)] = &[
 "
         }
 
@@ -2372,9 +11327,9 @@ static TEST_CASES: &[((i32, i32), i32)] = &[
 ];
 
 #[test]
-fn modsi3() {
+fn umodsi3() {
     for &((a, b), c) in TEST_CASES {
-        let c_ = __modsi3(a, b);
+        let c_ = __umodsi3(a, b);
         assert_eq!(((a, b), c), ((a, b), c_));
     }
 }
@@ -2383,15 +11338,15 @@ fn modsi3() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Modti3 {
-        a: i128,
-        b: i128,
-        c: i128,
+    pub struct Umodti3 {
+        a: u128,
+        b: u128,
+        c: u128,
     }
 
-    impl TestCase for Modti3 {
+    impl TestCase for Umodti3 {
         fn name() -> &'static str {
-            "modti3"
+            "umodti3"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -2399,14 +11354,14 @@ fn modsi3() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_i128(rng);
-            let b = gen_i128(rng);
+            let a = gen_u128(rng);
+            let b = gen_u128(rng);
             if b == 0 {
                 return None;
             }
             let c = a % b;
 
-            Some(Modti3 { a, b, c })
+            Some(Umodti3 { a, b, c })
         }
 
         fn to_string(&self, buffer: &mut String) {
@@ -2422,9 +11377,9 @@ fn modsi3() {
 
         fn prologue() -> &'static str {
             "
-use compiler_builtins::int::sdiv::__modti3;
+use compiler_builtins::int::udiv::__umodti3;
 
-static TEST_CASES: &[((i128, i128), i128)] = &[
+static TEST_CASES: &[((u128, u128), u128)] = &[
 "
         }
 
@@ -2433,9 +11388,9 @@ static TEST_CASES: &[((i128, i128), i128)] = &[
 ];
 
 #[test]
-fn modti3() {
+fn umodti3() {
     for &((a, b), c) in TEST_CASES {
-        let c_ = __modti3(a, b);
+        let c_ = __umodti3(a, b);
         assert_eq!(((a, b), c), ((a, b), c_));
     }
 }
@@ -2444,15 +11399,16 @@ fn modti3() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    struct Muldi3 {
-        a: u64,
-        b: u64,
-        c: u64,
+    pub struct Fma {
+        a: u64, // f64
+        b: u64, // f64
+        c: u64, // f64
+        d: u64, // f64
     }
 
-    impl TestCase for Muldi3 {
+    impl TestCase for Fma {
         fn name() -> &'static str {
-            "muldi3"
+            "fma"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -2460,30 +11416,66 @@ fn modti3() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_u64(rng);
-            let b = gen_u64(rng);
-            let c = a.wrapping_mul(b);
+            // `gen_f64` mostly returns denormals, also try any bit pattern
+            let a = if rng.gen() { gen_f64(rng) } else { mk_f64(rng.gen()) };
+            let b = if rng.gen() { gen_f64(rng) } else { mk_f64(rng.gen()) };
+            // Also cancel most of the product, so that its rounding error
+            // is the result
+            let c = if rng.gen() { gen_f64(rng) } else { -(a * b) };
+            // The host libm is the reference
+            let d = a.mul_add(b, c);
 
-            Some(Muldi3 { a, b, c })
+            Some(
+                Fma {
+                    a: to_u64(a),
+                    b: to_u64(b),
+                    c: to_u64(c),
+                    d: to_u64(d),
+                },
+            )
         }
 
         fn to_string(&self, buffer: &mut String) {
             writeln!(
                 buffer,
-                "(({a}, {b}), {c}),",
+                "(({a}, {b}, {c}), {d}),",
                 a = self.a,
                 b = self.b,
-                c = self.c
+                c = self.c,
+                d = self.d
             )
                     .unwrap();
         }
 
         fn prologue() -> &'static str {
-            "
-use compiler_builtins::int::mul::__muldi3;
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::math;
 
-static TEST_CASES: &[((u64, u64), u64)] = &[
-"
+fn mk_f64(x: u64) -> f64 {
+    unsafe { mem::transmute(x) }
+}
+
+fn to_u64(x: f64) -> u64 {
+    unsafe { mem::transmute(x) }
+}
+
+// Any NaN is accepted for a NaN result
+fn canonical(x: u64) -> u64 {
+    if x & !(1 << 63) > 0x7ff0_0000_0000_0000 { 0x7ff8_0000_0000_0000 } else { x }
+}
+
+static TEST_CASES: &[((u64, u64, u64), u64)] = &[
+"#
         }
 
         fn epilogue() -> &'static str {
@@ -2491,10 +11483,10 @@ static TEST_CASES: &[((u64, u64), u64)] = &[
 ];
 
 #[test]
-fn muldi3() {
-    for &((a, b), c) in TEST_CASES {
-        let c_ = __muldi3(a, b);
-        assert_eq!(((a, b), c), ((a, b), c_));
+fn fma() {
+    for &((a, b, c), d) in TEST_CASES {
+        let d_ = math::fma(mk_f64(a), mk_f64(b), mk_f64(c));
+        assert_eq!(((a, b, c), canonical(d)), ((a, b, c), canonical(to_u64(d_))));
     }
 }
 "
@@ -2502,48 +11494,83 @@ fn muldi3() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Mulodi4 {
-        a: i64,
-        b: i64,
-        c: i64,
-        overflow: u32,
+    pub struct Fmaf {
+        a: u32, // f32
+        b: u32, // f32
+        c: u32, // f32
+        d: u32, // f32
     }
 
-    impl TestCase for Mulodi4 {
+    impl TestCase for Fmaf {
         fn name() -> &'static str {
-            "mulodi4"
+            "fmaf"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
         where
             R: Rng,
+            Self: Sized,
         {
-            let a = gen_i64(rng);
-            let b = gen_i64(rng);
-            let c = a.wrapping_mul(b);
-            let overflow = if a.checked_mul(b).is_some() { 0 } else { 1 };
+            // `gen_f32` mostly returns denormals, also try any bit pattern
+            let a = if rng.gen() { gen_f32(rng) } else { mk_f32(rng.gen()) };
+            let b = if rng.gen() { gen_f32(rng) } else { mk_f32(rng.gen()) };
+            // Also cancel most of the product, so that its rounding error
+            // is the result
+            let c = if rng.gen() { gen_f32(rng) } else { -(a * b) };
+            // The host libm is the reference
+            let d = a.mul_add(b, c);
 
-            Some(Mulodi4 { a, b, c, overflow })
+            Some(
+                Fmaf {
+                    a: to_u32(a),
+                    b: to_u32(b),
+                    c: to_u32(c),
+                    d: to_u32(d),
+                },
+            )
         }
 
         fn to_string(&self, buffer: &mut String) {
             writeln!(
                 buffer,
-                "(({a}, {b}), ({c}, {overflow})),",
+                "(({a}, {b}, {c}), {d}),",
                 a = self.a,
                 b = self.b,
                 c = self.c,
-                overflow = self.overflow
+                d = self.d
             )
                     .unwrap();
         }
 
         fn prologue() -> &'static str {
-            "
-use compiler_builtins::int::mul::__mulodi4;
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::math;
 
-static TEST_CASES: &[((i64, i64), (i64, i32))] = &[
-"
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+fn to_u32(x: f32) -> u32 {
+    unsafe { mem::transmute(x) }
+}
+
+// Any NaN is accepted for a NaN result
+fn canonical(x: u32) -> u32 {
+    if x & !(1 << 31) > 0x7f80_0000 { 0x7fc0_0000 } else { x }
+}
+
+static TEST_CASES: &[((u32, u32, u32), u32)] = &[
+"#
         }
 
         fn epilogue() -> &'static str {
@@ -2551,11 +11578,10 @@ static TEST_CASES: &[((i64, i64), (i64, i32))] = &[
 ];
 
 #[test]
-fn mulodi4() {
-    let mut overflow_ = 2;
-    for &((a, b), (c, overflow)) in TEST_CASES {
-        let c_ = __mulodi4(a, b, &mut overflow_);
-        assert_eq!(((a, b), (c, overflow)), ((a, b), (c_, overflow_)));
+fn fmaf() {
+    for &((a, b, c), d) in TEST_CASES {
+        let d_ = math::fmaf(mk_f32(a), mk_f32(b), mk_f32(c));
+        assert_eq!(((a, b, c), canonical(d)), ((a, b, c), canonical(to_u32(d_))));
     }
 }
 "
@@ -2563,36 +11589,68 @@ fn mulodi4() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Mulosi4 {
-        a: i32,
-        b: i32,
-        c: i32,
-        overflow: u32,
+    pub struct Sqrt {
+        a: u64, // f64
+        b: u64, // f64
     }
 
-    impl TestCase for Mulosi4 {
+    impl TestCase for Sqrt {
         fn name() -> &'static str {
-            "mulosi4"
+            "sqrt"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
         where
             R: Rng,
+            Self: Sized,
         {
-            let a = gen_i32(rng);
-            let b = gen_i32(rng);
-            let c = a.wrapping_mul(b);
-            let overflow = if a.checked_mul(b).is_some() { 0 } else { 1 };
+            // `gen_f64` mostly returns denormals, also try any bit pattern
+            let a = if rng.gen() { gen_f64(rng) } else { mk_f64(rng.gen()) };
+            // Also try perfect squares
+            let a = if rng.gen_weighted_bool(4) { a.sqrt().round() * a.sqrt().round() } else { a };
+            let b = a.sqrt();
 
-            Some(Mulosi4 { a, b, c, overflow })
+            Some(
+                Sqrt {
+                    a: to_u64(a),
+                    b: to_u64(b),
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
         }
 
         fn prologue() -> &'static str {
-            "
-use compiler_builtins::int::mul::__mulosi4;
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::math;
 
-static TEST_CASES: &[((i32, i32), (i32, i32))] = &[
-"
+fn mk_f64(x: u64) -> f64 {
+    unsafe { mem::transmute(x) }
+}
+
+fn to_u64(x: f64) -> u64 {
+    unsafe { mem::transmute(x) }
+}
+
+// Any NaN is accepted for a NaN result
+fn canonical(x: u64) -> u64 {
+    if x & !(1 << 63) > 0x7ff0_0000_0000_0000 { 0x7ff8_0000_0000_0000 } else { x }
+}
+
+static TEST_CASES: &[((u64,), u64)] = &[
+"#
         }
 
         fn epilogue() -> &'static str {
@@ -2600,60 +11658,79 @@ static TEST_CASES: &[((i32, i32), (i32, i32))] = &[
 ];
 
 #[test]
-fn mulosi4() {
-    let mut overflow_ = 2;
-    for &((a, b), (c, overflow)) in TEST_CASES {
-        let c_ = __mulosi4(a, b, &mut overflow_);
-        assert_eq!(((a, b), (c, overflow)), ((a, b), (c_, overflow_)));
+fn sqrt() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = math::sqrt(mk_f64(a));
+        assert_eq!(((a,), canonical(b)), ((a,), canonical(to_u64(b_))));
     }
 }
 "
         }
-
-        fn to_string(&self, buffer: &mut String) {
-            writeln!(
-                buffer,
-                "(({a}, {b}), ({c}, {overflow})),",
-                a = self.a,
-                b = self.b,
-                c = self.c,
-                overflow = self.overflow
-            )
-                    .unwrap();
-        }
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Muloti4 {
-        a: i128,
-        b: i128,
-        c: i128,
-        overflow: u32,
+    pub struct Sqrtf {
+        a: u32, // f32
+        b: u32, // f32
     }
 
-    impl TestCase for Muloti4 {
+    impl TestCase for Sqrtf {
         fn name() -> &'static str {
-            "muloti4"
+            "sqrtf"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
         where
             R: Rng,
+            Self: Sized,
         {
-            let a = gen_i128(rng);
-            let b = gen_i128(rng);
-            let c = a.wrapping_mul(b);
-            let overflow = if a.checked_mul(b).is_some() { 0 } else { 1 };
+            // `gen_f32` mostly returns denormals, also try any bit pattern
+            let a = if rng.gen() { gen_f32(rng) } else { mk_f32(rng.gen()) };
+            // Also try perfect squares
+            let a = if rng.gen_weighted_bool(4) { a.sqrt().round() * a.sqrt().round() } else { a };
+            let b = a.sqrt();
 
-            Some(Muloti4 { a, b, c, overflow })
+            Some(
+                Sqrtf {
+                    a: to_u32(a),
+                    b: to_u32(b),
+                },
+            )
+        }
+
+        fn to_string(&self, buffer: &mut String) {
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
         }
 
         fn prologue() -> &'static str {
-            "
-use compiler_builtins::int::mul::__muloti4;
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::math;
 
-static TEST_CASES: &[((i128, i128), (i128, i32))] = &[
-"
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+fn to_u32(x: f32) -> u32 {
+    unsafe { mem::transmute(x) }
+}
+
+// Any NaN is accepted for a NaN result
+fn canonical(x: u32) -> u32 {
+    if x & !(1 << 31) > 0x7f80_0000 { 0x7fc0_0000 } else { x }
+}
+
+static TEST_CASES: &[((u32,), u32)] = &[
+"#
         }
 
         fn epilogue() -> &'static str {
@@ -2661,39 +11738,25 @@ static TEST_CASES: &[((i128, i128), (i128, i32))] = &[
 ];
 
 #[test]
-fn muloti4() {
-    let mut overflow_ = 2;
-    for &((a, b), (c, overflow)) in TEST_CASES {
-        let c_ = __muloti4(a, b, &mut overflow_);
-        assert_eq!(((a, b), (c, overflow)), ((a, b), (c_, overflow_)));
-    }
-}
-"
-        }
-
-        fn to_string(&self, buffer: &mut String) {
-            writeln!(
-                buffer,
-                "(({a}, {b}), ({c}, {overflow})),",
-                a = self.a,
-                b = self.b,
-                c = self.c,
-                overflow = self.overflow
-            )
-                    .unwrap();
+fn sqrtf() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = math::sqrtf(mk_f32(a));
+        assert_eq!(((a,), canonical(b)), ((a,), canonical(to_u32(b_))));
+    }
+}
+"
         }
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Multi3 {
-        a: i128,
-        b: i128,
-        c: i128,
+    pub struct Floorf {
+        a: u32, // f32
+        b: u32, // f32
     }
 
-    impl TestCase for Multi3 {
+    impl TestCase for Floorf {
         fn name() -> &'static str {
-            "multi3"
+            "floorf"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -2701,30 +11764,60 @@ fn muloti4() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_i128(rng);
-            let b = gen_i128(rng);
-            let c = a.wrapping_mul(b);
+            let a = match rng.gen_range(0, 4) {
+                // Any bit pattern
+                0 => mk_f32(rng.gen()),
+                // Halfway between two integers
+                1 => rng.gen_range(-64, 64) as f32 + 0.5,
+                // A magnitude between 1/8 and the first one without a fraction
+                _ => {
+                    let exponent = rng.gen_range(127 - 3, 127 + 23 + 1);
+                    mk_f32(rng.gen::<u32>() & 0x807f_ffff | exponent << 23)
+                }
+            };
+            let b = a.floor();
 
-            Some(Multi3 { a, b, c })
+            Some(
+                Floorf {
+                    a: to_u32(a),
+                    b: to_u32(b),
+                },
+            )
         }
 
         fn to_string(&self, buffer: &mut String) {
-            writeln!(
-                buffer,
-                "(({a}, {b}), {c}),",
-                a = self.a,
-                b = self.b,
-                c = self.c
-            )
-                    .unwrap();
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
         }
 
         fn prologue() -> &'static str {
-            "
-use compiler_builtins::int::mul::__multi3;
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::math;
 
-static TEST_CASES: &[((i128, i128), i128)] = &[
-"
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+fn to_u32(x: f32) -> u32 {
+    unsafe { mem::transmute(x) }
+}
+
+// Any NaN is accepted for a NaN result
+fn canonical(x: u32) -> u32 {
+    if x & !(1 << 31) > 0x7f80_0000 { 0x7fc0_0000 } else { x }
+}
+
+static TEST_CASES: &[((u32,), u32)] = &[
+"#
         }
 
         fn epilogue() -> &'static str {
@@ -2732,10 +11825,10 @@ static TEST_CASES: &[((i128, i128), i128)] = &[
 ];
 
 #[test]
-fn multi3() {
-    for &((a, b), c) in TEST_CASES {
-        let c_ = __multi3(a, b);
-        assert_eq!(((a, b), c), ((a, b), c_));
+fn floorf() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = math::floorf(mk_f32(a));
+        assert_eq!(((a,), canonical(b)), ((a,), canonical(to_u32(b_))));
     }
 }
 "
@@ -2743,15 +11836,14 @@ fn multi3() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Powidf2 {
-        a: u64,  // f64
-        b: i32,
-        c: u64,  // f64
+    pub struct Floor {
+        a: u64, // f64
+        b: u64, // f64
     }
 
-    impl TestCase for Powidf2 {
+    impl TestCase for Floor {
         fn name() -> &'static str {
-            "powidf2"
+            "floor"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -2759,33 +11851,29 @@ fn multi3() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_f64(rng);
-            let b = gen_i32(rng);
-            let c = a.powi(b);
-            // TODO accept NaNs. We don't do that right now because we can't check
-            // for NaN-ness on the thumb targets
-            if a.is_nan() || c.is_nan() {
-                return None;
-            }
+            let a = match rng.gen_range(0, 4) {
+                // Any bit pattern
+                0 => mk_f64(rng.gen()),
+                // Halfway between two integers
+                1 => rng.gen_range(-64, 64) as f64 + 0.5,
+                // A magnitude between 1/8 and the first one without a fraction
+                _ => {
+                    let exponent = rng.gen_range(1023 - 3, 1023 + 52 + 1);
+                    mk_f64(rng.gen::<u64>() & 0x800f_ffff_ffff_ffff | exponent << 52)
+                }
+            };
+            let b = a.floor();
 
             Some(
-                Powidf2 {
+                Floor {
                     a: to_u64(a),
-                    b,
-                    c: to_u64(c),
+                    b: to_u64(b),
                 },
             )
         }
 
         fn to_string(&self, buffer: &mut String) {
-            writeln!(
-                buffer,
-                "(({a}, {b}), {c}),",
-                a = self.a,
-                b = self.b,
-                c = self.c
-            )
-                    .unwrap();
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
         }
 
         fn prologue() -> &'static str {
@@ -2800,7 +11888,7 @@ use core::mem;
               target_os = "linux",
               test)))]
 use std::mem;
-use compiler_builtins::float::pow::__powidf2;
+use compiler_builtins::math;
 
 fn mk_f64(x: u64) -> f64 {
     unsafe { mem::transmute(x) }
@@ -2810,7 +11898,12 @@ fn to_u64(x: f64) -> u64 {
     unsafe { mem::transmute(x) }
 }
 
-static TEST_CASES: &[((u64, i32), u64)] = &[
+// Any NaN is accepted for a NaN result
+fn canonical(x: u64) -> u64 {
+    if x & !(1 << 63) > 0x7ff0_0000_0000_0000 { 0x7ff8_0000_0000_0000 } else { x }
+}
+
+static TEST_CASES: &[((u64,), u64)] = &[
 "#
         }
 
@@ -2819,10 +11912,10 @@ static TEST_CASES: &[((u64, i32), u64)] = &[
 ];
 
 #[test]
-fn powidf2() {
-    for &((a, b), c) in TEST_CASES {
-        let c_ = __powidf2(mk_f64(a), b);
-        assert_eq!(((a, b), c), ((a, b), to_u64(c_)));
+fn floor() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = math::floor(mk_f64(a));
+        assert_eq!(((a,), canonical(b)), ((a,), canonical(to_u64(b_))));
     }
 }
 "
@@ -2830,15 +11923,14 @@ fn powidf2() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Powisf2 {
-        a: u32,  // f32
-        b: i32,
-        c: u32,  // f32
+    pub struct Ceilf {
+        a: u32, // f32
+        b: u32, // f32
     }
 
-    impl TestCase for Powisf2 {
+    impl TestCase for Ceilf {
         fn name() -> &'static str {
-            "powisf2"
+            "ceilf"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -2846,33 +11938,29 @@ fn powidf2() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_f32(rng);
-            let b = gen_i32(rng);
-            let c = a.powi(b);
-            // TODO accept NaNs. We don't do that right now because we can't check
-            // for NaN-ness on the thumb targets
-            if a.is_nan() || c.is_nan() {
-                return None;
-            }
+            let a = match rng.gen_range(0, 4) {
+                // Any bit pattern
+                0 => mk_f32(rng.gen()),
+                // Halfway between two integers
+                1 => rng.gen_range(-64, 64) as f32 + 0.5,
+                // A magnitude between 1/8 and the first one without a fraction
+                _ => {
+                    let exponent = rng.gen_range(127 - 3, 127 + 23 + 1);
+                    mk_f32(rng.gen::<u32>() & 0x807f_ffff | exponent << 23)
+                }
+            };
+            let b = a.ceil();
 
             Some(
-                Powisf2 {
+                Ceilf {
                     a: to_u32(a),
-                    b,
-                    c: to_u32(c),
+                    b: to_u32(b),
                 },
             )
         }
 
         fn to_string(&self, buffer: &mut String) {
-            writeln!(
-                buffer,
-                "(({a}, {b}), {c}),",
-                a = self.a,
-                b = self.b,
-                c = self.c
-            )
-                    .unwrap();
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
         }
 
         fn prologue() -> &'static str {
@@ -2887,7 +11975,7 @@ use core::mem;
               target_os = "linux",
               test)))]
 use std::mem;
-use compiler_builtins::float::pow::__powisf2;
+use compiler_builtins::math;
 
 fn mk_f32(x: u32) -> f32 {
     unsafe { mem::transmute(x) }
@@ -2897,7 +11985,12 @@ fn to_u32(x: f32) -> u32 {
     unsafe { mem::transmute(x) }
 }
 
-static TEST_CASES: &[((u32, i32), u32)] = &[
+// Any NaN is accepted for a NaN result
+fn canonical(x: u32) -> u32 {
+    if x & !(1 << 31) > 0x7f80_0000 { 0x7fc0_0000 } else { x }
+}
+
+static TEST_CASES: &[((u32,), u32)] = &[
 "#
         }
 
@@ -2906,10 +11999,10 @@ static TEST_CASES: &[((u32, i32), u32)] = &[
 ];
 
 #[test]
-fn powisf2() {
-    for &((a, b), c) in TEST_CASES {
-        let c_ = __powisf2(mk_f32(a), b);
-        assert_eq!(((a, b), c), ((a, b), to_u32(c_)));
+fn ceilf() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = math::ceilf(mk_f32(a));
+        assert_eq!(((a,), canonical(b)), ((a,), canonical(to_u32(b_))));
     }
 }
 "
@@ -2917,15 +12010,14 @@ fn powisf2() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Lshrdi3 {
-        a: u64,
-        b: u32,
-        c: u64,
+    pub struct Ceil {
+        a: u64, // f64
+        b: u64, // f64
     }
 
-    impl TestCase for Lshrdi3 {
+    impl TestCase for Ceil {
         fn name() -> &'static str {
-            "lshrdi3"
+            "ceil"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -2933,30 +12025,60 @@ fn powisf2() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_u64(rng);
-            let b = (rng.gen::<u8>() % 64) as u32;
-            let c = a >> b;
+            let a = match rng.gen_range(0, 4) {
+                // Any bit pattern
+                0 => mk_f64(rng.gen()),
+                // Halfway between two integers
+                1 => rng.gen_range(-64, 64) as f64 + 0.5,
+                // A magnitude between 1/8 and the first one without a fraction
+                _ => {
+                    let exponent = rng.gen_range(1023 - 3, 1023 + 52 + 1);
+                    mk_f64(rng.gen::<u64>() & 0x800f_ffff_ffff_ffff | exponent << 52)
+                }
+            };
+            let b = a.ceil();
 
-            Some(Lshrdi3 { a, b, c })
+            Some(
+                Ceil {
+                    a: to_u64(a),
+                    b: to_u64(b),
+                },
+            )
         }
 
         fn to_string(&self, buffer: &mut String) {
-            writeln!(
-                buffer,
-                "(({a}, {b}), {c}),",
-                a = self.a,
-                b = self.b,
-                c = self.c
-            )
-                    .unwrap();
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
         }
 
         fn prologue() -> &'static str {
-            "
-use compiler_builtins::int::shift::__lshrdi3;
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::math;
 
-static TEST_CASES: &[((u64, u32), u64)] = &[
-"
+fn mk_f64(x: u64) -> f64 {
+    unsafe { mem::transmute(x) }
+}
+
+fn to_u64(x: f64) -> u64 {
+    unsafe { mem::transmute(x) }
+}
+
+// Any NaN is accepted for a NaN result
+fn canonical(x: u64) -> u64 {
+    if x & !(1 << 63) > 0x7ff0_0000_0000_0000 { 0x7ff8_0000_0000_0000 } else { x }
+}
+
+static TEST_CASES: &[((u64,), u64)] = &[
+"#
         }
 
         fn epilogue() -> &'static str {
@@ -2964,10 +12086,10 @@ static TEST_CASES: &[((u64, u32), u64)] = &[
 ];
 
 #[test]
-fn lshrdi3() {
-    for &((a, b), c) in TEST_CASES {
-        let c_ = __lshrdi3(a, b);
-        assert_eq!(((a, b), c), ((a, b), c_));
+fn ceil() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = math::ceil(mk_f64(a));
+        assert_eq!(((a,), canonical(b)), ((a,), canonical(to_u64(b_))));
     }
 }
 "
@@ -2975,15 +12097,14 @@ fn lshrdi3() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Lshrti3 {
-        a: u128,
-        b: u32,
-        c: u128,
+    pub struct Truncf {
+        a: u32, // f32
+        b: u32, // f32
     }
 
-    impl TestCase for Lshrti3 {
+    impl TestCase for Truncf {
         fn name() -> &'static str {
-            "lshrti3"
+            "truncf"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -2991,30 +12112,60 @@ fn lshrdi3() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_u128(rng);
-            let b = (rng.gen::<u8>() % 128) as u32;
-            let c = a >> b;
+            let a = match rng.gen_range(0, 4) {
+                // Any bit pattern
+                0 => mk_f32(rng.gen()),
+                // Halfway between two integers
+                1 => rng.gen_range(-64, 64) as f32 + 0.5,
+                // A magnitude between 1/8 and the first one without a fraction
+                _ => {
+                    let exponent = rng.gen_range(127 - 3, 127 + 23 + 1);
+                    mk_f32(rng.gen::<u32>() & 0x807f_ffff | exponent << 23)
+                }
+            };
+            let b = a.trunc();
 
-            Some(Lshrti3 { a, b, c })
+            Some(
+                Truncf {
+                    a: to_u32(a),
+                    b: to_u32(b),
+                },
+            )
         }
 
         fn to_string(&self, buffer: &mut String) {
-            writeln!(
-                buffer,
-                "(({a}, {b}), {c}),",
-                a = self.a,
-                b = self.b,
-                c = self.c
-            )
-                    .unwrap();
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
         }
 
         fn prologue() -> &'static str {
-            "
-use compiler_builtins::int::shift::__lshrti3;
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::math;
 
-static TEST_CASES: &[((u128, u32), u128)] = &[
-"
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+fn to_u32(x: f32) -> u32 {
+    unsafe { mem::transmute(x) }
+}
+
+// Any NaN is accepted for a NaN result
+fn canonical(x: u32) -> u32 {
+    if x & !(1 << 31) > 0x7f80_0000 { 0x7fc0_0000 } else { x }
+}
+
+static TEST_CASES: &[((u32,), u32)] = &[
+"#
         }
 
         fn epilogue() -> &'static str {
@@ -3022,10 +12173,10 @@ static TEST_CASES: &[((u128, u32), u128)] = &[
 ];
 
 #[test]
-fn lshrti3() {
-    for &((a, b), c) in TEST_CASES {
-        let c_ = __lshrti3(a, b);
-        assert_eq!(((a, b), c), ((a, b), c_));
+fn truncf() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = math::truncf(mk_f32(a));
+        assert_eq!(((a,), canonical(b)), ((a,), canonical(to_u32(b_))));
     }
 }
 "
@@ -3033,15 +12184,14 @@ fn lshrti3() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Subdf3 {
-        a: u64,  // f64
-        b: u64,  // f64
-        c: u64,  // f64
+    pub struct Trunc {
+        a: u64, // f64
+        b: u64, // f64
     }
 
-    impl TestCase for Subdf3 {
+    impl TestCase for Trunc {
         fn name() -> &'static str {
-            "subdf3"
+            "trunc"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -3049,33 +12199,29 @@ fn lshrti3() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_f64(rng);
-            let b = gen_f64(rng);
-            let c = a - b;
-            // TODO accept NaNs. We don't do that right now because we can't check
-            // for NaN-ness on the thumb targets (due to missing intrinsics)
-            if a.is_nan() || b.is_nan() || c.is_nan() {
-                return None;
-            }
+            let a = match rng.gen_range(0, 4) {
+                // Any bit pattern
+                0 => mk_f64(rng.gen()),
+                // Halfway between two integers
+                1 => rng.gen_range(-64, 64) as f64 + 0.5,
+                // A magnitude between 1/8 and the first one without a fraction
+                _ => {
+                    let exponent = rng.gen_range(1023 - 3, 1023 + 52 + 1);
+                    mk_f64(rng.gen::<u64>() & 0x800f_ffff_ffff_ffff | exponent << 52)
+                }
+            };
+            let b = a.trunc();
 
             Some(
-                Subdf3 {
+                Trunc {
                     a: to_u64(a),
                     b: to_u64(b),
-                    c: to_u64(c),
                 },
             )
         }
 
         fn to_string(&self, buffer: &mut String) {
-            writeln!(
-                buffer,
-                "(({a}, {b}), {c}),",
-                a = self.a,
-                b = self.b,
-                c = self.c
-            )
-                    .unwrap();
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
         }
 
         fn prologue() -> &'static str {
@@ -3090,7 +12236,7 @@ use core::mem;
               target_os = "linux",
               test)))]
 use std::mem;
-use compiler_builtins::float::sub::__subdf3;
+use compiler_builtins::math;
 
 fn mk_f64(x: u64) -> f64 {
     unsafe { mem::transmute(x) }
@@ -3100,7 +12246,12 @@ fn to_u64(x: f64) -> u64 {
     unsafe { mem::transmute(x) }
 }
 
-static TEST_CASES: &[((u64, u64), u64)] = &[
+// Any NaN is accepted for a NaN result
+fn canonical(x: u64) -> u64 {
+    if x & !(1 << 63) > 0x7ff0_0000_0000_0000 { 0x7ff8_0000_0000_0000 } else { x }
+}
+
+static TEST_CASES: &[((u64,), u64)] = &[
 "#
         }
 
@@ -3109,10 +12260,10 @@ static TEST_CASES: &[((u64, u64), u64)] = &[
 ];
 
 #[test]
-fn subdf3() {
-    for &((a, b), c) in TEST_CASES {
-        let c_ = __subdf3(mk_f64(a), mk_f64(b));
-        assert_eq!(((a, b), c), ((a, b), to_u64(c_)));
+fn trunc() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = math::trunc(mk_f64(a));
+        assert_eq!(((a,), canonical(b)), ((a,), canonical(to_u64(b_))));
     }
 }
 "
@@ -3120,15 +12271,14 @@ fn subdf3() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Subsf3 {
-        a: u32,  // f32
-        b: u32,  // f32
-        c: u32,  // f32
+    pub struct Roundf {
+        a: u32, // f32
+        b: u32, // f32
     }
 
-    impl TestCase for Subsf3 {
+    impl TestCase for Roundf {
         fn name() -> &'static str {
-            "subsf3"
+            "roundf"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -3136,33 +12286,29 @@ fn subdf3() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_f32(rng);
-            let b = gen_f32(rng);
-            let c = a - b;
-            // TODO accept NaNs. We don't do that right now because we can't check
-            // for NaN-ness on the thumb targets (due to missing intrinsics)
-            if a.is_nan() || b.is_nan() || c.is_nan() {
-                return None;
-            }
+            let a = match rng.gen_range(0, 4) {
+                // Any bit pattern
+                0 => mk_f32(rng.gen()),
+                // Halfway between two integers
+                1 => rng.gen_range(-64, 64) as f32 + 0.5,
+                // A magnitude between 1/8 and the first one without a fraction
+                _ => {
+                    let exponent = rng.gen_range(127 - 3, 127 + 23 + 1);
+                    mk_f32(rng.gen::<u32>() & 0x807f_ffff | exponent << 23)
+                }
+            };
+            let b = a.round();
 
             Some(
-                Subsf3 {
+                Roundf {
                     a: to_u32(a),
                     b: to_u32(b),
-                    c: to_u32(c),
                 },
             )
         }
 
         fn to_string(&self, buffer: &mut String) {
-            writeln!(
-                buffer,
-                "(({a}, {b}), {c}),",
-                a = self.a,
-                b = self.b,
-                c = self.c
-            )
-                    .unwrap();
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
         }
 
         fn prologue() -> &'static str {
@@ -3177,7 +12323,7 @@ use core::mem;
               target_os = "linux",
               test)))]
 use std::mem;
-use compiler_builtins::float::sub::__subsf3;
+use compiler_builtins::math;
 
 fn mk_f32(x: u32) -> f32 {
     unsafe { mem::transmute(x) }
@@ -3187,7 +12333,12 @@ fn to_u32(x: f32) -> u32 {
     unsafe { mem::transmute(x) }
 }
 
-static TEST_CASES: &[((u32, u32), u32)] = &[
+// Any NaN is accepted for a NaN result
+fn canonical(x: u32) -> u32 {
+    if x & !(1 << 31) > 0x7f80_0000 { 0x7fc0_0000 } else { x }
+}
+
+static TEST_CASES: &[((u32,), u32)] = &[
 "#
         }
 
@@ -3196,10 +12347,10 @@ static TEST_CASES: &[((u32, u32), u32)] = &[
 ];
 
 #[test]
-fn subsf3() {
-    for &((a, b), c) in TEST_CASES {
-        let c_ = __subsf3(mk_f32(a), mk_f32(b));
-        assert_eq!(((a, b), c), ((a, b), to_u32(c_)));
+fn roundf() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = math::roundf(mk_f32(a));
+        assert_eq!(((a,), canonical(b)), ((a,), canonical(to_u32(b_))));
     }
 }
 "
@@ -3207,15 +12358,14 @@ fn subsf3() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Udivdi3 {
-        a: u64,
-        b: u64,
-        c: u64,
+    pub struct Round {
+        a: u64, // f64
+        b: u64, // f64
     }
 
-    impl TestCase for Udivdi3 {
+    impl TestCase for Round {
         fn name() -> &'static str {
-            "udivdi3"
+            "round"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -3223,33 +12373,60 @@ fn subsf3() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_u64(rng);
-            let b = gen_u64(rng);
-            if b == 0 {
-                return None;
-            }
-            let c = a / b;
+            let a = match rng.gen_range(0, 4) {
+                // Any bit pattern
+                0 => mk_f64(rng.gen()),
+                // Halfway between two integers
+                1 => rng.gen_range(-64, 64) as f64 + 0.5,
+                // A magnitude between 1/8 and the first one without a fraction
+                _ => {
+                    let exponent = rng.gen_range(1023 - 3, 1023 + 52 + 1);
+                    mk_f64(rng.gen::<u64>() & 0x800f_ffff_ffff_ffff | exponent << 52)
+                }
+            };
+            let b = a.round();
 
-            Some(Udivdi3 { a, b, c })
+            Some(
+                Round {
+                    a: to_u64(a),
+                    b: to_u64(b),
+                },
+            )
         }
 
         fn to_string(&self, buffer: &mut String) {
-            writeln!(
-                buffer,
-                "(({a}, {b}), {c}),",
-                a = self.a,
-                b = self.b,
-                c = self.c
-            )
-                    .unwrap();
+            writeln!(buffer, "(({a},), {b}),", a = self.a, b = self.b).unwrap();
         }
 
         fn prologue() -> &'static str {
-            "
-use compiler_builtins::int::udiv::__udivdi3;
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::math;
 
-static TEST_CASES: &[((u64, u64), u64)] = &[
-"
+fn mk_f64(x: u64) -> f64 {
+    unsafe { mem::transmute(x) }
+}
+
+fn to_u64(x: f64) -> u64 {
+    unsafe { mem::transmute(x) }
+}
+
+// Any NaN is accepted for a NaN result
+fn canonical(x: u64) -> u64 {
+    if x & !(1 << 63) > 0x7ff0_0000_0000_0000 { 0x7ff8_0000_0000_0000 } else { x }
+}
+
+static TEST_CASES: &[((u64,), u64)] = &[
+"#
         }
 
         fn epilogue() -> &'static str {
@@ -3257,10 +12434,10 @@ static TEST_CASES: &[((u64, u64), u64)] = &[
 ];
 
 #[test]
-fn udivdi3() {
-    for &((a, b), c) in TEST_CASES {
-        let c_ = __udivdi3(a, b);
-        assert_eq!(((a, b), c), ((a, b), c_));
+fn round() {
+    for &((a,), b) in TEST_CASES {
+        let b_ = math::round(mk_f64(a));
+        assert_eq!(((a,), canonical(b)), ((a,), canonical(to_u64(b_))));
     }
 }
 "
@@ -3268,16 +12445,15 @@ fn udivdi3() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Udivmoddi4 {
-        a: u64,
-        b: u64,
-        c: u64,
-        rem: u64,
+    pub struct Fminf {
+        a: u32, // f32
+        b: u32, // f32
+        c: u32, // f32
     }
 
-    impl TestCase for Udivmoddi4 {
+    impl TestCase for Fminf {
         fn name() -> &'static str {
-            "udivmoddi4"
+            "fminf"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -3285,35 +12461,66 @@ fn udivdi3() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_u64(rng);
-            let b = gen_u64(rng);
-            if b == 0 {
-                return None;
-            }
-            let c = a / b;
-            let rem = a % b;
+            let a = if rng.gen() { gen_f32(rng) } else { mk_f32(rng.gen()) };
+            let b = if rng.gen() { gen_f32(rng) } else { mk_f32(rng.gen()) };
+            // Also try equal magnitudes, which covers zeros of both signs
+            let b = if rng.gen_weighted_bool(4) { -a } else { b };
+            let c = if a == b {
+                // Zeros of opposite signs compare equal, -0 is the negative one
+                mk_f32(to_u32(a) | to_u32(b))
+            } else {
+                a.min(b)
+            };
 
-            Some(Udivmoddi4 { a, b, c, rem })
+            Some(
+                Fminf {
+                    a: to_u32(a),
+                    b: to_u32(b),
+                    c: to_u32(c),
+                },
+            )
         }
 
         fn to_string(&self, buffer: &mut String) {
             writeln!(
                 buffer,
-                "(({a}, {b}), ({c}, {rem})),",
+                "(({a}, {b}), {c}),",
                 a = self.a,
                 b = self.b,
-                c = self.c,
-                rem = self.rem
+                c = self.c
             )
                     .unwrap();
         }
 
         fn prologue() -> &'static str {
-            "
-use compiler_builtins::int::udiv::__udivmoddi4;
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::math;
 
-static TEST_CASES: &[((u64, u64), (u64, u64))] = &[
-"
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+fn to_u32(x: f32) -> u32 {
+    unsafe { mem::transmute(x) }
+}
+
+// Any NaN is accepted for a NaN result
+fn canonical(x: u32) -> u32 {
+    if x & !(1 << 31) > 0x7f80_0000 { 0x7fc0_0000 } else { x }
+}
+
+static TEST_CASES: &[((u32, u32), u32)] = &[
+"#
         }
 
         fn epilogue() -> &'static str {
@@ -3321,11 +12528,10 @@ static TEST_CASES: &[((u64, u64), (u64, u64))] = &[
 ];
 
 #[test]
-fn udivmoddi4() {
-    for &((a, b), (c, rem)) in TEST_CASES {
-        let mut rem_ = 0;
-        let c_ = __udivmoddi4(a, b, Some(&mut rem_));
-        assert_eq!(((a, b), (c, rem)), ((a, b), (c_, rem_)));
+fn fminf() {
+    for &((a, b), c) in TEST_CASES {
+        let c_ = math::fminf(mk_f32(a), mk_f32(b));
+        assert_eq!(((a, b), canonical(c)), ((a, b), canonical(to_u32(c_))));
     }
 }
 "
@@ -3333,16 +12539,15 @@ fn udivmoddi4() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Udivmodsi4 {
-        a: u32,
-        b: u32,
-        c: u32,
-        rem: u32,
+    pub struct Fmin {
+        a: u64, // f64
+        b: u64, // f64
+        c: u64, // f64
     }
 
-    impl TestCase for Udivmodsi4 {
+    impl TestCase for Fmin {
         fn name() -> &'static str {
-            "udivmodsi4"
+            "fmin"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -3350,35 +12555,66 @@ fn udivmoddi4() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_u32(rng);
-            let b = gen_u32(rng);
-            if b == 0 {
-                return None;
-            }
-            let c = a / b;
-            let rem = a % b;
+            let a = if rng.gen() { gen_f64(rng) } else { mk_f64(rng.gen()) };
+            let b = if rng.gen() { gen_f64(rng) } else { mk_f64(rng.gen()) };
+            // Also try equal magnitudes, which covers zeros of both signs
+            let b = if rng.gen_weighted_bool(4) { -a } else { b };
+            let c = if a == b {
+                // Zeros of opposite signs compare equal, -0 is the negative one
+                mk_f64(to_u64(a) | to_u64(b))
+            } else {
+                a.min(b)
+            };
 
-            Some(Udivmodsi4 { a, b, c, rem })
+            Some(
+                Fmin {
+                    a: to_u64(a),
+                    b: to_u64(b),
+                    c: to_u64(c),
+                },
+            )
         }
 
         fn to_string(&self, buffer: &mut String) {
             writeln!(
                 buffer,
-                "(({a}, {b}), ({c}, {rem})),",
+                "(({a}, {b}), {c}),",
                 a = self.a,
                 b = self.b,
-                c = self.c,
-                rem = self.rem
+                c = self.c
             )
                     .unwrap();
         }
 
         fn prologue() -> &'static str {
-            "
-use compiler_builtins::int::udiv::__udivmodsi4;
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::math;
 
-static TEST_CASES: &[((u32, u32), (u32, u32))] = &[
-"
+fn mk_f64(x: u64) -> f64 {
+    unsafe { mem::transmute(x) }
+}
+
+fn to_u64(x: f64) -> u64 {
+    unsafe { mem::transmute(x) }
+}
+
+// Any NaN is accepted for a NaN result
+fn canonical(x: u64) -> u64 {
+    if x & !(1 << 63) > 0x7ff0_0000_0000_0000 { 0x7ff8_0000_0000_0000 } else { x }
+}
+
+static TEST_CASES: &[((u64, u64), u64)] = &[
+"#
         }
 
         fn epilogue() -> &'static str {
@@ -3386,11 +12622,10 @@ static TEST_CASES: &[((u32, u32), (u32, u32))] = &[
 ];
 
 #[test]
-fn udivmodsi4() {
-    for &((a, b), (c, rem)) in TEST_CASES {
-        let mut rem_ = 0;
-        let c_ = __udivmodsi4(a, b, Some(&mut rem_));
-        assert_eq!(((a, b), (c, rem)), ((a, b), (c_, rem_)));
+fn fmin() {
+    for &((a, b), c) in TEST_CASES {
+        let c_ = math::fmin(mk_f64(a), mk_f64(b));
+        assert_eq!(((a, b), canonical(c)), ((a, b), canonical(to_u64(c_))));
     }
 }
 "
@@ -3398,16 +12633,15 @@ fn udivmodsi4() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Udivmodti4 {
-        a: u128,
-        b: u128,
-        c: u128,
-        rem: u128,
+    pub struct Fmaxf {
+        a: u32, // f32
+        b: u32, // f32
+        c: u32, // f32
     }
 
-    impl TestCase for Udivmodti4 {
+    impl TestCase for Fmaxf {
         fn name() -> &'static str {
-            "udivmodti4"
+            "fmaxf"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -3415,35 +12649,66 @@ fn udivmodsi4() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_u128(rng);
-            let b = gen_u128(rng);
-            if b == 0 {
-                return None;
-            }
-            let c = a / b;
-            let rem = a % b;
+            let a = if rng.gen() { gen_f32(rng) } else { mk_f32(rng.gen()) };
+            let b = if rng.gen() { gen_f32(rng) } else { mk_f32(rng.gen()) };
+            // Also try equal magnitudes, which covers zeros of both signs
+            let b = if rng.gen_weighted_bool(4) { -a } else { b };
+            let c = if a == b {
+                // Zeros of opposite signs compare equal, -0 is the positive one
+                mk_f32(to_u32(a) & to_u32(b))
+            } else {
+                a.max(b)
+            };
 
-            Some(Udivmodti4 { a, b, c, rem })
+            Some(
+                Fmaxf {
+                    a: to_u32(a),
+                    b: to_u32(b),
+                    c: to_u32(c),
+                },
+            )
         }
 
         fn to_string(&self, buffer: &mut String) {
             writeln!(
                 buffer,
-                "(({a}, {b}), ({c}, {rem})),",
+                "(({a}, {b}), {c}),",
                 a = self.a,
                 b = self.b,
-                c = self.c,
-                rem = self.rem
+                c = self.c
             )
                     .unwrap();
         }
 
         fn prologue() -> &'static str {
-            "
-use compiler_builtins::int::udiv::__udivmodti4;
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::math;
 
-static TEST_CASES: &[((u128, u128), (u128, u128))] = &[
-"
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+fn to_u32(x: f32) -> u32 {
+    unsafe { mem::transmute(x) }
+}
+
+// Any NaN is accepted for a NaN result
+fn canonical(x: u32) -> u32 {
+    if x & !(1 << 31) > 0x7f80_0000 { 0x7fc0_0000 } else { x }
+}
+
+static TEST_CASES: &[((u32, u32), u32)] = &[
+"#
         }
 
         fn epilogue() -> &'static str {
@@ -3451,11 +12716,10 @@ static TEST_CASES: &[((u128, u128), (u128, u128))] = &[
 ];
 
 #[test]
-fn udivmodti4() {
-    for &((a, b), (c, rem)) in TEST_CASES {
-        let mut rem_ = 0;
-        let c_ = __udivmodti4(a, b, Some(&mut rem_));
-        assert_eq!(((a, b), (c, rem)), ((a, b), (c_, rem_)));
+fn fmaxf() {
+    for &((a, b), c) in TEST_CASES {
+        let c_ = math::fmaxf(mk_f32(a), mk_f32(b));
+        assert_eq!(((a, b), canonical(c)), ((a, b), canonical(to_u32(c_))));
     }
 }
 "
@@ -3463,15 +12727,15 @@ fn udivmodti4() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Udivsi3 {
-        a: u32,
-        b: u32,
-        c: u32,
+    pub struct Fmax {
+        a: u64, // f64
+        b: u64, // f64
+        c: u64, // f64
     }
 
-    impl TestCase for Udivsi3 {
+    impl TestCase for Fmax {
         fn name() -> &'static str {
-            "udivsi3"
+            "fmax"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -3479,14 +12743,24 @@ fn udivmodti4() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_u32(rng);
-            let b = gen_u32(rng);
-            if b == 0 {
-                return None;
-            }
-            let c = a / b;
+            let a = if rng.gen() { gen_f64(rng) } else { mk_f64(rng.gen()) };
+            let b = if rng.gen() { gen_f64(rng) } else { mk_f64(rng.gen()) };
+            // Also try equal magnitudes, which covers zeros of both signs
+            let b = if rng.gen_weighted_bool(4) { -a } else { b };
+            let c = if a == b {
+                // Zeros of opposite signs compare equal, -0 is the positive one
+                mk_f64(to_u64(a) & to_u64(b))
+            } else {
+                a.max(b)
+            };
 
-            Some(Udivsi3 { a, b, c })
+            Some(
+                Fmax {
+                    a: to_u64(a),
+                    b: to_u64(b),
+                    c: to_u64(c),
+                },
+            )
         }
 
         fn to_string(&self, buffer: &mut String) {
@@ -3501,11 +12775,34 @@ fn udivmodti4() {
         }
 
         fn prologue() -> &'static str {
-            "
-use compiler_builtins::int::udiv::__udivsi3;
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::math;
 
-static TEST_CASES: &[((u32, u32), u32)] = &[
-"
+fn mk_f64(x: u64) -> f64 {
+    unsafe { mem::transmute(x) }
+}
+
+fn to_u64(x: f64) -> u64 {
+    unsafe { mem::transmute(x) }
+}
+
+// Any NaN is accepted for a NaN result
+fn canonical(x: u64) -> u64 {
+    if x & !(1 << 63) > 0x7ff0_0000_0000_0000 { 0x7ff8_0000_0000_0000 } else { x }
+}
+
+static TEST_CASES: &[((u64, u64), u64)] = &[
+"#
         }
 
         fn epilogue() -> &'static str {
@@ -3513,10 +12810,10 @@ static TEST_CASES: &[((u32, u32), u32)] = &[
 ];
 
 #[test]
-fn udivsi3() {
+fn fmax() {
     for &((a, b), c) in TEST_CASES {
-        let c_ = __udivsi3(a, b);
-        assert_eq!(((a, b), c), ((a, b), c_));
+        let c_ = math::fmax(mk_f64(a), mk_f64(b));
+        assert_eq!(((a, b), canonical(c)), ((a, b), canonical(to_u64(c_))));
     }
 }
 "
@@ -3524,15 +12821,15 @@ fn udivsi3() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Udivti3 {
-        a: u128,
-        b: u128,
-        c: u128,
+    pub struct Fmodf {
+        a: u32, // f32
+        b: u32, // f32
+        c: u32, // f32
     }
 
-    impl TestCase for Udivti3 {
+    impl TestCase for Fmodf {
         fn name() -> &'static str {
-            "udivti3"
+            "fmodf"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -3540,14 +12837,23 @@ fn udivsi3() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_u128(rng);
-            let b = gen_u128(rng);
-            if b == 0 {
-                return None;
-            }
-            let c = a / b;
+            let b = if rng.gen() { gen_f32(rng) } else { mk_f32(rng.gen()) };
+            let a = match rng.gen_range(0, 4) {
+                0 => gen_f32(rng),
+                1 => mk_f32(rng.gen()),
+                // A small quotient, possibly halfway between two integers
+                2 => b * (rng.gen_range(-16, 16) as f32 + 0.5),
+                _ => b * rng.gen_range(-1000.0, 1000.0),
+            };
+            let c = a % b;
 
-            Some(Udivti3 { a, b, c })
+            Some(
+                Fmodf {
+                    a: to_u32(a),
+                    b: to_u32(b),
+                    c: to_u32(c),
+                },
+            )
         }
 
         fn to_string(&self, buffer: &mut String) {
@@ -3562,11 +12868,34 @@ fn udivsi3() {
         }
 
         fn prologue() -> &'static str {
-            "
-use compiler_builtins::int::udiv::__udivti3;
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::math;
 
-static TEST_CASES: &[((u128, u128), u128)] = &[
-"
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+fn to_u32(x: f32) -> u32 {
+    unsafe { mem::transmute(x) }
+}
+
+// Any NaN is accepted for a NaN result
+fn canonical(x: u32) -> u32 {
+    if x & !(1 << 31) > 0x7f80_0000 { 0x7fc0_0000 } else { x }
+}
+
+static TEST_CASES: &[((u32, u32), u32)] = &[
+"#
         }
 
         fn epilogue() -> &'static str {
@@ -3574,10 +12903,10 @@ static TEST_CASES: &[((u128, u128), u128)] = &[
 ];
 
 #[test]
-fn udivti3() {
+fn fmodf() {
     for &((a, b), c) in TEST_CASES {
-        let c_ = __udivti3(a, b);
-        assert_eq!(((a, b), c), ((a, b), c_));
+        let c_ = math::fmodf(mk_f32(a), mk_f32(b));
+        assert_eq!(((a, b), canonical(c)), ((a, b), canonical(to_u32(c_))));
     }
 }
 "
@@ -3585,15 +12914,15 @@ fn udivti3() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Umoddi3 {
-        a: u64,
-        b: u64,
-        c: u64,
+    pub struct Fmod {
+        a: u64, // f64
+        b: u64, // f64
+        c: u64, // f64
     }
 
-    impl TestCase for Umoddi3 {
+    impl TestCase for Fmod {
         fn name() -> &'static str {
-            "umoddi3"
+            "fmod"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -3601,14 +12930,23 @@ fn udivti3() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_u64(rng);
-            let b = gen_u64(rng);
-            if b == 0 {
-                return None;
-            }
+            let b = if rng.gen() { gen_f64(rng) } else { mk_f64(rng.gen()) };
+            let a = match rng.gen_range(0, 4) {
+                0 => gen_f64(rng),
+                1 => mk_f64(rng.gen()),
+                // A small quotient, possibly halfway between two integers
+                2 => b * (rng.gen_range(-16, 16) as f64 + 0.5),
+                _ => b * rng.gen_range(-1000.0, 1000.0),
+            };
             let c = a % b;
 
-            Some(Umoddi3 { a, b, c })
+            Some(
+                Fmod {
+                    a: to_u64(a),
+                    b: to_u64(b),
+                    c: to_u64(c),
+                },
+            )
         }
 
         fn to_string(&self, buffer: &mut String) {
@@ -3623,11 +12961,34 @@ fn udivti3() {
         }
 
         fn prologue() -> &'static str {
-            "
-use compiler_builtins::int::udiv::__umoddi3;
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::math;
+
+fn mk_f64(x: u64) -> f64 {
+    unsafe { mem::transmute(x) }
+}
+
+fn to_u64(x: f64) -> u64 {
+    unsafe { mem::transmute(x) }
+}
+
+// Any NaN is accepted for a NaN result
+fn canonical(x: u64) -> u64 {
+    if x & !(1 << 63) > 0x7ff0_0000_0000_0000 { 0x7ff8_0000_0000_0000 } else { x }
+}
 
 static TEST_CASES: &[((u64, u64), u64)] = &[
-"
+"#
         }
 
         fn epilogue() -> &'static str {
@@ -3635,10 +12996,10 @@ static TEST_CASES: &[((u64, u64), u64)] = &[
 ];
 
 #[test]
-fn umoddi3() {
+fn fmod() {
     for &((a, b), c) in TEST_CASES {
-        let c_ = __umoddi3(a, b);
-        assert_eq!(((a, b), c), ((a, b), c_));
+        let c_ = math::fmod(mk_f64(a), mk_f64(b));
+        assert_eq!(((a, b), canonical(c)), ((a, b), canonical(to_u64(c_))));
     }
 }
 "
@@ -3646,15 +13007,15 @@ fn umoddi3() {
     }
 
     #[derive(Eq, Hash, PartialEq)]
-    pub struct Umodsi3 {
-        a: u32,
-        b: u32,
-        c: u32,
+    pub struct Remainderf {
+        a: u32, // f32
+        b: u32, // f32
+        c: u32, // f32
     }
 
-    impl TestCase for Umodsi3 {
+    impl TestCase for Remainderf {
         fn name() -> &'static str {
-            "umodsi3"
+            "remainderf"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -3662,14 +13023,23 @@ fn umoddi3() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_u32(rng);
-            let b = gen_u32(rng);
-            if b == 0 {
-                return None;
-            }
-            let c = a % b;
+            let b = if rng.gen() { gen_f32(rng) } else { mk_f32(rng.gen()) };
+            let a = match rng.gen_range(0, 4) {
+                0 => gen_f32(rng),
+                1 => mk_f32(rng.gen()),
+                // A small quotient, possibly halfway between two integers
+                2 => b * (rng.gen_range(-16, 16) as f32 + 0.5),
+                _ => b * rng.gen_range(-1000.0, 1000.0),
+            };
+            let c = unsafe { remainderf(a, b) };
 
-            Some(Umodsi3 { a, b, c })
+            Some(
+                Remainderf {
+                    a: to_u32(a),
+                    b: to_u32(b),
+                    c: to_u32(c),
+                },
+            )
         }
 
         fn to_string(&self, buffer: &mut String) {
@@ -3684,11 +13054,34 @@ fn umoddi3() {
         }
 
         fn prologue() -> &'static str {
-            "
-use compiler_builtins::int::udiv::__umodsi3;
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::math;
+
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+fn to_u32(x: f32) -> u32 {
+    unsafe { mem::transmute(x) }
+}
+
+// Any NaN is accepted for a NaN result
+fn canonical(x: u32) -> u32 {
+    if x & !(1 << 31) > 0x7f80_0000 { 0x7fc0_0000 } else { x }
+}
 
 static TEST_CASES: &[((u32, u32), u32)] = &[
-"
+"#
         }
 
         fn epilogue() -> &'static str {
@@ -3696,26 +13089,26 @@ static TEST_CASES: &[((u32, u32), u32)] = &[
 ];
 
 #[test]
-fn umodsi3() {
+fn remainderf() {
     for &((a, b), c) in TEST_CASES {
-        let c_ = __umodsi3(a, b);
-        assert_eq!(((a, b), c), ((a, b), c_));
+        let c_ = math::remainderf(mk_f32(a), mk_f32(b));
+        assert_eq!(((a, b), canonical(c)), ((a, b), canonical(to_u32(c_))));
     }
 }
 "
         }
     }
 
-    #[derive(Eq, Hash, PartialEq)]
-    pub struct Umodti3 {
-        a: u128,
-        b: u128,
-        c: u128,
+    #[derive(Eq, Hash, PartialEq)]
+    pub struct Remainder {
+        a: u64, // f64
+        b: u64, // f64
+        c: u64, // f64
     }
 
-    impl TestCase for Umodti3 {
+    impl TestCase for Remainder {
         fn name() -> &'static str {
-            "umodti3"
+            "remainder"
         }
 
         fn generate<R>(rng: &mut R) -> Option<Self>
@@ -3723,14 +13116,23 @@ fn umodsi3() {
             R: Rng,
             Self: Sized,
         {
-            let a = gen_u128(rng);
-            let b = gen_u128(rng);
-            if b == 0 {
-                return None;
-            }
-            let c = a % b;
+            let b = if rng.gen() { gen_f64(rng) } else { mk_f64(rng.gen()) };
+            let a = match rng.gen_range(0, 4) {
+                0 => gen_f64(rng),
+                1 => mk_f64(rng.gen()),
+                // A small quotient, possibly halfway between two integers
+                2 => b * (rng.gen_range(-16, 16) as f64 + 0.5),
+                _ => b * rng.gen_range(-1000.0, 1000.0),
+            };
+            let c = unsafe { remainder(a, b) };
 
-            Some(Umodti3 { a, b, c })
+            Some(
+                Remainder {
+                    a: to_u64(a),
+                    b: to_u64(b),
+                    c: to_u64(c),
+                },
+            )
         }
 
         fn to_string(&self, buffer: &mut String) {
@@ -3745,11 +13147,34 @@ fn umodsi3() {
         }
 
         fn prologue() -> &'static str {
-            "
-use compiler_builtins::int::udiv::__umodti3;
+            r#"
+#[cfg(all(target_arch = "arm",
+          not(any(target_env = "gnu", target_env = "musl")),
+          target_os = "linux",
+          test))]
+use core::mem;
+#[cfg(not(all(target_arch = "arm",
+              not(any(target_env = "gnu", target_env = "musl")),
+              target_os = "linux",
+              test)))]
+use std::mem;
+use compiler_builtins::math;
 
-static TEST_CASES: &[((u128, u128), u128)] = &[
-"
+fn mk_f64(x: u64) -> f64 {
+    unsafe { mem::transmute(x) }
+}
+
+fn to_u64(x: f64) -> u64 {
+    unsafe { mem::transmute(x) }
+}
+
+// Any NaN is accepted for a NaN result
+fn canonical(x: u64) -> u64 {
+    if x & !(1 << 63) > 0x7ff0_0000_0000_0000 { 0x7ff8_0000_0000_0000 } else { x }
+}
+
+static TEST_CASES: &[((u64, u64), u64)] = &[
+"#
         }
 
         fn epilogue() -> &'static str {
@@ -3757,10 +13182,10 @@ static TEST_CASES: &[((u128, u128), u128)] = &[
 ];
 
 #[test]
-fn umodti3() {
+fn remainder() {
     for &((a, b), c) in TEST_CASES {
-        let c_ = __umodti3(a, b);
-        assert_eq!(((a, b), c), ((a, b), c_));
+        let c_ = math::remainder(mk_f64(a), mk_f64(b));
+        assert_eq!(((a, b), canonical(c)), ((a, b), canonical(to_u64(c_))));
     }
 }
 "
@@ -3884,6 +13309,1070 @@ macro_rules! panic {
     gen_float!(gen_f32, f32, u32, 32, 23);
     gen_float!(gen_f64, f64, u64, 64, 52);
 
+    // There's no half precision type in Rust, so `f16`s are handled as their
+    // bit patterns
+    fn gen_f16<R>(rng: &mut R) -> u16
+    where
+        R: Rng,
+    {
+        if rng.gen_weighted_bool(10) {
+            // Special values
+            *rng.choose(&[0x0000, 0x8000, 0x7e00, 0x7c00, 0xfc00]).unwrap()
+        } else if rng.gen() {
+            // Denormalized
+            rng.gen::<u16>() & 0x83ff
+        } else {
+            // Random anything
+            rng.gen::<u16>()
+        }
+    }
+
+    fn f16_is_nan(x: u16) -> bool {
+        x & 0x7fff > 0x7c00
+    }
+
+    /// Exact conversion of a (non-NaN) half to a double
+    fn f16_to_f64(x: u16) -> f64 {
+        let sign = if x & 0x8000 != 0 { -1.0 } else { 1.0 };
+        let exponent = ((x >> 10) & 0x1f) as i32;
+        let significand = (x & 0x3ff) as f64;
+        match exponent {
+            0 => sign * significand * 2f64.powi(-24),
+            0x1f => sign * ::std::f64::INFINITY,
+            _ => sign * (significand + 1024.0) * 2f64.powi(exponent - 25),
+        }
+    }
+
+    /// Reference conversion of a (non-NaN) double to the nearest half, ties to
+    /// even
+    fn f64_to_f16(x: f64) -> u16 {
+        let sign = if x.is_sign_negative() { 0x8000 } else { 0 };
+        let x = x.abs();
+
+        // Find the two consecutive halves around `x`. All the differences
+        // below are exact.
+        let (mut below, mut above) = (0u16, 0x7c00u16);
+        while above - below > 1 {
+            let mid = below + (above - below) / 2;
+            if f16_to_f64(mid) <= x {
+                below = mid;
+            } else {
+                above = mid;
+            }
+        }
+        // 65536 is where the next binade would start if the exponent didn't
+        // overflow to infinity.
+        let below_value = f16_to_f64(below);
+        let above_value = if above == 0x7c00 { 65536.0 } else { f16_to_f64(above) };
+        let rounded = if x - below_value < above_value - x {
+            below
+        } else if x - below_value > above_value - x {
+            above
+        } else if below & 1 == 0 {
+            below
+        } else {
+            above
+        };
+        sign | rounded
+    }
+
+    // `bf16`s are handled as their bit patterns too. They're the top half of
+    // an `f32`.
+    fn bf16_is_nan(x: u16) -> bool {
+        x & 0x7fff > 0x7f80
+    }
+
+    fn bf16_to_f64(x: u16) -> f64 {
+        mk_f32((x as u32) << 16) as f64
+    }
+
+    /// Reference conversion of a (non-NaN) double to the nearest bfloat16,
+    /// ties to even
+    fn f64_to_bf16(x: f64) -> u16 {
+        let sign = if x.is_sign_negative() { 0x8000 } else { 0 };
+        let x = x.abs();
+
+        // Find the two consecutive `bf16`s around `x`, like in `f64_to_f16`
+        let (mut below, mut above) = (0u16, 0x7f80u16);
+        while above - below > 1 {
+            let mid = below + (above - below) / 2;
+            if bf16_to_f64(mid) <= x {
+                below = mid;
+            } else {
+                above = mid;
+            }
+        }
+        // 2^128 is where the next binade would start if the exponent didn't
+        // overflow to infinity.
+        let below_value = bf16_to_f64(below);
+        let above_value = if above == 0x7f80 { 2f64.powi(128) } else { bf16_to_f64(above) };
+        let rounded = if x - below_value < above_value - x {
+            below
+        } else if x - below_value > above_value - x {
+            above
+        } else if below & 1 == 0 {
+            below
+        } else {
+            above
+        };
+        sign | rounded
+    }
+
+    // There's no quad precision type in Rust either, so `f128`s are handled as
+    // their bit patterns too. The reference implementations below compute the
+    // exact result of an operation and round it once.
+    const F128_SIGN_MASK: u128 = 1 << 127;
+    const F128_SIGNIFICAND_MASK: u128 = (1 << 112) - 1;
+    const F128_INFINITY: u128 = 0x7fff << 112;
+    const F128_NAN: u128 = 0x7fff8 << 108;
+
+    fn gen_f128<R>(rng: &mut R) -> u128
+    where
+        R: Rng,
+    {
+        let bits = gen_u128(rng);
+        if rng.gen_weighted_bool(10) {
+            // Special values
+            *rng.choose(
+                &[
+                    0,
+                    F128_SIGN_MASK,
+                    F128_INFINITY,
+                    F128_SIGN_MASK | F128_INFINITY,
+                    F128_NAN,
+                ],
+            )
+                    .unwrap()
+        } else if rng.gen_weighted_bool(10) {
+            // Denormalized
+            bits & (F128_SIGN_MASK | F128_SIGNIFICAND_MASK)
+        } else if rng.gen() {
+            // Exponent close to the bias, so the result is likely a normal
+            // number
+            let exponent = rng.gen_range(0x3f00, 0x4100) as u128;
+            bits & (F128_SIGN_MASK | F128_SIGNIFICAND_MASK) | exponent << 112
+        } else {
+            // Random anything
+            bits
+        }
+    }
+
+    fn f128_is_nan(x: u128) -> bool {
+        x & !F128_SIGN_MASK > F128_INFINITY
+    }
+
+    /// Splits a finite non-zero `f128` into its sign, significand and exponent,
+    /// such that its magnitude is `significand * 2^exponent`
+    fn f128_unpack(x: u128) -> (bool, u128, i32) {
+        let sign = x & F128_SIGN_MASK != 0;
+        let exponent = (x >> 112) as i32 & 0x7fff;
+        let significand = x & F128_SIGNIFICAND_MASK;
+        if exponent == 0 {
+            (sign, significand, -16494)
+        } else {
+            (sign, significand | 1 << 112, exponent - 16495)
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    enum RoundingMode {
+        ToNearest,
+        Downward,
+        Upward,
+        TowardZero,
+    }
+
+    fn gen_rounding_mode<R>(rng: &mut R) -> RoundingMode
+    where
+        R: Rng,
+    {
+        *rng.choose(
+            &[
+                RoundingMode::ToNearest,
+                RoundingMode::Downward,
+                RoundingMode::Upward,
+                RoundingMode::TowardZero,
+            ],
+        ).unwrap()
+    }
+
+    /// Reference rounding of `x` to an integer in the given rounding mode, ties
+    /// to even when rounding to nearest
+    fn f64_round_integral(x: f64, mode: RoundingMode) -> f64 {
+        match mode {
+            RoundingMode::ToNearest => {
+                let r = x.round();
+                if (r - x).abs() == 0.5 { 2.0 * (x / 2.0).round() } else { r }
+            }
+            RoundingMode::Downward => x.floor(),
+            RoundingMode::Upward => x.ceil(),
+            RoundingMode::TowardZero => x.trunc(),
+        }
+    }
+
+    /// Reference conversion of an integral `x` to an `i64`, which saturates
+    /// like `__fixdfdi`, also for NaNs
+    fn f64_to_i64(x: f64) -> i64 {
+        if x.is_nan() {
+            if x.is_sign_negative() { i64::min_value() } else { i64::max_value() }
+        } else {
+            x as i64
+        }
+    }
+
+    // The exception flags of `float::fenv`
+    const INVALID: u32 = 0x01;
+    const DIVIDE_BY_ZERO: u32 = 0x02;
+    const OVERFLOW: u32 = 0x04;
+    const UNDERFLOW: u32 = 0x08;
+    const INEXACT: u32 = 0x10;
+
+    /// Rounds `(significand + d) * 2^exponent` to a binary floating point
+    /// number with the given field widths, where `0 < d < 1` if `sticky` is set
+    /// and `d == 0` otherwise. If `sticky` is set `significand` must be at
+    /// least `2^(significand_bits + 4)`, so the bits that are discarded include
+    /// the round bit.
+    fn round_float(
+        sign: bool,
+        significand: u128,
+        exponent: i32,
+        sticky: bool,
+        significand_bits: i32,
+        exponent_bits: i32,
+        mode: RoundingMode,
+    ) -> u128 {
+        let (bits, _) = round_float_except(
+            sign,
+            significand,
+            exponent,
+            sticky,
+            significand_bits,
+            exponent_bits,
+            mode,
+        );
+        bits
+    }
+
+    /// Like `round_float`, also returns the exceptions raised by the rounding.
+    /// Underflow is detected before rounding.
+    fn round_float_except(
+        sign: bool,
+        significand: u128,
+        exponent: i32,
+        sticky: bool,
+        significand_bits: i32,
+        exponent_bits: i32,
+        mode: RoundingMode,
+    ) -> (u128, u32) {
+        let negative = sign;
+        let sign = if sign { 1 << (significand_bits + exponent_bits) } else { 0 };
+        let infinity = ((1 << exponent_bits) - 1) << significand_bits;
+        if significand == 0 {
+            return (sign, 0);
+        }
+
+        // Whether inexact results are rounded away from zero, `None` when
+        // rounding to nearest
+        let away = match mode {
+            RoundingMode::ToNearest => None,
+            RoundingMode::Downward => Some(negative),
+            RoundingMode::Upward => Some(!negative),
+            RoundingMode::TowardZero => Some(false),
+        };
+
+        // The exponent of the last bit of the result. Denormals have the same
+        // exponent as the smallest normal numbers.
+        let min_exponent = 2 - (1 << (exponent_bits - 1)) - significand_bits;
+        let top_exponent = exponent + 127 - significand.leading_zeros() as i32;
+        let bottom_exponent = ::std::cmp::max(top_exponent - significand_bits, min_exponent);
+        let shift = bottom_exponent - exponent;
+
+        let (result, inexact) = if shift <= 0 {
+            (significand << -shift, false)
+        } else if shift > 128 {
+            // Less than half of the smallest denormal
+            (if away == Some(true) { 1 } else { 0 }, true)
+        } else {
+            let (result, rest) = if shift == 128 {
+                (0, significand)
+            } else {
+                (significand >> shift, significand & ((1 << shift) - 1))
+            };
+            let half = 1 << (shift - 1);
+            let round_up = match away {
+                None => rest > half || rest == half && (sticky || result & 1 == 1),
+                Some(away) => away && (rest != 0 || sticky),
+            };
+            (if round_up { result + 1 } else { result }, rest != 0 || sticky)
+        };
+        let tiny = top_exponent < min_exponent + significand_bits;
+        let flags = match (inexact, tiny) {
+            (false, _) => 0,
+            (true, false) => INEXACT,
+            (true, true) => UNDERFLOW | INEXACT,
+        };
+
+        // The implicit bit of a normal result adds one to the biased exponent,
+        // which also takes care of a carry out of the significand.
+        let biased_exponent = bottom_exponent - min_exponent;
+        let overflow = if away == Some(false) {
+            // The largest finite number
+            (sign | (infinity - 1), OVERFLOW | INEXACT)
+        } else {
+            (sign | infinity, OVERFLOW | INEXACT)
+        };
+        if biased_exponent >= (1 << exponent_bits) - 1 {
+            return overflow;
+        }
+        let abs = ((biased_exponent as u128) << significand_bits) + result;
+        if abs >= infinity { overflow } else { (sign | abs, flags) }
+    }
+
+    fn f128_round(sign: bool, significand: u128, exponent: i32, sticky: bool) -> u128 {
+        round_float(sign, significand, exponent, sticky, 112, 15, RoundingMode::ToNearest)
+    }
+
+    /// Splits a binary floating point number with the given field widths into
+    /// its sign, significand and exponent, such that its magnitude is
+    /// `significand * 2^exponent` if it's finite
+    fn float_unpack(x: u128, significand_bits: i32, exponent_bits: i32) -> (bool, u128, i32) {
+        let sign = x >> (significand_bits + exponent_bits) != 0;
+        let exponent = (x >> significand_bits) as i32 & ((1 << exponent_bits) - 1);
+        let significand = x & ((1 << significand_bits) - 1);
+        let bias = (1 << (exponent_bits - 1)) - 1;
+        if exponent == 0 {
+            (sign, significand, 1 - bias - significand_bits)
+        } else {
+            (sign, significand | 1 << significand_bits, exponent - bias - significand_bits)
+        }
+    }
+
+    /// Returns `(is_nan, is_signaling_nan)` for a binary floating point number
+    /// with the given field widths
+    fn float_classify_nan(x: u128, significand_bits: i32, exponent_bits: i32) -> (bool, bool) {
+        let infinity = ((1 << exponent_bits) - 1) << significand_bits;
+        let abs = x & ((1 << (significand_bits + exponent_bits)) - 1);
+        let is_nan = abs > infinity;
+        (is_nan, is_nan && abs & 1 << (significand_bits - 1) == 0)
+    }
+
+    /// The result of an operation with the operands `a` and `b`, one of which
+    /// is NaN. This is the first NaN operand quieted, or the default NaN with
+    /// the `default-nan` feature.
+    fn float_propagate_nan(a: u128, b: u128, significand_bits: i32, exponent_bits: i32) -> u128 {
+        let quiet_bit = 1 << (significand_bits - 1);
+        let infinity = ((1 << exponent_bits) - 1) << significand_bits;
+        if cfg!(feature = "default-nan") {
+            infinity | quiet_bit
+        } else if float_classify_nan(a, significand_bits, exponent_bits).0 {
+            a | quiet_bit
+        } else {
+            b | quiet_bit
+        }
+    }
+
+    /// Replaces the NaN result `c` of a binary operation on `f32`s or `f64`s
+    /// with the one of `float`, which doesn't depend on the host. NaN operands
+    /// are propagated, and invalid operations return the positive default NaN.
+    fn float_nan_result(
+        a: u128,
+        b: u128,
+        c: u128,
+        significand_bits: i32,
+        exponent_bits: i32,
+    ) -> u128 {
+        let infinity = ((1 << exponent_bits) - 1) << significand_bits;
+        let (a_is_nan, _) = float_classify_nan(a, significand_bits, exponent_bits);
+        let (b_is_nan, _) = float_classify_nan(b, significand_bits, exponent_bits);
+        let (c_is_nan, _) = float_classify_nan(c, significand_bits, exponent_bits);
+        if a_is_nan || b_is_nan {
+            float_propagate_nan(a, b, significand_bits, exponent_bits)
+        } else if c_is_nan {
+            infinity | 1 << (significand_bits - 1)
+        } else {
+            c
+        }
+    }
+
+    /// Converts the NaN `a` to another binary floating point format like
+    /// `float::extend` and `float::trunc`: the result is quiet and has the
+    /// sign and the top bits of the payload of `a`, or it's the default NaN
+    /// with the `default-nan` feature
+    fn float_convert_nan(
+        a: u128,
+        src_significand_bits: i32,
+        src_exponent_bits: i32,
+        dst_significand_bits: i32,
+        dst_exponent_bits: i32,
+    ) -> u128 {
+        let dst_infinity = ((1 << dst_exponent_bits) - 1) << dst_significand_bits;
+        let dst_quiet_bit = 1 << (dst_significand_bits - 1);
+        if cfg!(feature = "default-nan") {
+            return dst_infinity | dst_quiet_bit;
+        }
+        let sign = a >> (src_significand_bits + src_exponent_bits) & 1;
+        let payload = a & ((1 << (src_significand_bits - 1)) - 1);
+        let payload = if dst_significand_bits > src_significand_bits {
+            payload << (dst_significand_bits - src_significand_bits)
+        } else {
+            payload >> (src_significand_bits - dst_significand_bits)
+        };
+        sign << (dst_significand_bits + dst_exponent_bits) | dst_infinity | dst_quiet_bit | payload
+    }
+
+    /// Adds two binary floating point numbers with the given field widths,
+    /// returning the sum and the exceptions it raises
+    fn float_add(
+        a: u128,
+        b: u128,
+        significand_bits: i32,
+        exponent_bits: i32,
+        mode: RoundingMode,
+    ) -> (u128, u32) {
+        let sign_mask = 1 << (significand_bits + exponent_bits);
+        let infinity = ((1 << exponent_bits) - 1) << significand_bits;
+        let nan = infinity | 1 << (significand_bits - 1);
+        // The sign of an exact zero sum of operands with opposite signs
+        let zero = if mode == RoundingMode::Downward { sign_mask } else { 0 };
+
+        let (a_is_nan, a_is_snan) = float_classify_nan(a, significand_bits, exponent_bits);
+        let (b_is_nan, b_is_snan) = float_classify_nan(b, significand_bits, exponent_bits);
+        let a_abs = a & !sign_mask;
+        let b_abs = b & !sign_mask;
+        if a_is_nan || b_is_nan {
+            let nan = float_propagate_nan(a, b, significand_bits, exponent_bits);
+            return (nan, if a_is_snan || b_is_snan { INVALID } else { 0 });
+        }
+
+        if a_abs == infinity {
+            return if b_abs == infinity && (a ^ b) & sign_mask != 0 {
+                (nan, INVALID)
+            } else {
+                (a, 0)
+            };
+        }
+        if b_abs == infinity {
+            return (b, 0);
+        }
+        if a_abs == 0 && b_abs == 0 {
+            return (if a == b { a } else { zero }, 0);
+        }
+        if a_abs == 0 {
+            return (b, 0);
+        }
+        if b_abs == 0 {
+            return (a, 0);
+        }
+
+        let (a, b) = if a_abs < b_abs { (b, a) } else { (a, b) };
+        let (a_sign, a_significand, a_exponent) = float_unpack(a, significand_bits, exponent_bits);
+        let (b_sign, b_significand, b_exponent) = float_unpack(b, significand_bits, exponent_bits);
+
+        // Shift the significands up so that the sum is just below 2^128, and
+        // align b with a. The bits of b that are shifted out are sticky.
+        let shift = 126 - significand_bits;
+        let a_significand = a_significand << shift;
+        let b_significand = b_significand << shift;
+        let align = a_exponent - b_exponent;
+        let (b_significand, sticky) = if align >= 128 {
+            (0, true)
+        } else {
+            (b_significand >> align, b_significand & ((1 << align) - 1) != 0)
+        };
+
+        let (significand, sticky) = if a_sign == b_sign {
+            (a_significand + b_significand, sticky)
+        } else if sticky {
+            // a - (b + d) = (a - b - 1) + (1 - d)
+            (a_significand - b_significand - 1, true)
+        } else if a_significand == b_significand {
+            return (zero, 0);
+        } else {
+            (a_significand - b_significand, false)
+        };
+        round_float_except(
+            a_sign,
+            significand,
+            a_exponent - shift,
+            sticky,
+            significand_bits,
+            exponent_bits,
+            mode,
+        )
+    }
+
+    fn f128_add(a: u128, b: u128) -> u128 {
+        float_add(a, b, 112, 15, RoundingMode::ToNearest).0
+    }
+
+    fn f128_sub(a: u128, b: u128) -> u128 {
+        f128_add(a, b ^ F128_SIGN_MASK)
+    }
+
+    /// Multiplies two binary floating point numbers with the given field
+    /// widths, of at most 64 bits, returning the product and the exceptions
+    /// it raises.
+    fn float_mul(a: u128, b: u128, significand_bits: i32, exponent_bits: i32) -> (u128, u32) {
+        let sign_mask = 1 << (significand_bits + exponent_bits);
+        let infinity = ((1 << exponent_bits) - 1) << significand_bits;
+        let nan = infinity | 1 << (significand_bits - 1);
+
+        let (a_is_nan, a_is_snan) = float_classify_nan(a, significand_bits, exponent_bits);
+        let (b_is_nan, b_is_snan) = float_classify_nan(b, significand_bits, exponent_bits);
+        if a_is_nan || b_is_nan {
+            let nan = float_propagate_nan(a, b, significand_bits, exponent_bits);
+            return (nan, if a_is_snan || b_is_snan { INVALID } else { 0 });
+        }
+
+        let sign = (a ^ b) & sign_mask;
+        let a_abs = a & !sign_mask;
+        let b_abs = b & !sign_mask;
+        if a_abs == infinity || b_abs == infinity {
+            return if a_abs == 0 || b_abs == 0 {
+                (nan, INVALID)
+            } else {
+                (sign | infinity, 0)
+            };
+        }
+        if a_abs == 0 || b_abs == 0 {
+            return (sign, 0);
+        }
+
+        let (_, a_significand, a_exponent) = float_unpack(a, significand_bits, exponent_bits);
+        let (_, b_significand, b_exponent) = float_unpack(b, significand_bits, exponent_bits);
+        round_float_except(
+            sign != 0,
+            a_significand * b_significand,
+            a_exponent + b_exponent,
+            false,
+            significand_bits,
+            exponent_bits,
+            RoundingMode::ToNearest,
+        )
+    }
+
+    /// Divides two binary floating point numbers with the given field widths,
+    /// of at most 64 bits, returning the quotient and the exceptions it
+    /// raises.
+    fn float_div(a: u128, b: u128, significand_bits: i32, exponent_bits: i32) -> (u128, u32) {
+        let sign_mask = 1 << (significand_bits + exponent_bits);
+        let infinity = ((1 << exponent_bits) - 1) << significand_bits;
+        let nan = infinity | 1 << (significand_bits - 1);
+
+        let (a_is_nan, a_is_snan) = float_classify_nan(a, significand_bits, exponent_bits);
+        let (b_is_nan, b_is_snan) = float_classify_nan(b, significand_bits, exponent_bits);
+        if a_is_nan || b_is_nan {
+            let nan = float_propagate_nan(a, b, significand_bits, exponent_bits);
+            return (nan, if a_is_snan || b_is_snan { INVALID } else { 0 });
+        }
+
+        let sign = (a ^ b) & sign_mask;
+        let a_abs = a & !sign_mask;
+        let b_abs = b & !sign_mask;
+        if a_abs == infinity {
+            return if b_abs == infinity {
+                (nan, INVALID)
+            } else {
+                (sign | infinity, 0)
+            };
+        }
+        if b_abs == infinity {
+            return (sign, 0);
+        }
+        if b_abs == 0 {
+            return if a_abs == 0 {
+                (nan, INVALID)
+            } else {
+                (sign | infinity, DIVIDE_BY_ZERO)
+            };
+        }
+        if a_abs == 0 {
+            return (sign, 0);
+        }
+
+        // Shift the dividend up to bit 126, which gives a quotient of more
+        // than 64 bits
+        let (_, a_significand, a_exponent) = float_unpack(a, significand_bits, exponent_bits);
+        let (_, b_significand, b_exponent) = float_unpack(b, significand_bits, exponent_bits);
+        let shift = a_significand.leading_zeros() - 1;
+        let a_significand = a_significand << shift;
+        round_float_except(
+            sign != 0,
+            a_significand / b_significand,
+            a_exponent - shift as i32 - b_exponent,
+            a_significand % b_significand != 0,
+            significand_bits,
+            exponent_bits,
+            RoundingMode::ToNearest,
+        )
+    }
+
+    /// Evaluates `op`, one of `float_add`, `float_mul` or `float_div`, in
+    /// flush-to-zero mode: denormal operands are read as zeros of the same
+    /// sign, and results that are tiny before rounding become zeros
+    fn float_flush_to_zero<F>(
+        op: F,
+        a: u128,
+        b: u128,
+        significand_bits: i32,
+        exponent_bits: i32,
+    ) -> u128
+    where
+        F: Fn(u128, u128) -> (u128, u32),
+    {
+        let sign_mask = 1 << (significand_bits + exponent_bits);
+        let min_normal = 1 << significand_bits;
+        let flush = |x: u128| {
+            let abs = x & !sign_mask;
+            if abs != 0 && abs < min_normal { x & sign_mask } else { x }
+        };
+        let (c, flags) = op(flush(a), flush(b));
+        // Tiny results that aren't exact raise underflow, exact ones are
+        // denormal
+        if flags & UNDERFLOW != 0 { c & sign_mask } else { flush(c) }
+    }
+
+    fn f128_mul(a: u128, b: u128) -> u128 {
+        let sign = (a ^ b) & F128_SIGN_MASK;
+        let a_abs = a & !F128_SIGN_MASK;
+        let b_abs = b & !F128_SIGN_MASK;
+        if a_abs == F128_INFINITY || b_abs == F128_INFINITY {
+            return if a_abs == 0 || b_abs == 0 {
+                F128_NAN
+            } else {
+                sign | F128_INFINITY
+            };
+        }
+        if a_abs == 0 || b_abs == 0 {
+            return sign;
+        }
+
+        let (_, a_significand, a_exponent) = f128_unpack(a);
+        let (_, b_significand, b_exponent) = f128_unpack(b);
+
+        // Full 226-bit product of the significands, from their 64-bit halves
+        let low_mask = ::std::u64::MAX as u128;
+        let (a_high, a_low) = (a_significand >> 64, a_significand & low_mask);
+        let (b_high, b_low) = (b_significand >> 64, b_significand & low_mask);
+        let middle = a_high * b_low + a_low * b_high;
+        let (low, carry) = (a_low * b_low).overflowing_add(middle << 64);
+        let high = a_high * b_high + (middle >> 64) + carry as u128;
+
+        let exponent = a_exponent + b_exponent;
+        if high == 0 {
+            f128_round(sign != 0, low, exponent, false)
+        } else {
+            // Keep the top 128 bits, the rest is sticky
+            let shift = 128 - high.leading_zeros();
+            let significand = high << (128 - shift) | low >> shift;
+            let sticky = low << (128 - shift) != 0;
+            f128_round(sign != 0, significand, exponent + shift as i32, sticky)
+        }
+    }
+
+    fn f128_div(a: u128, b: u128) -> u128 {
+        let sign = (a ^ b) & F128_SIGN_MASK;
+        let a_abs = a & !F128_SIGN_MASK;
+        let b_abs = b & !F128_SIGN_MASK;
+        if a_abs == F128_INFINITY {
+            return if b_abs == F128_INFINITY {
+                F128_NAN
+            } else {
+                sign | F128_INFINITY
+            };
+        }
+        if b_abs == F128_INFINITY {
+            return sign;
+        }
+        if b_abs == 0 {
+            return if a_abs == 0 { F128_NAN } else { sign | F128_INFINITY };
+        }
+        if a_abs == 0 {
+            return sign;
+        }
+
+        // Normalize the significands so their top bit is bit 112
+        let (_, a_significand, a_exponent) = f128_unpack(a);
+        let (_, b_significand, b_exponent) = f128_unpack(b);
+        let a_shift = a_significand.leading_zeros() - 15;
+        let b_shift = b_significand.leading_zeros() - 15;
+        let a_significand = a_significand << a_shift;
+        let b_significand = b_significand << b_shift;
+        let exponent = (a_exponent - a_shift as i32) - (b_exponent - b_shift as i32);
+
+        // Long division, one bit of the quotient at a time. The quotient ends
+        // up with 119 or 120 bits.
+        let mut quotient = 0u128;
+        let mut remainder = a_significand;
+        for _ in 0..120 {
+            quotient <<= 1;
+            if remainder >= b_significand {
+                remainder -= b_significand;
+                quotient |= 1;
+            }
+            remainder <<= 1;
+        }
+
+        f128_round(sign != 0, quotient, exponent - 119, remainder != 0)
+    }
+
+    /// Maps a (non-NaN) `f128` to an integer with the same ordering
+    fn f128_order_key(x: u128) -> i128 {
+        let abs = (x & !F128_SIGN_MASK) as i128;
+        if x & F128_SIGN_MASK != 0 { -abs } else { abs }
+    }
+
+    /// Exact conversion of a (non-NaN) double to an `f128`
+    fn f64_to_f128(x: f64) -> u128 {
+        let bits = to_u64(x);
+        let sign = bits >> 63 != 0;
+        let exponent = (bits >> 52) as i32 & 0x7ff;
+        let significand = (bits & ((1 << 52) - 1)) as u128;
+        match exponent {
+            0 => f128_round(sign, significand, -1074, false),
+            0x7ff => (sign as u128) << 127 | F128_INFINITY,
+            _ => f128_round(sign, significand | 1 << 52, exponent - 1075, false),
+        }
+    }
+
+    /// Reference conversion of a (non-NaN) `f128` to the nearest double, ties to
+    /// even
+    fn f128_to_f64(x: u128) -> u64 {
+        let (sign, significand, exponent) = f128_unpack(x);
+        if x & !F128_SIGN_MASK == F128_INFINITY {
+            return (sign as u64) << 63 | 0x7ff << 52;
+        }
+        round_float(sign, significand, exponent, false, 52, 11, RoundingMode::ToNearest) as u64
+    }
+
+    /// Reference conversion of a (non-NaN) `f128` to the nearest float, ties to
+    /// even
+    fn f128_to_f32(x: u128) -> u32 {
+        let (sign, significand, exponent) = f128_unpack(x);
+        if x & !F128_SIGN_MASK == F128_INFINITY {
+            return (sign as u32) << 31 | 0xff << 23;
+        }
+        round_float(sign, significand, exponent, false, 23, 8, RoundingMode::ToNearest) as u32
+    }
+
+    /// Truncates an `f128` towards zero, returning the sign and magnitude of
+    /// the result, or `None` if the magnitude doesn't fit in a `u128` (this
+    /// includes infinities and NaNs)
+    fn f128_trunc(x: u128) -> Option<(bool, u128)> {
+        let (sign, significand, exponent) = f128_unpack(x);
+        if exponent <= -113 {
+            Some((sign, 0))
+        } else if exponent < 0 {
+            Some((sign, significand >> -exponent))
+        } else if significand.leading_zeros() as i32 >= exponent {
+            Some((sign, significand << exponent))
+        } else {
+            None
+        }
+    }
+
+    fn f128_to_i128(x: u128) -> Option<i128> {
+        match f128_trunc(x) {
+            Some((false, magnitude)) if magnitude < 1 << 127 => Some(magnitude as i128),
+            Some((true, magnitude)) if magnitude <= 1 << 127 => {
+                Some((magnitude as i128).wrapping_neg())
+            }
+            _ => None,
+        }
+    }
+
+    fn f128_to_u128(x: u128) -> Option<u128> {
+        match f128_trunc(x) {
+            Some((false, magnitude)) | Some((true, magnitude @ 0)) => Some(magnitude),
+            _ => None,
+        }
+    }
+
+    /// Reference saturating conversion of an `f128` to a `bits`-bit integer,
+    /// like Rust's `as`. NaN converts to 0. The result is returned as the low
+    /// bits of a `u128`, in two's complement for negative numbers.
+    fn f128_to_int_sat(x: u128, signed: bool, bits: u32) -> u128 {
+        if f128_is_nan(x) {
+            return 0;
+        }
+        // Out of range of a `u128` means out of range of any integer
+        let (sign, magnitude) = f128_trunc(x).unwrap_or((x & F128_SIGN_MASK != 0, !0));
+        saturate_int(sign, magnitude, signed, bits)
+    }
+
+    /// Clamps the integer with the given sign and magnitude to the range of a
+    /// `bits`-bit integer, in the representation of `f128_to_int_sat`
+    fn saturate_int(sign: bool, magnitude: u128, signed: bool, bits: u32) -> u128 {
+        let max = if signed { (1 << (bits - 1)) - 1 } else { !0 >> (128 - bits) };
+        let min_magnitude = if signed { 1 << (bits - 1) } else { 0 };
+        if sign {
+            ::std::cmp::min(magnitude, min_magnitude).wrapping_neg()
+        } else {
+            ::std::cmp::min(magnitude, max)
+        }
+    }
+
+    /// Reference conversion of an integer to the nearest `f128`, ties to even
+    fn i128_to_f128(x: i128) -> u128 {
+        f128_round(x < 0, x.wrapping_abs() as u128, 0, false)
+    }
+
+    fn u128_to_f128(x: u128) -> u128 {
+        f128_round(false, x, 0, false)
+    }
+
+    // The x87 extended precision format is handled the same way. Unlike the
+    // IEEE formats, it has an explicit integer bit, which is set for normal
+    // numbers and clear for denormals.
+    const F80_SIGN_MASK: u128 = 1 << 79;
+    const F80_INTEGER_BIT: u128 = 1 << 63;
+    const F80_INFINITY: u128 = 0x7fff << 64 | F80_INTEGER_BIT;
+    const F80_NAN: u128 = F80_SIGN_MASK | 0x7fff << 64 | 3 << 62;
+
+    fn gen_f80<R>(rng: &mut R) -> u128
+    where
+        R: Rng,
+    {
+        let bits = gen_u128(rng) & ((1 << 80) - 1);
+        if rng.gen_weighted_bool(10) {
+            // Special values
+            *rng.choose(
+                &[
+                    0,
+                    F80_SIGN_MASK,
+                    F80_INFINITY,
+                    F80_SIGN_MASK | F80_INFINITY,
+                    F80_NAN,
+                ],
+            )
+                    .unwrap()
+        } else if rng.gen_weighted_bool(10) {
+            // Denormalized
+            bits & (F80_SIGN_MASK | (F80_INTEGER_BIT - 1))
+        } else if rng.gen() {
+            // Exponent close to the bias, so the value is likely in the range
+            // of the integer types
+            let exponent = rng.gen_range(0x3ff0, 0x4090) as u128;
+            bits & (F80_SIGN_MASK | (F80_INTEGER_BIT - 1)) | exponent << 64 | F80_INTEGER_BIT
+        } else if bits & (0x7fff << 64) == 0 {
+            // Random anything
+            bits & !F80_INTEGER_BIT
+        } else {
+            bits | F80_INTEGER_BIT
+        }
+    }
+
+    fn f80_is_nan(x: u128) -> bool {
+        x & (0x7fff << 64) == 0x7fff << 64 && x & (F80_INTEGER_BIT - 1) != 0
+    }
+
+    /// Splits an `f80` into its sign, significand and exponent, such that its
+    /// magnitude is `significand * 2^exponent` if it's finite
+    fn f80_unpack(x: u128) -> (bool, u128, i32) {
+        let sign = x & F80_SIGN_MASK != 0;
+        let exponent = (x >> 64) as i32 & 0x7fff;
+        let significand = x & ((1 << 64) - 1);
+        (sign, significand, ::std::cmp::max(exponent, 1) - 16446)
+    }
+
+    /// Like `f128_round`, for `f80`
+    fn f80_round(
+        sign: bool,
+        significand: u128,
+        exponent: i32,
+        sticky: bool,
+        mode: RoundingMode,
+    ) -> u128 {
+        f80_round_except(sign, significand, exponent, sticky, mode).0
+    }
+
+    /// Like `round_float_except`, for `f80`
+    fn f80_round_except(
+        sign: bool,
+        significand: u128,
+        exponent: i32,
+        sticky: bool,
+        mode: RoundingMode,
+    ) -> (u128, u32) {
+        // Insert the explicit integer bit
+        let (bits, flags) = round_float_except(sign, significand, exponent, sticky, 63, 15, mode);
+        let sign_exponent = bits >> 63;
+        let integer_bit = if sign_exponent & 0x7fff != 0 { F80_INTEGER_BIT } else { 0 };
+        (sign_exponent << 64 | integer_bit | bits & (F80_INTEGER_BIT - 1), flags)
+    }
+
+    /// The exceptions raised by an operation with a NaN operand
+    fn f80_nan_flags(a: u128, b: u128) -> u32 {
+        let is_signaling = |x| f80_is_nan(x) && x & F80_INTEGER_BIT >> 1 == 0;
+        if is_signaling(a) || is_signaling(b) { INVALID } else { 0 }
+    }
+
+    fn f80_mul(a: u128, b: u128) -> (u128, u32) {
+        let a_abs = a & !F80_SIGN_MASK;
+        let b_abs = b & !F80_SIGN_MASK;
+        let sign = (a ^ b) & F80_SIGN_MASK;
+        if f80_is_nan(a) || f80_is_nan(b) {
+            return (F80_NAN, f80_nan_flags(a, b));
+        }
+        if a_abs == F80_INFINITY || b_abs == F80_INFINITY {
+            return if a_abs == 0 || b_abs == 0 {
+                (F80_NAN, INVALID)
+            } else {
+                (sign | F80_INFINITY, 0)
+            };
+        }
+
+        let (_, a_significand, a_exponent) = f80_unpack(a);
+        let (_, b_significand, b_exponent) = f80_unpack(b);
+        f80_round_except(
+            sign != 0,
+            a_significand * b_significand,
+            a_exponent + b_exponent,
+            false,
+            RoundingMode::ToNearest,
+        )
+    }
+
+    fn f80_div(a: u128, b: u128) -> (u128, u32) {
+        let a_abs = a & !F80_SIGN_MASK;
+        let b_abs = b & !F80_SIGN_MASK;
+        let sign = (a ^ b) & F80_SIGN_MASK;
+        if f80_is_nan(a) || f80_is_nan(b) {
+            return (F80_NAN, f80_nan_flags(a, b));
+        }
+        if a_abs == F80_INFINITY {
+            return if b_abs == F80_INFINITY {
+                (F80_NAN, INVALID)
+            } else {
+                (sign | F80_INFINITY, 0)
+            };
+        }
+        if b_abs == F80_INFINITY {
+            return (sign, 0);
+        }
+        if b_abs == 0 {
+            return if a_abs == 0 {
+                (F80_NAN, INVALID)
+            } else {
+                (sign | F80_INFINITY, DIVIDE_BY_ZERO)
+            };
+        }
+        if a_abs == 0 {
+            return (sign, 0);
+        }
+
+        // Normalize the significands so their top bit is bit 63
+        let (_, a_significand, a_exponent) = f80_unpack(a);
+        let (_, b_significand, b_exponent) = f80_unpack(b);
+        let a_shift = a_significand.leading_zeros() - 64;
+        let b_shift = b_significand.leading_zeros() - 64;
+        let a_significand = a_significand << a_shift;
+        let b_significand = b_significand << b_shift;
+        let exponent = (a_exponent - a_shift as i32) - (b_exponent - b_shift as i32);
+
+        // Long division, one bit of the quotient at a time. The quotient ends
+        // up with 65 or 66 bits.
+        let mut quotient = 0u128;
+        let mut remainder = a_significand;
+        for _ in 0..66 {
+            quotient <<= 1;
+            if remainder >= b_significand {
+                remainder -= b_significand;
+                quotient |= 1;
+            }
+            remainder <<= 1;
+        }
+
+        f80_round_except(
+            sign != 0,
+            quotient,
+            exponent - 65,
+            remainder != 0,
+            RoundingMode::ToNearest,
+        )
+    }
+
+    /// `a` raised to the power `b` the way `__powixf2` computes it, by repeated
+    /// squaring, and the exceptions raised by the computation
+    fn f80_powi(mut a: u128, mut b: i32) -> (u128, u32) {
+        let one = 0x3fff << 64 | F80_INTEGER_BIT;
+        let recip = b < 0;
+        let mut r = one;
+        let mut flags = 0;
+        loop {
+            if b & 1 != 0 {
+                let (product, product_flags) = f80_mul(r, a);
+                r = product;
+                flags |= product_flags;
+            }
+            b /= 2;
+            if b == 0 {
+                break;
+            }
+            let (square, square_flags) = f80_mul(a, a);
+            a = square;
+            flags |= square_flags;
+        }
+
+        if recip {
+            let (quotient, quotient_flags) = f80_div(one, r);
+            (quotient, flags | quotient_flags)
+        } else {
+            (r, flags)
+        }
+    }
+
+    /// Truncates an `f80` towards zero, returning the sign and magnitude of
+    /// the result, or `None` if the magnitude doesn't fit in a `u128` (this
+    /// includes infinities and NaNs)
+    fn f80_trunc(x: u128) -> Option<(bool, u128)> {
+        let (sign, significand, exponent) = f80_unpack(x);
+        if exponent <= -64 {
+            Some((sign, 0))
+        } else if exponent < 0 {
+            Some((sign, significand >> -exponent))
+        } else if significand.leading_zeros() as i32 >= exponent {
+            Some((sign, significand << exponent))
+        } else {
+            None
+        }
+    }
+
+    fn f80_to_i128(x: u128) -> Option<i128> {
+        match f80_trunc(x) {
+            Some((false, magnitude)) if magnitude < 1 << 127 => Some(magnitude as i128),
+            Some((true, magnitude)) if magnitude <= 1 << 127 => {
+                Some((magnitude as i128).wrapping_neg())
+            }
+            _ => None,
+        }
+    }
+
+    fn f80_to_u128(x: u128) -> Option<u128> {
+        match f80_trunc(x) {
+            Some((false, magnitude)) | Some((true, magnitude @ 0)) => Some(magnitude),
+            _ => None,
+        }
+    }
+
+    /// Like `f128_to_int_sat`, for `f80`
+    fn f80_to_int_sat(x: u128, signed: bool, bits: u32) -> u128 {
+        if f80_is_nan(x) {
+            return 0;
+        }
+        let (sign, magnitude) = f80_trunc(x).unwrap_or((x & F80_SIGN_MASK != 0, !0));
+        saturate_int(sign, magnitude, signed, bits)
+    }
+
+    /// Reference conversion of an integer to the nearest `f80`, ties to even
+    fn i128_to_f80(x: i128) -> u128 {
+        f80_round(x < 0, x.wrapping_abs() as u128, 0, false, RoundingMode::ToNearest)
+    }
+
+    fn u128_to_f80(x: u128) -> u128 {
+        f80_round(false, x, 0, false, RoundingMode::ToNearest)
+    }
+
     pub fn gen_u128<R>(rng: &mut R) -> u128
     where
         R: Rng,
@@ -3913,6 +14402,52 @@ macro_rules! panic {
         unsafe { mem::transmute(x) }
     }
 
+    pub fn mk_f32(x: u32) -> f32 {
+        unsafe { mem::transmute(x) }
+    }
+
+    pub fn mk_f64(x: u64) -> f64 {
+        unsafe { mem::transmute(x) }
+    }
+
+    // The C library of the host is the reference for the functions that
+    // aren't in `std`, and its compiler runtime, libgcc or compiler-rt, for
+    // the complex multiplications
+    extern "C" {
+        fn remainderf(x: f32, y: f32) -> f32;
+        fn remainder(x: f64, y: f64) -> f64;
+        fn __muldc3(a: f64, b: f64, c: f64, d: f64) -> CComplex<f64>;
+        #[cfg(not(target_arch = "x86"))]
+        fn __mulsc3(a: f32, b: f32, c: f32, d: f32) -> CComplex<f32>;
+        #[cfg(target_arch = "x86")]
+        fn __mulsc3(a: f32, b: f32, c: f32, d: f32) -> u64;
+    }
+
+    /// `_Complex float` or `_Complex double`, which are returned like this
+    /// struct except for `_Complex float` on i386
+    #[repr(C)]
+    struct CComplex<F> {
+        re: F,
+        im: F,
+    }
+
+    fn c_muldc3(a: f64, b: f64, c: f64, d: f64) -> (f64, f64) {
+        let z = unsafe { __muldc3(a, b, c, d) };
+        (z.re, z.im)
+    }
+
+    #[cfg(not(target_arch = "x86"))]
+    fn c_mulsc3(a: f32, b: f32, c: f32, d: f32) -> (f32, f32) {
+        let z = unsafe { __mulsc3(a, b, c, d) };
+        (z.re, z.im)
+    }
+
+    #[cfg(target_arch = "x86")]
+    fn c_mulsc3(a: f32, b: f32, c: f32, d: f32) -> (f32, f32) {
+        let z: [f32; 2] = unsafe { mem::transmute(__mulsc3(a, b, c, d)) };
+        (z[0], z[1])
+    }
+
     fn mk_tests<T, R>(mut n: usize, rng: &mut R) -> String
     where
         T: Eq + Hash + TestCase,
@@ -4059,27 +14594,9 @@ mod c {
                 "addvdi3.c",
                 "addvsi3.c",
                 "apple_versioning.c",
-                "clzdi2.c",
-                "clzsi2.c",
                 "cmpdi2.c",
-                "comparedf2.c",
-                "comparesf2.c",
-                "ctzdi2.c",
-                "ctzsi2.c",
-                "divdc3.c",
-                "divdf3.c",
-                "divsc3.c",
-                "divsf3.c",
                 "divxc3.c",
-                "extendsfdf2.c",
-                "extendhfsf2.c",
-                "floatdisf.c",
-                "floatundisf.c",
                 "int_util.c",
-                "muldc3.c",
-                "muldf3.c",
-                "mulsc3.c",
-                "mulsf3.c",
                 "mulvdi3.c",
                 "mulvsi3.c",
                 "mulxc3.c",
@@ -4095,9 +14612,6 @@ mod c {
                 "powixf2.c",
                 "subvdi3.c",
                 "subvsi3.c",
-                "truncdfhf2.c",
-                "truncdfsf2.c",
-                "truncsfhf2.c",
                 "ucmpdi2.c",
             ],
         );
@@ -4117,9 +14631,7 @@ mod c {
                 &[
                     "absvti2.c",
                     "addvti3.c",
-                    "clzti2.c",
                     "cmpti2.c",
-                    "ctzti2.c",
                     "ffsti2.c",
                     "mulvti3.c",
                     "negti2.c",
@@ -4168,6 +14680,12 @@ mod c {
                             "x86_64/floatundixf.S",
                         ],
                     );
+
+                    // The `xf` intrinsics are shims in `x86_64.rs` on the ELF
+                    // targets
+                    if target_os != "macos" && target_os != "ios" {
+                        sources.remove(&["floatdixf", "floatundixf", "powixf2"]);
+                    }
                 }
             }
 
@@ -4198,20 +14716,11 @@ mod c {
         if target_arch == "arm" && target_os != "ios" {
             sources.extend(
                 &[
-                    "arm/aeabi_cdcmp.S",
-                    "arm/aeabi_cdcmpeq_check_nan.c",
-                    "arm/aeabi_cfcmp.S",
-                    "arm/aeabi_cfcmpeq_check_nan.c",
-                    "arm/aeabi_dcmp.S",
                     "arm/aeabi_div0.c",
                     "arm/aeabi_drsub.c",
-                    "arm/aeabi_fcmp.S",
                     "arm/aeabi_frsub.c",
                     "arm/bswapdi2.S",
                     "arm/bswapsi2.S",
-                    "arm/clzdi2.S",
-                    "arm/clzsi2.S",
-                    "arm/comparesf2.S",
                     "arm/divmodsi4.S",
                     "arm/modsi3.S",
                     "arm/switch16.S",
@@ -4309,39 +14818,13 @@ mod c {
         }
 
         if target_arch == "aarch64" {
-            sources.extend(
-                &[
-                    "comparetf2.c",
-                    "extenddftf2.c",
-                    "extendsftf2.c",
-                    "fixtfdi.c",
-                    "fixtfsi.c",
-                    "fixtfti.c",
-                    "fixunstfdi.c",
-                    "fixunstfsi.c",
-                    "fixunstfti.c",
-                    "floatditf.c",
-                    "floatsitf.c",
-                    "floatunditf.c",
-                    "floatunsitf.c",
-                    "multc3.c",
-                    "trunctfdf2.c",
-                    "trunctfsf2.c",
-                ],
-            );
+            sources.extend(&["multc3.c"]);
         }
 
         // Remove the assembly implementations that won't compile for the target
         if llvm_target[0] == "thumbv6m" {
             sources.remove(
                 &[
-                    "aeabi_cdcmp",
-                    "aeabi_cfcmp",
-                    "aeabi_dcmp",
-                    "aeabi_fcmp",
-                    "clzdi2",
-                    "clzsi2",
-                    "comparesf2",
                     "divmodsi4",
                     "modsi3",
                     "switch16",
@@ -4352,13 +14835,6 @@ mod c {
                     "umodsi3",
                 ],
             );
-
-            // But use some generic implementations where possible
-            sources.extend(&["clzdi2.c", "clzsi2.c"])
-        }
-
-        if llvm_target[0] == "thumbv7m" || llvm_target[0] == "thumbv7em" {
-            sources.remove(&["aeabi_cdcmp", "aeabi_cfcmp"]);
         }
 
         // When compiling in rustbuild (the rust-lang/rust repo) this build
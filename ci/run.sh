@@ -24,17 +24,10 @@ case $1 in
         for t in $(ls tests); do
             t=${t%.rs}
 
-            # TODO(#154) enable these tests when aeabi_*mul are implemented
-            case $t in
-                powi*f2)
-                    continue
-                    ;;
-            esac
-
-            xargo test --test $t --target $1 --features 'mem gen-tests' --no-run
+            xargo test --test $t --target $1 --features 'mem gen-tests fp-exceptions fp-rounding math' --no-run
             qemu-arm-static target/${1}/debug/$t-*
 
-            xargo test --test $t --target $1 --features 'mem gen-tests' --no-run --release
+            xargo test --test $t --target $1 --features 'mem gen-tests fp-exceptions fp-rounding math' --no-run --release
             qemu-arm-static target/${1}/release/$t-*
         done
         ;;
@@ -44,6 +37,10 @@ case $1 in
         $run --features 'gen-tests mangled-names' --release
         $run --features 'gen-tests mangled-names c'
         $run --features 'gen-tests mangled-names c' --release
+        $run --features 'gen-tests mangled-names fp-exceptions'
+        $run --features 'gen-tests mangled-names fp-rounding'
+        $run --features 'gen-tests mangled-names default-nan'
+        $run --features 'gen-tests mangled-names math'
         ;;
 esac
 
@@ -85,15 +82,11 @@ for rlib in $(echo $path); do
 
     # NOTE On i586, It's normal that the get_pc_thunk symbol appears several
     # times so ignore it
-    #
-    # FIXME(#167) - we shouldn't ignore `__builtin_cl` style symbols here.
     set +e
     echo "$stdout" | \
       sort | \
       uniq -d | \
       grep -v __x86.get_pc_thunk | \
-      grep -v __builtin_cl | \
-      grep -v __builtin_ctz | \
       grep 'T __'
 
     if test $? = 0; then
@@ -345,6 +345,30 @@ mod intrinsics {
     pub fn modti3(a: i128, b: i128) -> i128 {
         a % b
     }
+
+    pub fn clzsi2(a: u32) -> u32 {
+        a.leading_zeros()
+    }
+
+    pub fn clzdi2(a: u64) -> u32 {
+        a.leading_zeros()
+    }
+
+    pub fn clzti2(a: u128) -> u32 {
+        a.leading_zeros()
+    }
+
+    pub fn ctzsi2(a: u32) -> u32 {
+        a.trailing_zeros()
+    }
+
+    pub fn ctzdi2(a: u64) -> u32 {
+        a.trailing_zeros()
+    }
+
+    pub fn ctzti2(a: u128) -> u32 {
+        a.trailing_zeros()
+    }
 }
 
 fn run() {
@@ -409,6 +433,12 @@ fn run() {
     bb(umodti3(bb(2), bb(2)));
     bb(divti3(bb(2), bb(2)));
     bb(modti3(bb(2), bb(2)));
+    bb(clzsi2(bb(2)));
+    bb(clzdi2(bb(2)));
+    bb(clzti2(bb(2)));
+    bb(ctzsi2(bb(2)));
+    bb(ctzdi2(bb(2)));
+    bb(ctzti2(bb(2)));
 
     something_with_a_dtor(&|| assert_eq!(bb(1), 1));
 }
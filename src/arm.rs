@@ -57,6 +57,188 @@ pub unsafe fn __aeabi_ldivmod() {
     intrinsics::unreachable();
 }
 
+// NOTE Unlike the `__*sf2` / `__*df2` comparisons, which return a value whose relation to zero
+// encodes the result, the AEABI comparison helpers return 1 if the condition holds and 0 otherwise
+// (including when either operand is NaN, except for the `*un` variants)
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub extern "aapcs" fn __aeabi_fcmpeq(a: f32, b: f32) -> i32 {
+    (::float::cmp::__eqsf2(a, b) == 0) as i32
+}
+
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub extern "aapcs" fn __aeabi_fcmplt(a: f32, b: f32) -> i32 {
+    (::float::cmp::__ltsf2(a, b) < 0) as i32
+}
+
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub extern "aapcs" fn __aeabi_fcmple(a: f32, b: f32) -> i32 {
+    (::float::cmp::__lesf2(a, b) <= 0) as i32
+}
+
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub extern "aapcs" fn __aeabi_fcmpge(a: f32, b: f32) -> i32 {
+    (::float::cmp::__gesf2(a, b) >= 0) as i32
+}
+
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub extern "aapcs" fn __aeabi_fcmpgt(a: f32, b: f32) -> i32 {
+    (::float::cmp::__gtsf2(a, b) > 0) as i32
+}
+
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub extern "aapcs" fn __aeabi_fcmpun(a: f32, b: f32) -> i32 {
+    ::float::cmp::__unordsf2(a, b)
+}
+
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub extern "aapcs" fn __aeabi_dcmpeq(a: f64, b: f64) -> i32 {
+    (::float::cmp::__eqdf2(a, b) == 0) as i32
+}
+
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub extern "aapcs" fn __aeabi_dcmplt(a: f64, b: f64) -> i32 {
+    (::float::cmp::__ltdf2(a, b) < 0) as i32
+}
+
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub extern "aapcs" fn __aeabi_dcmple(a: f64, b: f64) -> i32 {
+    (::float::cmp::__ledf2(a, b) <= 0) as i32
+}
+
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub extern "aapcs" fn __aeabi_dcmpge(a: f64, b: f64) -> i32 {
+    (::float::cmp::__gedf2(a, b) >= 0) as i32
+}
+
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub extern "aapcs" fn __aeabi_dcmpgt(a: f64, b: f64) -> i32 {
+    (::float::cmp::__gtdf2(a, b) > 0) as i32
+}
+
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub extern "aapcs" fn __aeabi_dcmpun(a: f64, b: f64) -> i32 {
+    ::float::cmp::__unorddf2(a, b)
+}
+
+// NOTE The `__aeabi_c*cmp*` helpers return their result in the Z and C flags, as if a `cmp`
+// instruction had been used on the operands, and must preserve all the other registers:
+//
+// - less:               Z = 0, C = 0
+// - equal:              Z = 1, C = 1
+// - greater, unordered: Z = 0, C = 1
+//
+// The `__le*f2` (and, for the reversed variants, `__ge*f2`) result is mapped to 0, 1 and 2 for the
+// three cases above, which are then compared with 1 to set the flags. r4 is used to save ip across
+// the call, and pushing six registers keeps the stack 8-byte aligned.
+//
+// The ordered comparisons raise the invalid exception for NaN operands, the quiet `*eq` variants
+// only for signaling NaNs. Like in compiler-rt, they first call `__aeabi_c*cmpeq_check_nan`, and
+// only compare the operands, which are reloaded from the stack, if neither is NaN. Otherwise its
+// result of 1 is mapped to unordered like the one of `__le*f2`.
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub extern "aapcs" fn __aeabi_cfcmpeq_check_nan(a: f32, b: f32) -> i32 {
+    ::float::cmp::__unordsf2(a, b)
+}
+
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub extern "aapcs" fn __aeabi_cdcmpeq_check_nan(a: f64, b: f64) -> i32 {
+    ::float::cmp::__unorddf2(a, b)
+}
+
+#[naked]
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub unsafe fn __aeabi_cfcmpeq() {
+    asm!("push {r0, r1, r2, r3, r4, lr}
+          mov r4, ip
+          bl __aeabi_cfcmpeq_check_nan
+          cmp r0, #0
+          bne 1f
+          ldr r0, [sp]
+          ldr r1, [sp, #4]
+          bl __lesf2
+          1:
+          mov ip, r4
+          adds r0, r0, #1
+          cmp r0, #1
+          pop {r0, r1, r2, r3, r4, pc}");
+    intrinsics::unreachable();
+}
+
+#[naked]
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub unsafe fn __aeabi_cfcmple() {
+    asm!("push {r0, r1, r2, r3, r4, lr}
+          mov r4, ip
+          bl __lesf2
+          mov ip, r4
+          adds r0, r0, #1
+          cmp r0, #1
+          pop {r0, r1, r2, r3, r4, pc}");
+    intrinsics::unreachable();
+}
+
+#[naked]
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub unsafe fn __aeabi_cfrcmple() {
+    asm!("push {r0, r1, r2, r3, r4, lr}
+          mov r4, ip
+          bl __gesf2
+          mov ip, r4
+          negs r0, r0
+          adds r0, r0, #1
+          cmp r0, #1
+          pop {r0, r1, r2, r3, r4, pc}");
+    intrinsics::unreachable();
+}
+
+#[naked]
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub unsafe fn __aeabi_cdcmpeq() {
+    asm!("push {r0, r1, r2, r3, r4, lr}
+          mov r4, ip
+          bl __aeabi_cdcmpeq_check_nan
+          cmp r0, #0
+          bne 1f
+          ldr r0, [sp]
+          ldr r1, [sp, #4]
+          ldr r2, [sp, #8]
+          ldr r3, [sp, #12]
+          bl __ledf2
+          1:
+          mov ip, r4
+          adds r0, r0, #1
+          cmp r0, #1
+          pop {r0, r1, r2, r3, r4, pc}");
+    intrinsics::unreachable();
+}
+
+#[naked]
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub unsafe fn __aeabi_cdcmple() {
+    asm!("push {r0, r1, r2, r3, r4, lr}
+          mov r4, ip
+          bl __ledf2
+          mov ip, r4
+          adds r0, r0, #1
+          cmp r0, #1
+          pop {r0, r1, r2, r3, r4, pc}");
+    intrinsics::unreachable();
+}
+
+#[naked]
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub unsafe fn __aeabi_cdrcmple() {
+    asm!("push {r0, r1, r2, r3, r4, lr}
+          mov r4, ip
+          bl __gedf2
+          mov ip, r4
+          negs r0, r0
+          adds r0, r0, #1
+          cmp r0, #1
+          pop {r0, r1, r2, r3, r4, pc}");
+    intrinsics::unreachable();
+}
+
 // FIXME: The `*4` and `*8` variants should be defined as aliases.
 
 #[cfg(not(target_os = "ios"))]
@@ -1,5 +1,7 @@
 use int::{Int, CastInto};
-use float::Float;
+use float::{Float, f128, flush_denormal, is_signaling_nan, propagate_nan};
+use float::fenv::{RoundingMode, get_flush_to_zero, get_rounding_mode, raise_except};
+use float::fenv::{INEXACT, INVALID, OVERFLOW, UNDERFLOW};
 
 /// Returns `a + b`
 fn add<F: Float>(a: F, b: F) -> F where
@@ -24,6 +26,10 @@ fn add<F: Float>(a: F, b: F) -> F where
     let quiet_bit =        implicit_bit >> 1;
     let qnan_rep =         exponent_mask | quiet_bit;
 
+    let mode = get_rounding_mode();
+    let a = flush_denormal(a);
+    let b = flush_denormal(b);
+
     let mut a_rep = a.repr();
     let mut b_rep = b.repr();
     let a_abs = a_rep & abs_mask;
@@ -32,18 +38,23 @@ fn add<F: Float>(a: F, b: F) -> F where
     // Detect if a or b is zero, infinity, or NaN.
     if a_abs.wrapping_sub(one) >= inf_rep - one ||
         b_abs.wrapping_sub(one) >= inf_rep - one {
+        if is_signaling_nan(a) || is_signaling_nan(b) {
+            raise_except(INVALID);
+        }
+
         // NaN + anything = qNaN
         if a_abs > inf_rep {
-            return F::from_repr(a_abs | quiet_bit);
+            return propagate_nan(a);
         }
         // anything + NaN = qNaN
         if b_abs > inf_rep {
-            return F::from_repr(b_abs | quiet_bit);
+            return propagate_nan(b);
         }
 
         if a_abs == inf_rep {
             // +/-infinity + -/+infinity = qNaN
             if (a.repr() ^ b.repr()) == sign_bit {
+                raise_except(INVALID);
                 return F::from_repr(qnan_rep);
             } else {
                 // +/-infinity + anything remaining = +/- infinity
@@ -58,8 +69,12 @@ fn add<F: Float>(a: F, b: F) -> F where
 
         // zero + anything = anything
         if a_abs == Int::ZERO {
-            // but we need to get the sign right for zero + zero
+            // but we need to get the sign right for zero + zero, which is
+            // only negative if both are, or if either is when rounding down
             if b_abs == Int::ZERO {
+                if mode == RoundingMode::Downward {
+                    return F::from_repr(a.repr() | b.repr());
+                }
                 return F::from_repr(a.repr() & b.repr());
             } else {
                 return b;
@@ -123,8 +138,11 @@ fn add<F: Float>(a: F, b: F) -> F where
     }
     if subtraction {
         a_significand = a_significand.wrapping_sub(b_significand);
-        // If a == -b, return +zero.
+        // If a == -b, return +zero, or -zero when rounding down.
         if a_significand == Int::ZERO {
+            if mode == RoundingMode::Downward {
+                return F::from_repr(sign_bit);
+            }
             return F::from_repr(Int::ZERO);
         }
 
@@ -148,12 +166,29 @@ fn add<F: Float>(a: F, b: F) -> F where
         }
     }
 
-    // If we have overflowed the type, return +/- infinity:
+    // If we have overflowed the type, return +/- infinity, or the largest
+    // finite number when rounding towards zero:
     if a_exponent >= max_exponent as i32 {
+        raise_except(OVERFLOW | INEXACT);
+        let toward_zero = match mode {
+            RoundingMode::ToNearest => false,
+            RoundingMode::Downward => result_sign == zero,
+            RoundingMode::Upward => result_sign != zero,
+            RoundingMode::TowardZero => true,
+        };
+        if toward_zero {
+            return F::from_repr((inf_rep - one) | result_sign);
+        }
         return F::from_repr(inf_rep | result_sign);
     }
 
     if a_exponent <= 0 {
+        // Denormal results are returned as zero in flush-to-zero mode
+        if get_flush_to_zero() {
+            raise_except(UNDERFLOW);
+            return F::from_repr(result_sign);
+        }
+
         // Result is denormal before rounding; the exponent is zero and we
         // need to shift the significand.
         let shift = (1 - a_exponent).cast();
@@ -175,8 +210,26 @@ fn add<F: Float>(a: F, b: F) -> F where
 
     // Final rounding.  The result may overflow to infinity, but that is the
     // correct result in that case.
-    if round_guard_sticky > 0x4 { result += one; }
-    if round_guard_sticky == 0x4 { result += result & one; }
+    match mode {
+        RoundingMode::ToNearest => {
+            if round_guard_sticky > 0x4 { result += one; }
+            if round_guard_sticky == 0x4 { result += result & one; }
+        }
+        RoundingMode::Downward => {
+            if round_guard_sticky != 0 && result_sign != zero { result += one; }
+        }
+        RoundingMode::Upward => {
+            if round_guard_sticky != 0 && result_sign == zero { result += one; }
+        }
+        RoundingMode::TowardZero => {}
+    }
+    if round_guard_sticky != 0 {
+        raise_except(INEXACT);
+        // The rounding may have carried up to infinity
+        if result & abs_mask == inf_rep {
+            raise_except(OVERFLOW);
+        }
+    }
 
     F::from_repr(result)
 }
@@ -193,4 +246,8 @@ intrinsics! {
     pub extern "C" fn __adddf3(a: f64, b: f64) -> f64 {
         add(a, b)
     }
+
+    pub extern "C" fn __addtf3(a: f128, b: f128) -> f128 {
+        add(a, b)
+    }
 }
@@ -0,0 +1,28 @@
+use float::extend::__extendbfsf2;
+use float::trunc::__truncsfbf2;
+
+/// bfloat16 floating point number, stored as its bit pattern
+///
+/// This is the top half of an `f32`: it has the same 8-bit exponent, but only
+/// 7 significand bits. Like with `f16`, arithmetic is carried out in single
+/// precision and rounded back.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy)]
+pub struct bf16(pub u16);
+
+impl bf16 {
+    /// Returns `true` if this value is NaN
+    pub fn is_nan(self) -> bool {
+        self.0 & 0x7fff > 0x7f80
+    }
+
+    fn to_f32(self) -> f32 {
+        __extendbfsf2(self.0)
+    }
+
+    fn from_f32(a: f32) -> bf16 {
+        bf16(__truncsfbf2(a))
+    }
+}
+
+forward_to_f32!(bf16, bf16::from_f32);
@@ -0,0 +1,215 @@
+use int::Int;
+use float::{Float, f128, is_signaling_nan};
+use float::fenv::{raise_except, INVALID};
+
+#[derive(Clone, Copy)]
+enum Result {
+    Less,
+    Equal,
+    Greater,
+    Unordered,
+}
+
+impl Result {
+    /// Result encoding of the `__le*f2` family, unordered compares greater
+    fn to_le_abi(self) -> i32 {
+        match self {
+            Result::Less => -1,
+            Result::Equal => 0,
+            Result::Greater => 1,
+            Result::Unordered => 1,
+        }
+    }
+
+    /// Result encoding of the `__ge*f2` family, unordered compares less
+    fn to_ge_abi(self) -> i32 {
+        match self {
+            Result::Less => -1,
+            Result::Equal => 0,
+            Result::Greater => 1,
+            Result::Unordered => -1,
+        }
+    }
+}
+
+/// Three-way comparison of `a` and `b`
+///
+/// This is a quiet comparison, it only raises the invalid exception if `a` or
+/// `b` is a signaling NaN.
+fn cmp<F: Float>(a: F, b: F) -> Result {
+    let one = F::Int::ONE;
+    let zero = F::Int::ZERO;
+
+    let sign_bit = F::SIGN_MASK;
+    let abs_mask = sign_bit - one;
+    let inf_rep = F::EXPONENT_MASK;
+
+    let a_rep = a.repr();
+    let b_rep = b.repr();
+    let a_abs = a_rep & abs_mask;
+    let b_abs = b_rep & abs_mask;
+
+    // If either a or b is NaN, they are unordered.
+    if a_abs > inf_rep || b_abs > inf_rep {
+        if is_signaling_nan(a) || is_signaling_nan(b) {
+            raise_except(INVALID);
+        }
+        return Result::Unordered;
+    }
+
+    // If a and b are both zeros, they are equal, regardless of their signs.
+    if a_abs | b_abs == zero {
+        return Result::Equal;
+    }
+
+    let a_negative = a_rep & sign_bit != zero;
+    let b_negative = b_rep & sign_bit != zero;
+
+    // If the signs differ, the negative one is the smaller one.
+    if a_negative != b_negative {
+        return if a_negative { Result::Less } else { Result::Greater };
+    }
+
+    // Otherwise the magnitudes decide, in the opposite sense if both are
+    // negative.
+    if a_abs == b_abs {
+        Result::Equal
+    } else if (a_abs < b_abs) != a_negative {
+        Result::Less
+    } else {
+        Result::Greater
+    }
+}
+
+/// Like `cmp`, but a signaling comparison, which raises the invalid exception
+/// if `a` and `b` are unordered
+fn signaling_cmp<F: Float>(a: F, b: F) -> Result {
+    let result = cmp(a, b);
+    if let Result::Unordered = result {
+        raise_except(INVALID);
+    }
+    result
+}
+
+/// Returns `true` if either `a` or `b` is NaN, raises the invalid exception if
+/// either is a signaling NaN
+fn unord<F: Float>(a: F, b: F) -> bool {
+    let one = F::Int::ONE;
+
+    let sign_bit = F::SIGN_MASK;
+    let abs_mask = sign_bit - one;
+    let inf_rep = F::EXPONENT_MASK;
+
+    let a_abs = a.repr() & abs_mask;
+    let b_abs = b.repr() & abs_mask;
+
+    if is_signaling_nan(a) || is_signaling_nan(b) {
+        raise_except(INVALID);
+    }
+    a_abs > inf_rep || b_abs > inf_rep
+}
+
+// NOTE The result of these comparisons is only specified through its relation
+// to zero, e.g. `__ltsf2(a, b) < 0` iff `a < b`. Several intrinsics therefore
+// share an implementation, which also mirrors what compiler-rt does. The
+// `__eq*f2`, `__ne*f2` and `__unord*f2` comparisons are quiet, the others are
+// signaling.
+intrinsics! {
+    #[aapcs_on_arm]
+    pub extern "C" fn __lesf2(a: f32, b: f32) -> i32 {
+        signaling_cmp(a, b).to_le_abi()
+    }
+
+    #[aapcs_on_arm]
+    pub extern "C" fn __gesf2(a: f32, b: f32) -> i32 {
+        signaling_cmp(a, b).to_ge_abi()
+    }
+
+    #[aapcs_on_arm]
+    pub extern "C" fn __unordsf2(a: f32, b: f32) -> i32 {
+        unord(a, b) as i32
+    }
+
+    #[aapcs_on_arm]
+    pub extern "C" fn __eqsf2(a: f32, b: f32) -> i32 {
+        cmp(a, b).to_le_abi()
+    }
+
+    #[aapcs_on_arm]
+    pub extern "C" fn __ltsf2(a: f32, b: f32) -> i32 {
+        signaling_cmp(a, b).to_le_abi()
+    }
+
+    #[aapcs_on_arm]
+    pub extern "C" fn __nesf2(a: f32, b: f32) -> i32 {
+        cmp(a, b).to_le_abi()
+    }
+
+    #[aapcs_on_arm]
+    pub extern "C" fn __gtsf2(a: f32, b: f32) -> i32 {
+        signaling_cmp(a, b).to_ge_abi()
+    }
+
+    #[aapcs_on_arm]
+    pub extern "C" fn __ledf2(a: f64, b: f64) -> i32 {
+        signaling_cmp(a, b).to_le_abi()
+    }
+
+    #[aapcs_on_arm]
+    pub extern "C" fn __gedf2(a: f64, b: f64) -> i32 {
+        signaling_cmp(a, b).to_ge_abi()
+    }
+
+    #[aapcs_on_arm]
+    pub extern "C" fn __unorddf2(a: f64, b: f64) -> i32 {
+        unord(a, b) as i32
+    }
+
+    #[aapcs_on_arm]
+    pub extern "C" fn __eqdf2(a: f64, b: f64) -> i32 {
+        cmp(a, b).to_le_abi()
+    }
+
+    #[aapcs_on_arm]
+    pub extern "C" fn __ltdf2(a: f64, b: f64) -> i32 {
+        signaling_cmp(a, b).to_le_abi()
+    }
+
+    #[aapcs_on_arm]
+    pub extern "C" fn __nedf2(a: f64, b: f64) -> i32 {
+        cmp(a, b).to_le_abi()
+    }
+
+    #[aapcs_on_arm]
+    pub extern "C" fn __gtdf2(a: f64, b: f64) -> i32 {
+        signaling_cmp(a, b).to_ge_abi()
+    }
+
+    pub extern "C" fn __letf2(a: f128, b: f128) -> i32 {
+        signaling_cmp(a, b).to_le_abi()
+    }
+
+    pub extern "C" fn __getf2(a: f128, b: f128) -> i32 {
+        signaling_cmp(a, b).to_ge_abi()
+    }
+
+    pub extern "C" fn __unordtf2(a: f128, b: f128) -> i32 {
+        unord(a, b) as i32
+    }
+
+    pub extern "C" fn __eqtf2(a: f128, b: f128) -> i32 {
+        cmp(a, b).to_le_abi()
+    }
+
+    pub extern "C" fn __lttf2(a: f128, b: f128) -> i32 {
+        signaling_cmp(a, b).to_le_abi()
+    }
+
+    pub extern "C" fn __netf2(a: f128, b: f128) -> i32 {
+        cmp(a, b).to_le_abi()
+    }
+
+    pub extern "C" fn __gttf2(a: f128, b: f128) -> i32 {
+        signaling_cmp(a, b).to_ge_abi()
+    }
+}
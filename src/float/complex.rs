@@ -0,0 +1,245 @@
+use int::{Int, CastInto};
+use float::Float;
+
+/// A C99 complex number
+///
+/// `_Complex float` and `_Complex double` have the layout of this struct and,
+/// except for `_Complex float` results on x86, are passed like it.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Complex<F> {
+    pub re: F,
+    pub im: F,
+}
+
+fn is_nan<F: Float>(x: F) -> bool {
+    x.repr() & !F::SIGN_MASK > F::EXPONENT_MASK
+}
+
+fn is_infinite<F: Float>(x: F) -> bool {
+    x.repr() & !F::SIGN_MASK == F::EXPONENT_MASK
+}
+
+fn is_finite<F: Float>(x: F) -> bool {
+    x.repr() & !F::SIGN_MASK < F::EXPONENT_MASK
+}
+
+fn infinity<F: Float>() -> F {
+    F::from_repr(F::EXPONENT_MASK)
+}
+
+fn abs<F: Float>(x: F) -> F {
+    F::from_repr(x.repr() & !F::SIGN_MASK)
+}
+
+fn copysign<F: Float>(x: F, y: F) -> F {
+    F::from_repr(x.repr() & !F::SIGN_MASK | y.repr() & F::SIGN_MASK)
+}
+
+/// Like C's `fmax`, returns the larger argument, or the other one if one of
+/// them is NaN
+fn fmax<F: Float>(x: F, y: F) -> F {
+    if is_nan(x) || !is_nan(y) && y > x { y } else { x }
+}
+
+/// Turns an infinity into 1 and anything else into 0, keeping the sign
+fn box_infinity<F: Float>(x: F) -> F {
+    copysign(if is_infinite(x) { F::ONE } else { F::ZERO }, x)
+}
+
+/// Turns a NaN into 0, keeping the sign
+fn zero_if_nan<F: Float>(x: F) -> F {
+    if is_nan(x) { copysign(F::ZERO, x) } else { x }
+}
+
+/// Like C's `logb`, returns the exponent of `x` as if it was normalized.
+/// Returns `None` for zeros, infinities and NaNs, where `logb` isn't finite.
+fn logb<F: Float>(x: F) -> Option<i32> where
+    F::Int: CastInto<i32>,
+{
+    let abs = x.repr() & !F::SIGN_MASK;
+    if abs == F::Int::ZERO || abs >= F::EXPONENT_MASK {
+        return None;
+    }
+
+    let exponent: i32 = (abs >> F::SIGNIFICAND_BITS).cast();
+    let exponent = if exponent == 0 {
+        F::normalize(abs).0
+    } else {
+        exponent
+    };
+    Some(exponent - F::EXPONENT_BIAS as i32)
+}
+
+/// `2^n`, for `n` in the range of the exponents of normal numbers
+fn exp2<F: Float>(n: i32) -> F where
+    u32: CastInto<F::Int>,
+{
+    F::from_repr(((n + F::EXPONENT_BIAS as i32) as u32).cast() << F::SIGNIFICAND_BITS)
+}
+
+/// Like C's `scalbn`, returns `x * 2^n`, rounded once
+fn scalbn<F: Float>(mut x: F, mut n: i32) -> F where
+    u32: CastInto<F::Int>,
+{
+    let max = F::EXPONENT_BIAS as i32;
+    let min = 1 - max;
+    if n > max {
+        x = x * exp2(max);
+        n -= max;
+        if n > max {
+            x = x * exp2(max);
+            n -= max;
+            if n > max {
+                n = max;
+            }
+        }
+    } else if n < min {
+        // Keep the intermediate results normal, so that only the last
+        // multiplication can round the significand
+        let step = min + F::SIGNIFICAND_BITS as i32 + 1;
+        x = x * exp2(step);
+        n -= step;
+        if n < min {
+            x = x * exp2(step);
+            n -= step;
+            if n < min {
+                n = min;
+            }
+        }
+    }
+    x * exp2(n)
+}
+
+/// Returns `(a + ib) * (c + id)`
+///
+/// When the naive result is NaN + iNaN, this recovers the infinities as
+/// described in Annex G of the C99 standard.
+fn mul<F: Float>(mut a: F, mut b: F, mut c: F, mut d: F) -> Complex<F> {
+    let ac = a * c;
+    let bd = b * d;
+    let ad = a * d;
+    let bc = b * c;
+    let mut z = Complex {
+        re: ac - bd,
+        im: ad + bc,
+    };
+
+    if is_nan(z.re) && is_nan(z.im) {
+        let mut recalc = false;
+        if is_infinite(a) || is_infinite(b) {
+            // The first factor is infinite
+            a = box_infinity(a);
+            b = box_infinity(b);
+            c = zero_if_nan(c);
+            d = zero_if_nan(d);
+            recalc = true;
+        }
+        if is_infinite(c) || is_infinite(d) {
+            // The second factor is infinite
+            c = box_infinity(c);
+            d = box_infinity(d);
+            a = zero_if_nan(a);
+            b = zero_if_nan(b);
+            recalc = true;
+        }
+        if !recalc &&
+            (is_infinite(ac) || is_infinite(bd) || is_infinite(ad) || is_infinite(bc)) {
+            // One of the products overflowed
+            a = zero_if_nan(a);
+            b = zero_if_nan(b);
+            c = zero_if_nan(c);
+            d = zero_if_nan(d);
+            recalc = true;
+        }
+        if recalc {
+            z.re = infinity::<F>() * (a * c - b * d);
+            z.im = infinity::<F>() * (a * d + b * c);
+        }
+    }
+    z
+}
+
+/// Returns `(a + ib) / (c + id)`
+///
+/// The divisor is scaled by a power of two to avoid overflow and underflow.
+/// When the result is NaN + iNaN, this recovers infinities and zeros as
+/// described in Annex G of the C99 standard.
+fn div<F: Float>(mut a: F, mut b: F, mut c: F, mut d: F) -> Complex<F> where
+    F::Int: CastInto<i32>,
+    u32: CastInto<F::Int>,
+{
+    let w = fmax(abs(c), abs(d));
+    let mut ilogbw = 0;
+    if let Some(logbw) = logb(w) {
+        ilogbw = logbw;
+        c = scalbn(c, -ilogbw);
+        d = scalbn(d, -ilogbw);
+    }
+    let denom = c * c + d * d;
+    let mut z = Complex {
+        re: scalbn((a * c + b * d) / denom, -ilogbw),
+        im: scalbn((b * c - a * d) / denom, -ilogbw),
+    };
+
+    if is_nan(z.re) && is_nan(z.im) {
+        if denom == F::ZERO && (!is_nan(a) || !is_nan(b)) {
+            // Division by zero
+            z.re = copysign(infinity(), c) * a;
+            z.im = copysign(infinity(), c) * b;
+        } else if (is_infinite(a) || is_infinite(b)) && is_finite(c) && is_finite(d) {
+            // Infinite dividend
+            a = box_infinity(a);
+            b = box_infinity(b);
+            z.re = infinity::<F>() * (a * c + b * d);
+            z.im = infinity::<F>() * (b * c - a * d);
+        } else if is_infinite(w) && is_finite(a) && is_finite(b) {
+            // Infinite divisor
+            c = box_infinity(c);
+            d = box_infinity(d);
+            z.re = F::ZERO * (a * c + b * d);
+            z.im = F::ZERO * (b * c - a * d);
+        }
+    }
+    z
+}
+
+/// Packs the parts of a `_Complex float` into the `u64` that holds them in
+/// `edx:eax` on x86
+#[cfg(target_arch = "x86")]
+fn pack(z: Complex<f32>) -> u64 {
+    (z.im.repr() as u64) << 32 | z.re.repr() as u64
+}
+
+intrinsics! {
+    #[cfg(not(target_arch = "x86"))]
+    pub extern "C" fn __mulsc3(a: f32, b: f32, c: f32, d: f32) -> Complex<f32> {
+        mul(a, b, c, d)
+    }
+
+    pub extern "C" fn __muldc3(a: f64, b: f64, c: f64, d: f64) -> Complex<f64> {
+        mul(a, b, c, d)
+    }
+
+    #[cfg(not(target_arch = "x86"))]
+    pub extern "C" fn __divsc3(a: f32, b: f32, c: f32, d: f32) -> Complex<f32> {
+        div(a, b, c, d)
+    }
+
+    pub extern "C" fn __divdc3(a: f64, b: f64, c: f64, d: f64) -> Complex<f64> {
+        div(a, b, c, d)
+    }
+}
+
+// i386 returns `_Complex float` in registers, but structs in memory, so the
+// `f32` versions return a packed `u64` there
+#[cfg(target_arch = "x86")]
+intrinsics! {
+    pub extern "C" fn __mulsc3(a: f32, b: f32, c: f32, d: f32) -> u64 {
+        pack(mul(a, b, c, d))
+    }
+
+    pub extern "C" fn __divsc3(a: f32, b: f32, c: f32, d: f32) -> u64 {
+        pack(div(a, b, c, d))
+    }
+}
@@ -1,4 +1,7 @@
-use float::Float;
+use float::{Float, f128, flush_denormal};
+#[cfg(all(target_arch = "x86_64", not(any(windows, target_os = "macos", target_os = "ios"))))]
+use float::f80;
+use float::fenv::{RoundingMode, get_rounding_mode, raise_except, INEXACT, INVALID, OVERFLOW};
 use int::{Int, CastInto};
 
 fn int_to_float<I: Int, F: Float>(i: I) -> F where
@@ -12,6 +15,7 @@ fn int_to_float<I: Int, F: Float>(i: I) -> F where
     }
 
     let two = I::UnsignedInt::ONE + I::UnsignedInt::ONE;
+    let three = two + I::UnsignedInt::ONE;
     let four = two + two;
     let mant_dig = F::SIGNIFICAND_BITS + 1;
     let exponent_bias = F::EXPONENT_BIAS;
@@ -53,14 +57,29 @@ fn int_to_float<I: Int, F: Float>(i: I) -> F where
         };
 
         /* finish: */
-        a |= Int::from_bool((a & four) != I::UnsignedInt::ZERO); /* Or P into R */
-        a += Int::ONE; /* round - this step may add a significant bit */
+        if (a & three) != I::UnsignedInt::ZERO {
+            raise_except(INEXACT);
+        }
+        match get_rounding_mode() {
+            RoundingMode::ToNearest => {
+                a |= Int::from_bool((a & four) != I::UnsignedInt::ZERO); /* Or P into R */
+                a += Int::ONE; /* round - this step may add a significant bit */
+            }
+            /* round away from zero if Q or R is set */
+            RoundingMode::Downward => if s { a += three },
+            RoundingMode::Upward => if !s { a += three },
+            RoundingMode::TowardZero => {}
+        }
         a >>= 2; /* dump Q and R */
 
         /* a is now rounded to mant_dig or mant_dig+1 bits */
         if (a & (I::UnsignedInt::ONE << mant_dig)) != Int::ZERO {
             a >>= 1; e += 1;
         }
+        /* only u128 to f32 can round up to infinity */
+        if e + exponent_bias >= F::EXPONENT_MAX {
+            raise_except(OVERFLOW);
+        }
         a
         /* a is now rounded to mant_dig bits */
     } else {
@@ -88,14 +107,24 @@ intrinsics! {
     #[arm_aeabi_alias = __aeabi_l2d]
     pub extern "C" fn __floatdidf(i: i64) -> f64 {
         // On x86_64 LLVM will use native instructions for this conversion, we
-        // can just do it directly
-        if cfg!(target_arch = "x86_64") {
+        // can just do it directly, unless the conversion has to round in
+        // another mode or raise the inexact exception
+        if cfg!(target_arch = "x86_64") && !cfg!(feature = "fp-exceptions") &&
+            get_rounding_mode() == RoundingMode::ToNearest {
             i as f64
         } else {
             int_to_float(i)
         }
     }
 
+    #[use_c_shim_if(any(all(target_arch = "x86", not(target_env = "msvc")),
+                        all(target_arch = "x86_64",
+                            any(target_env = "msvc", not(windows)))))]
+    #[arm_aeabi_alias = __aeabi_l2f]
+    pub extern "C" fn __floatdisf(i: i64) -> f32 {
+        int_to_float(i)
+    }
+
     #[unadjusted_on_win64]
     pub extern "C" fn __floattisf(i: i128) -> f32 {
         int_to_float(i)
@@ -124,6 +153,14 @@ intrinsics! {
         int_to_float(i)
     }
 
+    #[use_c_shim_if(all(not(target_env = "msvc"),
+                        any(target_arch = "x86",
+                            all(not(windows), target_arch = "x86_64"))))]
+    #[arm_aeabi_alias = __aeabi_ul2f]
+    pub extern "C" fn __floatundisf(i: u64) -> f32 {
+        int_to_float(i)
+    }
+
     #[unadjusted_on_win64]
     pub extern "C" fn __floatuntisf(i: u128) -> f32 {
         int_to_float(i)
@@ -133,6 +170,32 @@ intrinsics! {
     pub extern "C" fn __floatuntidf(i: u128) -> f64 {
         int_to_float(i)
     }
+
+    pub extern "C" fn __floatsitf(i: i32) -> f128 {
+        int_to_float(i)
+    }
+
+    pub extern "C" fn __floatditf(i: i64) -> f128 {
+        int_to_float(i)
+    }
+
+    #[unadjusted_on_win64]
+    pub extern "C" fn __floattitf(i: i128) -> f128 {
+        int_to_float(i)
+    }
+
+    pub extern "C" fn __floatunsitf(i: u32) -> f128 {
+        int_to_float(i)
+    }
+
+    pub extern "C" fn __floatunditf(i: u64) -> f128 {
+        int_to_float(i)
+    }
+
+    #[unadjusted_on_win64]
+    pub extern "C" fn __floatuntitf(i: u128) -> f128 {
+        int_to_float(i)
+    }
 }
 
 #[derive(PartialEq)]
@@ -145,7 +208,7 @@ fn float_to_int<F: Float, I: Int>(f: F) -> I where
     F::Int: CastInto<u32>,
     F::Int: CastInto<I>,
 {
-    let f = f;
+    let f = flush_denormal(f);
     let fixint_min = I::min_value();
     let fixint_max = I::max_value();
     let fixint_bits = I::BITS;
@@ -168,22 +231,48 @@ fn float_to_int<F: Float, I: Int>(f: F) -> I where
     // if < 1 or unsigned & negative
     if exponent < exponent_bias ||
         fixint_unsigned && sign == Sign::Negative {
+        if exponent >= exponent_bias {
+            // <= -1 is out of range for unsigned
+            raise_except(INVALID);
+        } else if a_abs != F::Int::ZERO {
+            raise_except(INEXACT);
+        }
         return I::ZERO;
     }
     exponent -= exponent_bias;
 
-    // If the value is infinity, saturate.
-    // If the value is too large for the integer type, 0.
+    // The bits of the significand below the binary point
+    let fraction_bits = if exponent < significand_bits { significand_bits - exponent } else { 0 };
+    let inexact = significand & ((F::Int::ONE << fraction_bits) - F::Int::ONE) != F::Int::ZERO;
+
+    // If the value is infinity or too large for the integer type, saturate to
+    // the maximum or minimum of the type and raise the invalid exception.
     if exponent >= (if fixint_unsigned {fixint_bits} else {fixint_bits -1}) {
+        // Only the minimum of a signed type is in range, it has no bits
+        // other than the implicit one above the binary point. It's converted
+        // exactly, without raising invalid.
+        if sign == Sign::Negative && exponent == fixint_bits - 1 &&
+            significand >> fraction_bits == F::IMPLICIT_BIT >> fraction_bits {
+            if inexact {
+                raise_except(INEXACT);
+            }
+        } else {
+            raise_except(INVALID);
+        }
         return if sign == Sign::Positive {fixint_max} else {fixint_min}
     }
+    if inexact {
+        raise_except(INEXACT);
+    }
     // If 0 <= exponent < significand_bits, right shift to get the result.
     // Otherwise, shift left.
     // (sign - 1) will never overflow as negative signs are already returned as 0 for unsigned
     let r: I = if exponent < significand_bits {
         (significand >> (significand_bits - exponent)).cast()
     } else {
-        (significand << (exponent - significand_bits)).cast()
+        // Widen first, the value doesn't fit in `F::Int` for some conversions
+        let r: I = significand.cast();
+        r << (exponent - significand_bits)
     };
 
     if sign == Sign::Negative {
@@ -193,6 +282,98 @@ fn float_to_int<F: Float, I: Int>(f: F) -> I where
     }
 }
 
+/// Like `float_to_int`, but NaN converts to 0, which is the semantics of
+/// Rust's `as` casts
+fn float_to_int_sat<F: Float, I: Int>(f: F) -> I where
+    F::Int: CastInto<u32>,
+    F::Int: CastInto<I>,
+{
+    if f.repr() & !F::SIGN_MASK > F::EXPONENT_MASK {
+        raise_except(INVALID);
+        return I::ZERO;
+    }
+    float_to_int(f)
+}
+
+/// How `float_to_int_round` rounds a value that isn't an integer
+#[derive(Clone, Copy)]
+enum IntRounding {
+    /// To nearest, ties away from zero, like C's `lround`
+    HalfAway,
+    /// To nearest, ties to even
+    NearestEven,
+    /// In the current rounding mode, like C's `lrint`
+    Current,
+}
+
+/// Returns `f` rounded to an integer. Like with `float_to_int`, values that
+/// are out of range raise invalid and saturate.
+///
+/// The value is rounded to an integral float, which is exact, and that one is
+/// converted with `float_to_int`.
+fn float_to_int_round<F: Float, I: Int>(f: F, rounding: IntRounding) -> I where
+    F::Int: CastInto<u32>,
+    F::Int: CastInto<I>,
+    u32: CastInto<F::Int>,
+{
+    let zero = F::Int::ZERO;
+    let one = F::Int::ONE;
+    let significand_bits = F::SIGNIFICAND_BITS;
+    let exponent_bias = F::EXPONENT_BIAS;
+
+    let f = flush_denormal(f);
+    let a_rep = f.repr();
+    let sign_bit = a_rep & F::SIGN_MASK;
+    let negative = sign_bit != zero;
+    let a_abs = a_rep & !F::SIGN_MASK;
+    let exponent: u32 = (a_abs >> significand_bits).cast();
+
+    // Zeros, integers too large to have a fraction, infinities and NaNs
+    // convert like they do when truncating
+    if a_abs == zero || exponent >= exponent_bias + significand_bits {
+        return float_to_int(f);
+    }
+
+    // Split the magnitude into its integer part and how the fraction compares
+    // to one half
+    let (integral, above_half, exactly_half) = if exponent < exponent_bias - 1 {
+        // 0 < |f| < 0.5
+        (zero, false, false)
+    } else {
+        let significand = (a_abs & F::SIGNIFICAND_MASK) | F::IMPLICIT_BIT;
+        let fraction_bits = exponent_bias + significand_bits - exponent;
+        let fraction = significand & ((one << fraction_bits) - one);
+        if fraction == zero {
+            return float_to_int(f);
+        }
+        let half = one << (fraction_bits - 1);
+        (significand >> fraction_bits, fraction > half, fraction == half)
+    };
+    raise_except(INEXACT);
+
+    let nearest_even = above_half || exactly_half && integral & one != zero;
+    let round_up = match rounding {
+        IntRounding::HalfAway => above_half || exactly_half,
+        IntRounding::NearestEven => nearest_even,
+        IntRounding::Current => match get_rounding_mode() {
+            RoundingMode::ToNearest => nearest_even,
+            RoundingMode::Downward => negative,
+            RoundingMode::Upward => !negative,
+            RoundingMode::TowardZero => false,
+        },
+    };
+    let magnitude = if round_up { integral + one } else { integral };
+    if magnitude == zero {
+        return I::ZERO;
+    }
+
+    // The magnitude has at most `significand_bits + 1` bits, so it's exact
+    let shift = magnitude.leading_zeros() - (F::BITS - significand_bits - 1);
+    let integral_rep = ((exponent_bias + significand_bits - shift).cast() << significand_bits) +
+        ((magnitude << shift) & F::SIGNIFICAND_MASK);
+    float_to_int(F::from_repr(integral_rep | sign_bit))
+}
+
 intrinsics! {
     #[arm_aeabi_alias = __aeabi_f2iz]
     pub extern "C" fn __fixsfsi(f: f32) -> i32 {
@@ -253,4 +434,293 @@ intrinsics! {
     pub extern "C" fn __fixunsdfti(f: f64) -> u128 {
         float_to_int(f)
     }
+
+    pub extern "C" fn __fixtfsi(f: f128) -> i32 {
+        float_to_int(f)
+    }
+
+    pub extern "C" fn __fixtfdi(f: f128) -> i64 {
+        float_to_int(f)
+    }
+
+    #[unadjusted_on_win64]
+    pub extern "C" fn __fixtfti(f: f128) -> i128 {
+        float_to_int(f)
+    }
+
+    pub extern "C" fn __fixunstfsi(f: f128) -> u32 {
+        float_to_int(f)
+    }
+
+    pub extern "C" fn __fixunstfdi(f: f128) -> u64 {
+        float_to_int(f)
+    }
+
+    #[unadjusted_on_win64]
+    pub extern "C" fn __fixunstfti(f: f128) -> u128 {
+        float_to_int(f)
+    }
+}
+
+// Saturating conversions for Rust's `as` casts. Unlike the `fix` intrinsics
+// above, NaN is guaranteed to convert to 0.
+intrinsics! {
+    pub extern "C" fn __rust_fixsfsi_sat(f: f32) -> i32 {
+        float_to_int_sat(f)
+    }
+
+    pub extern "C" fn __rust_fixsfdi_sat(f: f32) -> i64 {
+        float_to_int_sat(f)
+    }
+
+    #[unadjusted_on_win64]
+    pub extern "C" fn __rust_fixsfti_sat(f: f32) -> i128 {
+        float_to_int_sat(f)
+    }
+
+    pub extern "C" fn __rust_fixunssfsi_sat(f: f32) -> u32 {
+        float_to_int_sat(f)
+    }
+
+    pub extern "C" fn __rust_fixunssfdi_sat(f: f32) -> u64 {
+        float_to_int_sat(f)
+    }
+
+    #[unadjusted_on_win64]
+    pub extern "C" fn __rust_fixunssfti_sat(f: f32) -> u128 {
+        float_to_int_sat(f)
+    }
+
+    pub extern "C" fn __rust_fixdfsi_sat(f: f64) -> i32 {
+        float_to_int_sat(f)
+    }
+
+    pub extern "C" fn __rust_fixdfdi_sat(f: f64) -> i64 {
+        float_to_int_sat(f)
+    }
+
+    #[unadjusted_on_win64]
+    pub extern "C" fn __rust_fixdfti_sat(f: f64) -> i128 {
+        float_to_int_sat(f)
+    }
+
+    pub extern "C" fn __rust_fixunsdfsi_sat(f: f64) -> u32 {
+        float_to_int_sat(f)
+    }
+
+    pub extern "C" fn __rust_fixunsdfdi_sat(f: f64) -> u64 {
+        float_to_int_sat(f)
+    }
+
+    #[unadjusted_on_win64]
+    pub extern "C" fn __rust_fixunsdfti_sat(f: f64) -> u128 {
+        float_to_int_sat(f)
+    }
+
+    pub extern "C" fn __rust_fixtfsi_sat(f: f128) -> i32 {
+        float_to_int_sat(f)
+    }
+
+    pub extern "C" fn __rust_fixtfdi_sat(f: f128) -> i64 {
+        float_to_int_sat(f)
+    }
+
+    #[unadjusted_on_win64]
+    pub extern "C" fn __rust_fixtfti_sat(f: f128) -> i128 {
+        float_to_int_sat(f)
+    }
+
+    pub extern "C" fn __rust_fixunstfsi_sat(f: f128) -> u32 {
+        float_to_int_sat(f)
+    }
+
+    pub extern "C" fn __rust_fixunstfdi_sat(f: f128) -> u64 {
+        float_to_int_sat(f)
+    }
+
+    #[unadjusted_on_win64]
+    pub extern "C" fn __rust_fixunstfti_sat(f: f128) -> u128 {
+        float_to_int_sat(f)
+    }
+}
+
+// The `xf` conversions take or return an x87 `long double`, which can't be
+// passed to or returned from a Rust function. These versions pass the `f80`
+// by reference instead, the intrinsics themselves are shims in `x86_64.rs`
+// that call them. Like the shims, they only exist on the x86_64 ELF targets.
+
+#[cfg(all(target_arch = "x86_64", not(any(windows, target_os = "macos", target_os = "ios"))))]
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub extern "C" fn __rust_floatdixf(r: &mut f80, i: i64) {
+    *r = f80::from_int(i);
+}
+
+#[cfg(all(target_arch = "x86_64", not(any(windows, target_os = "macos", target_os = "ios"))))]
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub extern "C" fn __rust_floattixf(r: &mut f80, i: i128) {
+    *r = f80::from_int(i);
+}
+
+#[cfg(all(target_arch = "x86_64", not(any(windows, target_os = "macos", target_os = "ios"))))]
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub extern "C" fn __rust_floatundixf(r: &mut f80, i: u64) {
+    *r = f80::from_int(i);
+}
+
+#[cfg(all(target_arch = "x86_64", not(any(windows, target_os = "macos", target_os = "ios"))))]
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub extern "C" fn __rust_floatuntixf(r: &mut f80, i: u128) {
+    *r = f80::from_int(i);
+}
+
+#[cfg(all(target_arch = "x86_64", not(any(windows, target_os = "macos", target_os = "ios"))))]
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub extern "C" fn __rust_fixxfdi(a: &f80) -> i64 {
+    a.to_int()
+}
+
+#[cfg(all(target_arch = "x86_64", not(any(windows, target_os = "macos", target_os = "ios"))))]
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub extern "C" fn __rust_fixxfti(a: &f80) -> i128 {
+    a.to_int()
+}
+
+#[cfg(all(target_arch = "x86_64", not(any(windows, target_os = "macos", target_os = "ios"))))]
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub extern "C" fn __rust_fixunsxfsi(a: &f80) -> u32 {
+    a.to_int()
+}
+
+#[cfg(all(target_arch = "x86_64", not(any(windows, target_os = "macos", target_os = "ios"))))]
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub extern "C" fn __rust_fixunsxfdi(a: &f80) -> u64 {
+    a.to_int()
+}
+
+#[cfg(all(target_arch = "x86_64", not(any(windows, target_os = "macos", target_os = "ios"))))]
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub extern "C" fn __rust_fixunsxfti(a: &f80) -> u128 {
+    a.to_int()
+}
+
+/// Like `f80::to_int`, but NaN converts to 0, like `float_to_int_sat`
+#[cfg(all(target_arch = "x86_64", not(any(windows, target_os = "macos", target_os = "ios"))))]
+fn f80_to_int_sat<I: Int>(a: &f80) -> I where
+    u128: CastInto<I>,
+{
+    if a.is_nan() {
+        raise_except(INVALID);
+        return I::ZERO;
+    }
+    a.to_int()
+}
+
+#[cfg(all(target_arch = "x86_64", not(any(windows, target_os = "macos", target_os = "ios"))))]
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub extern "C" fn __rust_fixxfsi_sat(a: &f80) -> i32 {
+    f80_to_int_sat(a)
+}
+
+#[cfg(all(target_arch = "x86_64", not(any(windows, target_os = "macos", target_os = "ios"))))]
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub extern "C" fn __rust_fixxfdi_sat(a: &f80) -> i64 {
+    f80_to_int_sat(a)
+}
+
+#[cfg(all(target_arch = "x86_64", not(any(windows, target_os = "macos", target_os = "ios"))))]
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub extern "C" fn __rust_fixxfti_sat(a: &f80) -> i128 {
+    f80_to_int_sat(a)
+}
+
+#[cfg(all(target_arch = "x86_64", not(any(windows, target_os = "macos", target_os = "ios"))))]
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub extern "C" fn __rust_fixunsxfsi_sat(a: &f80) -> u32 {
+    f80_to_int_sat(a)
+}
+
+#[cfg(all(target_arch = "x86_64", not(any(windows, target_os = "macos", target_os = "ios"))))]
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub extern "C" fn __rust_fixunsxfdi_sat(a: &f80) -> u64 {
+    f80_to_int_sat(a)
+}
+
+#[cfg(all(target_arch = "x86_64", not(any(windows, target_os = "macos", target_os = "ios"))))]
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub extern "C" fn __rust_fixunsxfti_sat(a: &f80) -> u128 {
+    f80_to_int_sat(a)
+}
+
+// The C99 conversions with rounding. Like the `mem` functions, they're only
+// exported unmangled with the `math` feature.
+
+#[cfg(any(target_pointer_width = "16", target_pointer_width = "32", windows))]
+#[allow(non_camel_case_types)]
+type c_long = i32;
+#[cfg(not(any(target_pointer_width = "16", target_pointer_width = "32", windows)))]
+#[allow(non_camel_case_types)]
+type c_long = i64;
+
+#[cfg_attr(all(feature = "math", not(feature = "mangled-names")), no_mangle)]
+pub extern "C" fn lroundf(x: f32) -> c_long {
+    float_to_int_round(x, IntRounding::HalfAway)
+}
+
+#[cfg_attr(all(feature = "math", not(feature = "mangled-names")), no_mangle)]
+pub extern "C" fn lround(x: f64) -> c_long {
+    float_to_int_round(x, IntRounding::HalfAway)
+}
+
+#[cfg_attr(all(feature = "math", not(feature = "mangled-names")), no_mangle)]
+pub extern "C" fn llroundf(x: f32) -> i64 {
+    float_to_int_round(x, IntRounding::HalfAway)
+}
+
+#[cfg_attr(all(feature = "math", not(feature = "mangled-names")), no_mangle)]
+pub extern "C" fn llround(x: f64) -> i64 {
+    float_to_int_round(x, IntRounding::HalfAway)
+}
+
+#[cfg_attr(all(feature = "math", not(feature = "mangled-names")), no_mangle)]
+pub extern "C" fn lrintf(x: f32) -> c_long {
+    float_to_int_round(x, IntRounding::Current)
+}
+
+#[cfg_attr(all(feature = "math", not(feature = "mangled-names")), no_mangle)]
+pub extern "C" fn lrint(x: f64) -> c_long {
+    float_to_int_round(x, IntRounding::Current)
+}
+
+#[cfg_attr(all(feature = "math", not(feature = "mangled-names")), no_mangle)]
+pub extern "C" fn llrintf(x: f32) -> i64 {
+    float_to_int_round(x, IntRounding::Current)
+}
+
+#[cfg_attr(all(feature = "math", not(feature = "mangled-names")), no_mangle)]
+pub extern "C" fn llrint(x: f64) -> i64 {
+    float_to_int_round(x, IntRounding::Current)
+}
+
+// There's no C function that rounds ties to even regardless of the rounding
+// mode, these are named like the ones above, and also only exported unmangled
+// with the `math` feature.
+
+#[cfg_attr(all(feature = "math", not(feature = "mangled-names")), no_mangle)]
+pub extern "C" fn __rust_lroundevenf(x: f32) -> c_long {
+    float_to_int_round(x, IntRounding::NearestEven)
+}
+
+#[cfg_attr(all(feature = "math", not(feature = "mangled-names")), no_mangle)]
+pub extern "C" fn __rust_lroundeven(x: f64) -> c_long {
+    float_to_int_round(x, IntRounding::NearestEven)
+}
+
+#[cfg_attr(all(feature = "math", not(feature = "mangled-names")), no_mangle)]
+pub extern "C" fn __rust_llroundevenf(x: f32) -> i64 {
+    float_to_int_round(x, IntRounding::NearestEven)
+}
+
+#[cfg_attr(all(feature = "math", not(feature = "mangled-names")), no_mangle)]
+pub extern "C" fn __rust_llroundeven(x: f64) -> i64 {
+    float_to_int_round(x, IntRounding::NearestEven)
 }
@@ -0,0 +1,240 @@
+use int::{Int, CastInto, WideInt};
+use float::{Float, f128, flush_denormal, is_signaling_nan, propagate_nan};
+use float::fenv::{get_flush_to_zero, raise_except};
+use float::fenv::{DIVIDE_BY_ZERO, INEXACT, INVALID, OVERFLOW, UNDERFLOW};
+
+/// Returns `a / b`
+///
+/// The quotient is computed by multiplying `a` by a fixed-point approximation
+/// of the reciprocal of `b`, which is refined with Newton-Raphson iterations.
+/// The resulting quotient is at most one ulp below the exact result, so a
+/// single correction step based on the residual gives the correctly rounded
+/// result.
+fn div<F: Float>(a: F, b: F) -> F where
+    u32: CastInto<F::Int>,
+    F::Int: CastInto<u32>,
+    i32: CastInto<F::Int>,
+    F::Int: CastInto<i32>,
+    F::Int: WideInt,
+{
+    let one = F::Int::ONE;
+    let zero = F::Int::ZERO;
+
+    let bits =             F::BITS;
+    let significand_bits = F::SIGNIFICAND_BITS;
+    let max_exponent =     F::EXPONENT_MAX;
+    let exponent_bias =    F::EXPONENT_BIAS;
+
+    let implicit_bit =     F::IMPLICIT_BIT;
+    let significand_mask = F::SIGNIFICAND_MASK;
+    let sign_bit =         F::SIGN_MASK as F::Int;
+    let abs_mask =         sign_bit - one;
+    let exponent_mask =    F::EXPONENT_MASK;
+    let inf_rep =          exponent_mask;
+    let quiet_bit =        implicit_bit >> 1;
+    let qnan_rep =         exponent_mask | quiet_bit;
+
+    let a = flush_denormal(a);
+    let b = flush_denormal(b);
+    let a_rep = a.repr();
+    let b_rep = b.repr();
+
+    let a_exponent: u32 = ((a_rep & exponent_mask) >> significand_bits).cast();
+    let b_exponent: u32 = ((b_rep & exponent_mask) >> significand_bits).cast();
+    let quotient_sign = (a_rep ^ b_rep) & sign_bit;
+
+    let mut a_significand = a_rep & significand_mask;
+    let mut b_significand = b_rep & significand_mask;
+    let mut scale: i32 = 0;
+
+    // Detect if a or b is zero, denormal, infinity, or NaN.
+    if a_exponent.wrapping_sub(1) >= max_exponent - 1 ||
+        b_exponent.wrapping_sub(1) >= max_exponent - 1 {
+        let a_abs = a_rep & abs_mask;
+        let b_abs = b_rep & abs_mask;
+
+        if is_signaling_nan(a) || is_signaling_nan(b) {
+            raise_except(INVALID);
+        }
+
+        // NaN / anything = qNaN
+        if a_abs > inf_rep {
+            return propagate_nan(a);
+        }
+        // anything / NaN = qNaN
+        if b_abs > inf_rep {
+            return propagate_nan(b);
+        }
+
+        if a_abs == inf_rep {
+            if b_abs == inf_rep {
+                // infinity / infinity = NaN
+                raise_except(INVALID);
+                return F::from_repr(qnan_rep);
+            } else {
+                // infinity / anything else = +/- infinity
+                return F::from_repr(a_abs | quotient_sign);
+            }
+        }
+
+        // anything else / infinity = +/- zero
+        if b_abs == inf_rep {
+            return F::from_repr(quotient_sign);
+        }
+
+        if a_abs == zero {
+            if b_abs == zero {
+                // zero / zero = NaN
+                raise_except(INVALID);
+                return F::from_repr(qnan_rep);
+            } else {
+                // zero / anything else = +/- zero
+                return F::from_repr(quotient_sign);
+            }
+        }
+
+        // anything else / zero = +/- infinity
+        if b_abs == zero {
+            raise_except(DIVIDE_BY_ZERO);
+            return F::from_repr(inf_rep | quotient_sign);
+        }
+
+        // One or both of a or b is denormal, the other (if applicable) is a
+        // normal number.  Renormalize one or both of a and b, and set scale to
+        // include the necessary exponent adjustment.
+        if a_abs < implicit_bit {
+            let (exponent, significand) = F::normalize(a_significand);
+            scale += exponent;
+            a_significand = significand;
+        }
+        if b_abs < implicit_bit {
+            let (exponent, significand) = F::normalize(b_significand);
+            scale -= exponent;
+            b_significand = significand;
+        }
+    }
+
+    // Set the implicit significand bit.  If we fell through from the
+    // denormal path it was already set by normalize(), but setting it twice
+    // won't hurt anything.
+    a_significand |= implicit_bit;
+    b_significand |= implicit_bit;
+
+    let mut written_exponent: i32 =
+        a_exponent as i32 - b_exponent as i32 + scale + exponent_bias as i32;
+
+    // b as a UQ1.(bits - 1) fixed-point number in [1, 2)
+    let b_uq1 = b_significand << (bits - significand_bits - 1);
+
+    // Compute an initial estimate of 1/b as a UQ0.bits number in [0.5, 1).
+    // This is a linear approximation with C = 3/4 + 1/sqrt(2) - 1, which
+    // gives x = 1/b with an error of about 4.5 bits.
+    let c: F::Int = 0x7504F333u32.cast() << (bits - 32);
+    let mut x_uq0 = c.wrapping_sub(b_uq1);
+
+    // Newton-Raphson iterations, x = x * (2 - b * x). The error after an
+    // iteration is b * e^2 with b < 2, so every iteration doubles the number of
+    // correct bits minus one. That number is tracked in half bits because the
+    // initial estimate is correct to about 4.5 bits. We stop once the quotient
+    // computed below is guaranteed to be less than one ulp off, which takes
+    // significand_bits + 3 correct bits.
+    let mut correct_half_bits = 9;
+    while correct_half_bits < 2 * (significand_bits + 3) {
+        let corr_uq1 = zero.wrapping_sub(x_uq0.wide_mul(b_uq1).0);
+        let (hi, lo) = x_uq0.wide_mul(corr_uq1);
+        x_uq0 = hi << 1 | lo >> (bits - 1);
+        correct_half_bits = 2 * correct_half_bits - 2;
+    }
+
+    // The iterations may produce an estimate slightly larger than 1/b, which
+    // could make the quotient larger than a/b. Decrement it so that the
+    // quotient is guaranteed to be a lower bound.
+    x_uq0 = x_uq0.wrapping_sub(one + one);
+
+    // The quotient as a UQ1.(significand_bits + 1) number in [0.5, 2)
+    let mut quotient = x_uq0.wide_mul(a_significand << 1).0;
+
+    let mut residual;
+    if quotient < implicit_bit << 1 {
+        // The highest bit is clear, reinterpret the quotient as
+        // UQ1.significand_bits, effectively doubling its value.
+        residual = (a_significand << (significand_bits + 1))
+            .wrapping_sub(quotient.wrapping_mul(b_significand));
+        written_exponent -= 1;
+        a_significand <<= 1;
+    } else {
+        // The highest bit is set, shift the quotient to UQ1.significand_bits
+        quotient = quotient >> 1;
+        residual = (a_significand << significand_bits)
+            .wrapping_sub(quotient.wrapping_mul(b_significand));
+    }
+
+    // If we have overflowed the exponent, return infinity.
+    if written_exponent >= max_exponent as i32 {
+        raise_except(OVERFLOW | INEXACT);
+        return F::from_repr(inf_rep | quotient_sign);
+    }
+
+    let mut abs_result;
+    if written_exponent > 0 {
+        // Clear the implicit bit and insert the exponent
+        abs_result = quotient & significand_mask;
+        abs_result |= written_exponent.cast() << significand_bits;
+        residual <<= 1;
+    } else {
+        // The result is denormal. If it is so small that it rounds to zero
+        // regardless of the quotient, or denormals are flushed to zero,
+        // return a zero of the appropriate sign.
+        if get_flush_to_zero() {
+            raise_except(UNDERFLOW);
+            return F::from_repr(quotient_sign);
+        }
+        if significand_bits as i32 + written_exponent < 0 {
+            raise_except(UNDERFLOW | INEXACT);
+            return F::from_repr(quotient_sign);
+        }
+
+        abs_result = quotient >> (1 - written_exponent) as u32;
+
+        // The residual is multiplied by two to keep the shift amount positive.
+        residual = (a_significand << (significand_bits as i32 + written_exponent) as u32)
+            .wrapping_sub(abs_result.wrapping_mul(b_significand) << 1);
+    }
+
+    // Round to nearest, ties to even. The quotient is at most one ulp below
+    // the exact result; it needs incrementing when the remaining part
+    // (residual / b) is more than half an ulp, or exactly half an ulp with an
+    // odd quotient.
+    if residual != zero {
+        // Underflow is detected before rounding
+        raise_except(if written_exponent <= 0 { UNDERFLOW | INEXACT } else { INEXACT });
+    }
+    residual += abs_result & one;
+    if residual > b_significand {
+        abs_result += one;
+    }
+    // The rounding may have carried up to infinity
+    if abs_result == inf_rep {
+        raise_except(OVERFLOW);
+    }
+
+    F::from_repr(abs_result | quotient_sign)
+}
+
+intrinsics! {
+    #[aapcs_on_arm]
+    #[arm_aeabi_alias = __aeabi_fdiv]
+    pub extern "C" fn __divsf3(a: f32, b: f32) -> f32 {
+        div(a, b)
+    }
+
+    #[aapcs_on_arm]
+    #[arm_aeabi_alias = __aeabi_ddiv]
+    pub extern "C" fn __divdf3(a: f64, b: f64) -> f64 {
+        div(a, b)
+    }
+
+    pub extern "C" fn __divtf3(a: f128, b: f128) -> f128 {
+        div(a, b)
+    }
+}
@@ -0,0 +1,114 @@
+use int::{Int, CastInto};
+use float::{Float, bf16, f16, f128, is_signaling_nan};
+use float::fenv::{raise_except, INVALID};
+
+/// Generic conversion from a narrower to a wider IEEE-754 floating-point type
+fn extend<F: Float, R: Float>(a: F) -> R where
+    F::Int: CastInto<u32>,
+    F::Int: CastInto<R::Int>,
+    u32: CastInto<R::Int>,
+{
+    let src_zero = F::Int::ZERO;
+    let src_one = F::Int::ONE;
+    let src_bits = F::BITS;
+    let src_sig_bits = F::SIGNIFICAND_BITS;
+    let src_exp_bias = F::EXPONENT_BIAS;
+    let src_min_normal = F::IMPLICIT_BIT;
+    let src_infinity = F::EXPONENT_MASK;
+    let src_sign_mask = F::SIGN_MASK;
+    let src_abs_mask = src_sign_mask - src_one;
+    let src_qnan = src_min_normal >> 1;
+    let src_nan_code = src_qnan - src_one;
+
+    let dst_bits = R::BITS;
+    let dst_sig_bits = R::SIGNIFICAND_BITS;
+    let dst_exp_bias = R::EXPONENT_BIAS;
+    let dst_min_normal = R::IMPLICIT_BIT;
+    let dst_infinity = R::EXPONENT_MASK;
+    let dst_qnan = dst_min_normal >> 1;
+
+    let sig_bits_delta = dst_sig_bits - src_sig_bits;
+    let exp_bias_delta = dst_exp_bias - src_exp_bias;
+
+    let a_rep = a.repr();
+    let a_abs = a_rep & src_abs_mask;
+    let abs_result: R::Int;
+
+    if is_signaling_nan(a) {
+        raise_except(INVALID);
+    }
+
+    if a_abs.wrapping_sub(src_min_normal) < src_infinity.wrapping_sub(src_min_normal) {
+        // a is a normal number.
+        // Extend to the destination type by shifting the significand and
+        // exponent into the proper position and rebiasing the exponent.
+        let abs_dst: R::Int = a_abs.cast();
+        let bias_dst: R::Int = exp_bias_delta.cast();
+        abs_result = (abs_dst << sig_bits_delta) + (bias_dst << dst_sig_bits);
+    } else if a_abs > src_infinity && cfg!(feature = "default-nan") {
+        // a is NaN, return the default NaN.
+        return R::from_repr(dst_infinity | dst_qnan);
+    } else if a_abs >= src_infinity {
+        // a is NaN or infinity.
+        // Conjure the result by beginning with infinity, then setting the qNaN
+        // bit (if a is NaN, which also quiets signaling NaNs) and left-aligning
+        // the rest of the trailing NaN payload field.
+        let nan_code_dst: R::Int = (a_abs & src_nan_code).cast();
+        let quiet = if a_abs != src_infinity { dst_qnan } else { R::Int::ZERO };
+        abs_result = dst_infinity | quiet | (nan_code_dst << sig_bits_delta);
+    } else if a_abs != src_zero {
+        // a is denormal.
+        let scale = a_abs.leading_zeros() - src_min_normal.leading_zeros();
+        let abs_dst: R::Int = a_abs.cast();
+        if scale > exp_bias_delta {
+            // The destination type doesn't have a wider exponent range, like
+            // `f32` for `bf16`, so a is denormal there too.
+            abs_result = abs_dst << sig_bits_delta;
+        } else {
+            // Renormalize the significand and clear the leading bit, then
+            // insert the correct adjusted exponent in the destination type.
+            let bias_dst: R::Int = (exp_bias_delta - scale + 1).cast();
+            abs_result = ((abs_dst << (sig_bits_delta + scale)) ^ dst_min_normal) |
+                (bias_dst << dst_sig_bits);
+        }
+    } else {
+        // a is zero.
+        abs_result = R::Int::ZERO;
+    }
+
+    // Apply the signbit to (R)abs(a).
+    let sign_result: R::Int = (a_rep & src_sign_mask).cast();
+    R::from_repr(abs_result | (sign_result << (dst_bits - src_bits)))
+}
+
+intrinsics! {
+    #[aapcs_on_arm]
+    #[arm_aeabi_alias = __aeabi_f2d]
+    pub extern "C" fn __extendsfdf2(a: f32) -> f64 {
+        extend(a)
+    }
+
+    #[aapcs_on_arm]
+    #[arm_aeabi_alias = __aeabi_h2f]
+    pub extern "C" fn __extendhfsf2(a: u16) -> f32 {
+        extend(f16(a))
+    }
+
+    #[aapcs_on_arm]
+    pub extern "C" fn __gnu_h2f_ieee(a: u16) -> f32 {
+        extend(f16(a))
+    }
+
+    #[aapcs_on_arm]
+    pub extern "C" fn __extendbfsf2(a: u16) -> f32 {
+        extend(bf16(a))
+    }
+
+    pub extern "C" fn __extendsftf2(a: f32) -> f128 {
+        extend(a)
+    }
+
+    pub extern "C" fn __extenddftf2(a: f64) -> f128 {
+        extend(a)
+    }
+}
@@ -0,0 +1,360 @@
+use core::cmp;
+use core::ops;
+
+use int::{Int, CastInto};
+use float::fenv::{RoundingMode, get_rounding_mode, raise_except};
+use float::fenv::{DIVIDE_BY_ZERO, INEXACT, INVALID, OVERFLOW, UNDERFLOW};
+
+const SIGN_BIT: u16 = 0x8000;
+const EXPONENT_MAX: u16 = 0x7fff;
+const INTEGER_BIT: u64 = 1 << 63;
+const QUIET_BIT: u64 = 1 << 62;
+
+/// The exponent of the last significand bit of denormals and of the smallest
+/// normal numbers
+const MIN_EXPONENT: i32 = 1 - 16383 - 63;
+
+/// x87 extended precision floating point number
+///
+/// This is the representation of C's `long double` on x86 and x86_64, except
+/// on MSVC targets. Unlike the IEEE interchange formats, the 64-bit
+/// significand has an explicit integer bit, which is set for normal numbers
+/// and clear for denormals. The struct has the layout of the 80-bit value in
+/// memory, but it isn't passed the same way as `long double` in function
+/// calls, see the `xf` shims in `x86_64.rs`.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct f80 {
+    /// The significand, including the integer bit
+    pub significand: u64,
+    /// The sign bit followed by the 15-bit biased exponent
+    pub sign_exponent: u16,
+}
+
+impl f80 {
+    /// The "real indefinite" NaN that the x87 returns for invalid operations
+    const INDEFINITE: f80 = f80 {
+        significand: INTEGER_BIT | QUIET_BIT,
+        sign_exponent: SIGN_BIT | EXPONENT_MAX,
+    };
+
+    /// Returns the 80 bits of the value, with the sign in bit 79
+    pub fn repr(self) -> u128 {
+        (self.sign_exponent as u128) << 64 | self.significand as u128
+    }
+
+    /// Creates a value from the low 80 bits of `a`
+    pub fn from_repr(a: u128) -> f80 {
+        f80 {
+            significand: a as u64,
+            sign_exponent: (a >> 64) as u16,
+        }
+    }
+
+    /// Returns `true` if this value is NaN
+    pub fn is_nan(self) -> bool {
+        self.sign_exponent & EXPONENT_MAX == EXPONENT_MAX && self.significand << 1 != 0
+    }
+
+    fn is_signaling_nan(self) -> bool {
+        self.is_nan() && self.significand & QUIET_BIT == 0
+    }
+
+    fn is_infinite(self) -> bool {
+        self.sign_exponent & EXPONENT_MAX == EXPONENT_MAX && self.significand << 1 == 0
+    }
+
+    fn is_zero(self) -> bool {
+        self.significand == 0 && !self.is_infinite()
+    }
+
+    fn sign(self) -> bool {
+        self.sign_exponent & SIGN_BIT != 0
+    }
+
+    fn infinity(sign: bool) -> f80 {
+        f80 {
+            significand: INTEGER_BIT,
+            sign_exponent: if sign { SIGN_BIT } else { 0 } | EXPONENT_MAX,
+        }
+    }
+
+    /// Splits the value into its sign, significand and exponent, such that its
+    /// magnitude is `significand * 2^exponent` if it's finite
+    fn unpack(self) -> (bool, u64, i32) {
+        let exponent = cmp::max(self.sign_exponent & EXPONENT_MAX, 1) as i32;
+        (self.sign(), self.significand, exponent + MIN_EXPONENT - 1)
+    }
+
+    /// Rounds `(significand + d) * 2^exponent` to an `f80` in the current
+    /// rounding mode, where `0 < d < 1` if `sticky` is set and `d == 0`
+    /// otherwise. If `sticky` is set `significand` must have more than 64
+    /// significant bits, so the bits that are discarded include the round bit.
+    fn round(sign: bool, significand: u128, exponent: i32, sticky: bool) -> f80 {
+        let sign_bit = if sign { SIGN_BIT } else { 0 };
+        if significand == 0 {
+            return f80 { significand: 0, sign_exponent: sign_bit };
+        }
+        let mode = get_rounding_mode();
+
+        // The exponent of the last bit of the result
+        let top_exponent = exponent + 127 - significand.leading_zeros() as i32;
+        let bottom_exponent = cmp::max(top_exponent - 63, MIN_EXPONENT);
+        let (significand, shift) = match bottom_exponent - exponent {
+            // Less than half of the smallest denormal, only the fact that it
+            // isn't zero matters
+            shift if shift > 128 => (1, 128),
+            shift => (significand, shift),
+        };
+
+        let mut result = if shift <= 0 {
+            significand << -shift
+        } else {
+            let (result, rest) = if shift == 128 {
+                (0, significand)
+            } else {
+                (significand >> shift, significand & ((1 << shift) - 1))
+            };
+            let half = 1 << (shift - 1);
+            let inexact = rest != 0 || sticky;
+            if inexact {
+                // Underflow is detected before rounding
+                raise_except(if top_exponent < MIN_EXPONENT + 63 {
+                    UNDERFLOW | INEXACT
+                } else {
+                    INEXACT
+                });
+            }
+            let round_up = match mode {
+                RoundingMode::ToNearest => {
+                    rest > half || rest == half && (sticky || result & 1 == 1)
+                }
+                RoundingMode::Downward => inexact && sign,
+                RoundingMode::Upward => inexact && !sign,
+                RoundingMode::TowardZero => false,
+            };
+            if round_up {
+                result + 1
+            } else {
+                result
+            }
+        };
+
+        let mut biased_exponent = bottom_exponent - MIN_EXPONENT + 1;
+        if result >> 64 != 0 {
+            // Rounding carried out of the significand
+            result >>= 1;
+            biased_exponent += 1;
+        }
+        if result < INTEGER_BIT as u128 {
+            biased_exponent = 0;
+        }
+        if biased_exponent >= EXPONENT_MAX as i32 {
+            raise_except(OVERFLOW | INEXACT);
+            let toward_zero = match mode {
+                RoundingMode::ToNearest => false,
+                RoundingMode::Downward => !sign,
+                RoundingMode::Upward => sign,
+                RoundingMode::TowardZero => true,
+            };
+            if toward_zero {
+                // The largest finite number
+                return f80 {
+                    significand: !0,
+                    sign_exponent: sign_bit | (EXPONENT_MAX - 1),
+                };
+            }
+            return f80::infinity(sign);
+        }
+
+        f80 {
+            significand: result as u64,
+            sign_exponent: sign_bit | biased_exponent as u16,
+        }
+    }
+
+    /// The NaN that results from an operation with a NaN operand. Like the
+    /// x87, this prefers a quiet NaN over a signaling one, and otherwise the
+    /// NaN with the larger significand.
+    fn propagate_nan(a: f80, b: f80) -> f80 {
+        if a.is_signaling_nan() || b.is_signaling_nan() {
+            raise_except(INVALID);
+        }
+        let nan = if !b.is_nan() {
+            a
+        } else if !a.is_nan() {
+            b
+        } else if (a.significand & QUIET_BIT) != (b.significand & QUIET_BIT) {
+            if a.significand & QUIET_BIT != 0 { a } else { b }
+        } else if a.significand >= b.significand {
+            a
+        } else {
+            b
+        };
+        f80 {
+            significand: nan.significand | QUIET_BIT,
+            sign_exponent: nan.sign_exponent,
+        }
+    }
+
+    /// Converts an integer to the nearest `f80`. This is exact for integers of
+    /// up to 64 bits.
+    pub fn from_int<I: Int>(i: I) -> f80
+    where
+        I::UnsignedInt: CastInto<u128>,
+    {
+        let (sign, abs) = i.extract_sign();
+        f80::round(sign, abs.cast(), 0, false)
+    }
+
+    /// Converts to an integer, rounding towards zero. Values that are out of
+    /// range saturate, negative values convert to 0 for unsigned types.
+    pub fn to_int<I: Int>(self) -> I
+    where
+        u128: CastInto<I>,
+    {
+        let (sign, significand, exponent) = self.unpack();
+        let unsigned = I::min_value() == I::ZERO;
+
+        // The number of bits of the integer part. Infinities and NaNs have a
+        // huge exponent, so they saturate too.
+        let bits = 64 - significand.leading_zeros() as i32 + exponent;
+        let max_bits = if unsigned { I::BITS } else { I::BITS - 1 };
+        let inexact = if exponent >= 0 {
+            false
+        } else if exponent <= -64 {
+            significand != 0
+        } else {
+            significand & ((1 << -exponent) - 1) != 0
+        };
+        if bits <= 0 {
+            if inexact {
+                raise_except(INEXACT);
+            }
+            return I::ZERO;
+        }
+        if unsigned && sign {
+            raise_except(INVALID);
+            return I::ZERO;
+        }
+        if bits > max_bits as i32 {
+            // The minimum of a signed type is the only value in range here
+            let integer_part = if exponent < 0 { significand >> -exponent } else { significand };
+            if !unsigned && sign && bits == I::BITS as i32 && integer_part.is_power_of_two() {
+                if inexact {
+                    raise_except(INEXACT);
+                }
+            } else {
+                raise_except(INVALID);
+            }
+            return if sign { I::min_value() } else { I::max_value() };
+        }
+        if inexact {
+            raise_except(INEXACT);
+        }
+
+        let magnitude = if exponent < 0 {
+            (significand >> -exponent) as u128
+        } else {
+            (significand as u128) << exponent
+        };
+        let r: I = magnitude.cast();
+        if sign {
+            (!r).wrapping_add(I::ONE)
+        } else {
+            r
+        }
+    }
+}
+
+impl ops::Mul for f80 {
+    type Output = f80;
+
+    fn mul(self, other: f80) -> f80 {
+        if self.is_nan() || other.is_nan() {
+            return f80::propagate_nan(self, other);
+        }
+        let sign = self.sign() != other.sign();
+        if self.is_infinite() || other.is_infinite() {
+            // infinity * zero = NaN
+            return if self.is_zero() || other.is_zero() {
+                raise_except(INVALID);
+                f80::INDEFINITE
+            } else {
+                f80::infinity(sign)
+            };
+        }
+
+        let (_, a_significand, a_exponent) = self.unpack();
+        let (_, b_significand, b_exponent) = other.unpack();
+        let product = a_significand as u128 * b_significand as u128;
+        f80::round(sign, product, a_exponent + b_exponent, false)
+    }
+}
+
+impl ops::MulAssign for f80 {
+    fn mul_assign(&mut self, other: f80) {
+        *self = *self * other;
+    }
+}
+
+impl ops::Div for f80 {
+    type Output = f80;
+
+    fn div(self, other: f80) -> f80 {
+        if self.is_nan() || other.is_nan() {
+            return f80::propagate_nan(self, other);
+        }
+        let sign = self.sign() != other.sign();
+        if self.is_infinite() {
+            // infinity / infinity = NaN
+            return if other.is_infinite() {
+                raise_except(INVALID);
+                f80::INDEFINITE
+            } else {
+                f80::infinity(sign)
+            };
+        }
+        if other.is_infinite() {
+            return f80::round(sign, 0, 0, false);
+        }
+        if other.is_zero() {
+            // zero / zero = NaN
+            return if self.is_zero() {
+                raise_except(INVALID);
+                f80::INDEFINITE
+            } else {
+                raise_except(DIVIDE_BY_ZERO);
+                f80::infinity(sign)
+            };
+        }
+        if self.is_zero() {
+            return f80::round(sign, 0, 0, false);
+        }
+
+        // Normalize the significands so their top bit is set
+        let (_, a_significand, a_exponent) = self.unpack();
+        let (_, b_significand, b_exponent) = other.unpack();
+        let a_shift = a_significand.leading_zeros();
+        let b_shift = b_significand.leading_zeros();
+        let a_significand = (a_significand << a_shift) as u128;
+        let b_significand = (b_significand << b_shift) as u128;
+        let exponent = (a_exponent - a_shift as i32) - (b_exponent - b_shift as i32);
+
+        // Long division, one bit of the quotient at a time. The quotient ends
+        // up with 65 or 66 bits.
+        let mut quotient = 0u128;
+        let mut remainder = a_significand;
+        for _ in 0..66 {
+            quotient <<= 1;
+            if remainder >= b_significand {
+                remainder -= b_significand;
+                quotient |= 1;
+            }
+            remainder <<= 1;
+        }
+
+        f80::round(sign, quotient, exponent - 65, remainder != 0)
+    }
+}
@@ -0,0 +1,121 @@
+//! The floating point environment of the soft-float routines
+//!
+//! The rounding mode is respected by the additions and subtractions and by
+//! the conversions from integers, when the `fp-rounding` feature is enabled.
+//! Reading the mode costs a load of a global in each of these operations, and
+//! keeps `__floatdidf` from using the native conversion on x86_64, so without
+//! the feature they always round to nearest, ties to even, like the other
+//! operations.
+//!
+//! The soft-float routines also record the IEEE-754 exceptions they raise in
+//! sticky flags, when the `fp-exceptions` feature is enabled. Underflow is
+//! detected before rounding. Targets with an FPU record the exceptions of
+//! hardware operations in its own status register instead.
+//!
+//! In flush-to-zero mode, the arithmetic operations and the conversions to
+//! integers treat denormals as zeros, like an ARM FPU with the `FZ` bit of
+//! `FPSCR` set.
+//!
+//! The environment is a global of the process, it isn't per thread like C's
+//! `fenv.h`. Its accesses aren't synchronized, because the targets without
+//! atomics need it too, so it must only be used by a single thread: changing
+//! the rounding mode or the flags while another thread does floating point
+//! operations is a data race.
+
+/// Invalid operation, like `0 / 0` or a conversion of NaN to an integer
+pub const INVALID: u32 = 0x01;
+/// Division of a finite number by zero
+pub const DIVIDE_BY_ZERO: u32 = 0x02;
+/// Result too large in magnitude to be represented
+pub const OVERFLOW: u32 = 0x04;
+/// Result tiny and inexact
+pub const UNDERFLOW: u32 = 0x08;
+/// Result rounded
+pub const INEXACT: u32 = 0x10;
+/// All the exceptions
+pub const ALL_EXCEPTIONS: u32 = INVALID | DIVIDE_BY_ZERO | OVERFLOW | UNDERFLOW | INEXACT;
+
+/// IEEE-754 rounding direction
+///
+/// The discriminants are the values of compiler-rt's `CRT_FE_ROUND_MODE`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RoundingMode {
+    /// Round to nearest, ties to even. This is the default.
+    ToNearest = 0,
+    /// Round towards negative infinity
+    Downward = 1,
+    /// Round towards positive infinity
+    Upward = 2,
+    /// Round towards zero
+    TowardZero = 3,
+}
+
+static mut ROUNDING_MODE: RoundingMode = RoundingMode::ToNearest;
+
+/// Returns the current rounding mode. Without the `fp-rounding` feature, this
+/// is always `ToNearest`.
+pub fn get_rounding_mode() -> RoundingMode {
+    if cfg!(feature = "fp-rounding") {
+        unsafe { ROUNDING_MODE }
+    } else {
+        RoundingMode::ToNearest
+    }
+}
+
+/// Sets the rounding mode of the following operations. Without the
+/// `fp-rounding` feature, this does nothing.
+pub fn set_rounding_mode(mode: RoundingMode) {
+    if cfg!(feature = "fp-rounding") {
+        unsafe { ROUNDING_MODE = mode }
+    }
+}
+
+static mut FLUSH_TO_ZERO: bool = false;
+
+/// Returns `true` if flush-to-zero mode is enabled
+pub fn get_flush_to_zero() -> bool {
+    unsafe { FLUSH_TO_ZERO }
+}
+
+/// Enables or disables flush-to-zero mode
+///
+/// In this mode, the additions, subtractions, multiplications, divisions and
+/// conversions to integers read denormal operands as zeros of the same sign.
+/// Results that are tiny before rounding are replaced with a zero of the same
+/// sign, which raises only the underflow exception.
+pub fn set_flush_to_zero(enable: bool) {
+    unsafe { FLUSH_TO_ZERO = enable }
+}
+
+static mut EXCEPTIONS: u32 = 0;
+
+/// Returns which of the exceptions in `excepts` have been raised since their
+/// flags were last cleared. Without the `fp-exceptions` feature, this always
+/// returns 0.
+pub fn test_except(excepts: u32) -> u32 {
+    unsafe { EXCEPTIONS & excepts }
+}
+
+/// Clears the flags of the exceptions in `excepts`
+pub fn clear_except(excepts: u32) {
+    unsafe { EXCEPTIONS &= !excepts }
+}
+
+/// Raises the exceptions in `excepts`. Without the `fp-exceptions` feature,
+/// this does nothing.
+pub fn raise_except(excepts: u32) {
+    if cfg!(feature = "fp-exceptions") {
+        unsafe { EXCEPTIONS |= excepts & ALL_EXCEPTIONS }
+    }
+}
+
+intrinsics! {
+    pub extern "C" fn __fe_getround() -> i32 {
+        get_rounding_mode() as i32
+    }
+
+    pub extern "C" fn __fe_raise_inexact() -> i32 {
+        raise_except(INEXACT);
+        0
+    }
+}
@@ -0,0 +1,188 @@
+use float::Float;
+
+/// OCP 8-bit floating point number with 4 exponent and 3 significand bits,
+/// stored as its bit pattern
+///
+/// Unlike in the IEEE formats, the largest exponent holds normal numbers too,
+/// and there are no infinities. The only NaNs are the encodings with all the
+/// exponent and significand bits set, so the largest number is 448.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy)]
+pub struct f8e4m3(pub u8);
+
+/// OCP 8-bit floating point number with 5 exponent and 2 significand bits,
+/// stored as its bit pattern
+///
+/// This follows the IEEE rules, with infinities and NaNs in the largest
+/// exponent. The largest finite number is 57344.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy)]
+pub struct f8e5m2(pub u8);
+
+/// Rounds `a` to the nearest number of the 8-bit format `F`, ties to even
+///
+/// The format has infinities if `has_infinity` is set, otherwise it's E4M3
+/// with its single NaN. Values that round to more than the largest finite
+/// number overflow to infinity, or to NaN without infinities, or to the
+/// largest finite number if `saturate` is set. Infinities saturate too.
+fn from_f32<F: Float<Int = u8>>(a: f32, has_infinity: bool, saturate: bool) -> u8 {
+    let significand_bits = F::SIGNIFICAND_BITS;
+    let exponent_bias = F::EXPONENT_BIAS as i32;
+    let quiet_bit = F::IMPLICIT_BIT >> 1;
+    let (max_rep, nan_rep) = if has_infinity {
+        (F::EXPONENT_MASK - 1, F::EXPONENT_MASK | quiet_bit)
+    } else {
+        (F::EXPONENT_MASK | (F::SIGNIFICAND_MASK - 1), F::EXPONENT_MASK | F::SIGNIFICAND_MASK)
+    };
+    let overflow_rep = if saturate {
+        max_rep
+    } else if has_infinity {
+        F::EXPONENT_MASK
+    } else {
+        nan_rep
+    };
+
+    let a_rep = a.repr();
+    let a_abs = a_rep & !f32::SIGN_MASK;
+    let sign = (a_rep >> (f32::BITS - F::BITS)) as u8 & F::SIGN_MASK;
+
+    if a_abs > f32::EXPONENT_MASK {
+        // a is NaN. With infinities, the top of the payload is kept.
+        if !has_infinity || cfg!(feature = "default-nan") {
+            return nan_rep | sign;
+        }
+        let payload = (a_abs & ((f32::IMPLICIT_BIT >> 1) - 1)) >>
+            (f32::SIGNIFICAND_BITS - significand_bits);
+        return nan_rep | payload as u8 | sign;
+    }
+    if a_abs == f32::EXPONENT_MASK {
+        return overflow_rep | sign;
+    }
+
+    // a = significand * 2^(exponent - 23), the result has its last bit at the
+    // exponent of the last significand bit of F, or of its denormals
+    let a_exponent = (a_abs >> f32::SIGNIFICAND_BITS) as i32;
+    let (exponent, significand) = if a_exponent == 0 {
+        (1 - f32::EXPONENT_BIAS as i32, a_abs)
+    } else {
+        (a_exponent - f32::EXPONENT_BIAS as i32, a_abs & f32::SIGNIFICAND_MASK | f32::IMPLICIT_BIT)
+    };
+    let biased_exponent = if exponent + exponent_bias > 1 { exponent + exponent_bias } else { 1 };
+    let shift = (f32::SIGNIFICAND_BITS - significand_bits) as i32 + biased_exponent -
+        (exponent + exponent_bias);
+
+    // Round to nearest, ties to even
+    let rounded = if shift >= 32 {
+        0
+    } else {
+        let rest = significand & ((1 << shift) - 1);
+        let halfway = 1 << (shift - 1);
+        let result = significand >> shift;
+        if rest > halfway || rest == halfway && result & 1 != 0 {
+            result + 1
+        } else {
+            result
+        }
+    };
+
+    // Adding the significand with its implicit bit increments the exponent,
+    // which takes care of a carry out of the significand, and of denormals
+    // that round up to the smallest normal number
+    let abs_result = (((biased_exponent - 1) as u32) << significand_bits) + rounded;
+    if abs_result > max_rep as u32 {
+        return overflow_rep | sign;
+    }
+    abs_result as u8 | sign
+}
+
+/// Converts `a` of the 8-bit format `F` to `f32`, which is exact
+fn to_f32<F: Float<Int = u8>>(a: u8, has_infinity: bool) -> f32 {
+    let significand_bits = F::SIGNIFICAND_BITS;
+    let a_abs = a & !F::SIGN_MASK;
+    let sign = ((a & F::SIGN_MASK) as u32) << (f32::BITS - F::BITS);
+
+    let is_nan = if has_infinity {
+        a_abs > F::EXPONENT_MASK
+    } else {
+        a_abs == F::EXPONENT_MASK | F::SIGNIFICAND_MASK
+    };
+    if is_nan {
+        let quiet_nan = f32::EXPONENT_MASK | f32::IMPLICIT_BIT >> 1;
+        if !has_infinity || cfg!(feature = "default-nan") {
+            return f32::from_repr(quiet_nan | sign);
+        }
+        // Quiet the NaN and keep its payload
+        let payload = ((a_abs & ((F::IMPLICIT_BIT >> 1) - 1)) as u32) <<
+            (f32::SIGNIFICAND_BITS - significand_bits);
+        return f32::from_repr(quiet_nan | payload | sign);
+    }
+    if has_infinity && a_abs == F::EXPONENT_MASK {
+        return f32::from_repr(f32::EXPONENT_MASK | sign);
+    }
+    if a_abs == 0 {
+        return f32::from_repr(sign);
+    }
+
+    // a = significand * 2^exponent
+    let a_exponent = (a_abs >> significand_bits) as i32;
+    let (exponent, significand) = if a_exponent == 0 {
+        (1 - F::EXPONENT_BIAS as i32 - significand_bits as i32, a_abs as u32)
+    } else {
+        (a_exponent - F::EXPONENT_BIAS as i32 - significand_bits as i32,
+         (a_abs & F::SIGNIFICAND_MASK | F::IMPLICIT_BIT) as u32)
+    };
+
+    // Move the top bit of the significand to the implicit bit of the `f32`
+    let top = 31 - significand.leading_zeros();
+    let biased_exponent = (exponent + top as i32 + f32::EXPONENT_BIAS as i32) as u32;
+    let rep = (biased_exponent << f32::SIGNIFICAND_BITS) |
+        ((significand << (f32::SIGNIFICAND_BITS - top)) & f32::SIGNIFICAND_MASK);
+    f32::from_repr(rep | sign)
+}
+
+impl f8e4m3 {
+    /// Converts `a` to the nearest `f8e4m3`, ties to even
+    ///
+    /// There are no infinities, so values that are too large in magnitude
+    /// convert to NaN, or to the largest finite number with the same sign if
+    /// `saturate` is set. Infinities convert the same way.
+    pub fn from_f32(a: f32, saturate: bool) -> f8e4m3 {
+        f8e4m3(from_f32::<f8e4m3>(a, false, saturate))
+    }
+
+    /// Converts to `f32`, which is exact
+    pub fn to_f32(self) -> f32 {
+        to_f32::<f8e4m3>(self.0, false)
+    }
+
+    /// Returns `true` if this value is NaN
+    pub fn is_nan(self) -> bool {
+        self.0 & 0x7f == 0x7f
+    }
+}
+
+impl f8e5m2 {
+    /// Converts `a` to the nearest `f8e5m2`, ties to even
+    ///
+    /// Values that are too large in magnitude convert to infinity, or to the
+    /// largest finite number with the same sign if `saturate` is set, and so
+    /// do infinities.
+    pub fn from_f32(a: f32, saturate: bool) -> f8e5m2 {
+        f8e5m2(from_f32::<f8e5m2>(a, true, saturate))
+    }
+
+    /// Converts to `f32`, which is exact
+    pub fn to_f32(self) -> f32 {
+        to_f32::<f8e5m2>(self.0, true)
+    }
+
+    /// Returns `true` if this value is NaN
+    pub fn is_nan(self) -> bool {
+        self.0 & 0x7f > 0x7c
+    }
+}
+
+// Like with `f16`, arithmetic is carried out in single precision and rounded
+// back. Results that overflow don't saturate.
+forward_to_f32!(f8e4m3, |a| f8e4m3::from_f32(a, false));
+forward_to_f32!(f8e5m2, |a| f8e5m2::from_f32(a, false));
@@ -0,0 +1,27 @@
+use float::extend::__extendhfsf2;
+use float::trunc::__truncsfhf2;
+
+/// IEEE-754 half precision (binary16) floating point number, stored as its
+/// bit pattern
+///
+/// Arithmetic on `f16` is carried out in single precision and rounded back.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy)]
+pub struct f16(pub u16);
+
+impl f16 {
+    /// Returns `true` if this value is NaN
+    pub fn is_nan(self) -> bool {
+        self.0 & 0x7fff > 0x7c00
+    }
+
+    fn to_f32(self) -> f32 {
+        __extendhfsf2(self.0)
+    }
+
+    fn from_f32(a: f32) -> f16 {
+        f16(__truncsfhf2(a))
+    }
+}
+
+forward_to_f32!(f16, f16::from_f32);
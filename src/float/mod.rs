@@ -3,10 +3,72 @@ use core::ops;
 
 use super::int::Int;
 
+pub use self::bfloat::bf16;
+pub use self::extended::f80;
+pub use self::fp8::{f8e4m3, f8e5m2};
+pub use self::half::f16;
+pub use self::quad::f128;
+
+// Rust has no native types for the narrow formats, `f16`, `bf16` and the 8-bit
+// ones, so they're stored as their bit patterns. They're compared in single
+// precision, and their arithmetic is carried out in single precision and
+// rounded back, which gives correctly rounded results because `f32` has more
+// than twice the precision of all of them. `$from_f32` rounds an `f32` back to
+// `$ty`.
+macro_rules! forward_to_f32 {
+    ($ty:ident, $from_f32:expr) => {
+        impl PartialEq for $ty {
+            fn eq(&self, other: &$ty) -> bool {
+                self.to_f32() == other.to_f32()
+            }
+        }
+
+        impl PartialOrd for $ty {
+            fn partial_cmp(&self, other: &$ty) -> Option<::core::cmp::Ordering> {
+                self.to_f32().partial_cmp(&other.to_f32())
+            }
+        }
+
+        forward_to_f32!($ty, $from_f32, Add, add, AddAssign, add_assign);
+        forward_to_f32!($ty, $from_f32, Sub, sub, SubAssign, sub_assign);
+        forward_to_f32!($ty, $from_f32, Mul, mul, MulAssign, mul_assign);
+        forward_to_f32!($ty, $from_f32, Div, div, DivAssign, div_assign);
+        forward_to_f32!($ty, $from_f32, Rem, rem, RemAssign, rem_assign);
+    };
+    ($ty:ident, $from_f32:expr, $op:ident, $fn:ident, $op_assign:ident, $fn_assign:ident) => {
+        impl ::core::ops::$op for $ty {
+            type Output = $ty;
+
+            fn $fn(self, other: $ty) -> $ty {
+                ($from_f32)(::core::ops::$op::$fn(self.to_f32(), other.to_f32()))
+            }
+        }
+
+        impl ::core::ops::$op_assign for $ty {
+            fn $fn_assign(&mut self, other: $ty) {
+                *self = ::core::ops::$op::$fn(*self, other);
+            }
+        }
+    };
+}
+
 pub mod conv;
 pub mod add;
+pub mod bfloat;
+pub mod cmp;
+pub mod complex;
+pub mod div;
+pub mod extend;
+pub mod extended;
+pub mod fenv;
+pub mod fp8;
+pub mod half;
+pub mod mul;
 pub mod pow;
+pub mod quad;
+pub mod rem;
 pub mod sub;
+pub mod trunc;
 
 /// Trait for some basic operations on floats
 pub trait Float:
@@ -17,6 +79,7 @@ pub trait Float:
     ops::MulAssign +
     ops::Add<Output = Self> +
     ops::Sub<Output = Self> +
+    ops::Mul<Output = Self> +
     ops::Div<Output = Self> +
     ops::Rem<Output = Self> +
 {
@@ -76,10 +139,13 @@ pub trait Float:
 //        https://github.com/rust-lang/rfcs/issues/1424
 macro_rules! float_impl {
     ($ty:ident, $ity:ident, $bits:expr, $significand_bits:expr) => {
+        float_impl!($ty, $ity, $bits, $significand_bits, 0.0, 1.0);
+    };
+    ($ty:ident, $ity:ident, $bits:expr, $significand_bits:expr, $zero:expr, $one:expr) => {
         impl Float for $ty {
             type Int = $ity;
-            const ZERO: Self = 0.0;
-            const ONE: Self = 1.0;
+            const ZERO: Self = $zero;
+            const ONE: Self = $one;
 
             const BITS: u32 = $bits;
             const SIGNIFICAND_BITS: u32 = $significand_bits;
@@ -119,3 +185,43 @@ macro_rules! float_impl {
 
 float_impl!(f32, u32, 32, 23);
 float_impl!(f64, u64, 64, 52);
+float_impl!(f16, u16, 16, 10, f16(0), f16(0x3c00));
+float_impl!(bf16, u16, 16, 7, bf16(0), bf16(0x3f80));
+float_impl!(f8e4m3, u8, 8, 3, f8e4m3(0), f8e4m3(0x38));
+float_impl!(f8e5m2, u8, 8, 2, f8e5m2(0), f8e5m2(0x3c));
+#[cfg(target_endian = "little")]
+float_impl!(f128, u128, 128, 112, f128(0, 0), f128(0, 0x3fff << 48));
+#[cfg(target_endian = "big")]
+float_impl!(f128, u128, 128, 112, f128(0, 0), f128(0x3fff << 48, 0));
+
+/// Returns `true` if `x` is a signaling NaN, which raises an invalid exception
+/// when it's an operand
+fn is_signaling_nan<F: Float>(x: F) -> bool {
+    let abs = x.repr() & !F::SIGN_MASK;
+    abs > F::EXPONENT_MASK && abs & (F::IMPLICIT_BIT >> 1) == F::Int::ZERO
+}
+
+/// Returns the result of an operation whose NaN operand is `x`
+///
+/// This is `x` quieted, which keeps its payload like x86 FPUs. With the
+/// `default-nan` feature it's the default NaN instead, like ARM FPUs with the
+/// `DN` bit of `FPSCR` set.
+fn propagate_nan<F: Float>(x: F) -> F {
+    let quiet_bit = F::IMPLICIT_BIT >> 1;
+    if cfg!(feature = "default-nan") {
+        F::from_repr(F::EXPONENT_MASK | quiet_bit)
+    } else {
+        F::from_repr(x.repr() | quiet_bit)
+    }
+}
+
+/// Returns `x`, or a zero of the same sign if `x` is denormal and denormals are
+/// flushed to zero
+fn flush_denormal<F: Float>(x: F) -> F {
+    let abs = x.repr() & !F::SIGN_MASK;
+    if fenv::get_flush_to_zero() && abs != F::Int::ZERO && abs < F::IMPLICIT_BIT {
+        F::from_repr(x.repr() & F::SIGN_MASK)
+    } else {
+        x
+    }
+}
@@ -1,7 +1,11 @@
+use core::ops;
+
 use int::Int;
-use float::Float;
+use float::f80;
+
+trait Pow: Copy + ops::MulAssign + ops::Div<Output = Self> {
+    const ONE: Self;
 
-trait Pow: Float {
     /// Returns `a` raised to the power `b`
     fn pow(self, mut b: i32) -> Self {
         let mut a = self;
@@ -26,8 +30,20 @@ trait Pow: Float {
     }
 }
 
-impl Pow for f32 {}
-impl Pow for f64 {}
+impl Pow for f32 {
+    const ONE: f32 = 1.0;
+}
+
+impl Pow for f64 {
+    const ONE: f64 = 1.0;
+}
+
+impl Pow for f80 {
+    const ONE: f80 = f80 {
+        significand: 1 << 63,
+        sign_exponent: 0x3fff,
+    };
+}
 
 intrinsics! {
     pub extern "C" fn __powisf2(a: f32, b: i32) -> f32 {
@@ -38,3 +54,10 @@ intrinsics! {
         a.pow(b)
     }
 }
+
+/// `__powixf2` with the `long double`s passed by reference, see `float::conv`
+#[cfg(all(target_arch = "x86_64", not(any(windows, target_os = "macos", target_os = "ios"))))]
+#[cfg_attr(not(feature = "mangled-names"), no_mangle)]
+pub extern "C" fn __rust_powixf2(r: &mut f80, a: &f80, b: i32) {
+    *r = a.pow(b);
+}
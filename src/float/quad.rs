@@ -0,0 +1,76 @@
+use core::cmp::Ordering;
+use core::ops;
+
+use float::Float;
+use float::add::__addtf3;
+use float::div::__divtf3;
+use float::mul::__multf3;
+use float::rem::fmod;
+use float::sub::__subtf3;
+
+/// IEEE-754 quadruple precision (binary128) floating point number
+///
+/// This is the representation of C's `long double` on AArch64 and 64-bit
+/// RISC-V, and of `__float128` on x86_64. The bits are stored in a 128-bit
+/// vector so that, like in C, values are passed in SIMD registers on x86_64
+/// and AArch64. The fields are the two 64-bit halves of the bit pattern in
+/// memory order, `Float::repr` and `Float::from_repr` convert from and to the
+/// whole bit pattern.
+#[allow(non_camel_case_types)]
+#[repr(simd)]
+#[derive(Clone, Copy)]
+pub struct f128(pub u64, pub u64);
+
+impl f128 {
+    /// Returns `true` if this value is NaN
+    pub fn is_nan(self) -> bool {
+        self.repr() & !Self::SIGN_MASK > Self::EXPONENT_MASK
+    }
+
+    /// Maps the value to an integer with the same ordering. Both zeros map to
+    /// `0`. Must not be called on NaN.
+    fn ordering_key(self) -> i128 {
+        let abs = (self.repr() & !Self::SIGN_MASK) as i128;
+        if self.repr() & Self::SIGN_MASK != 0 { -abs } else { abs }
+    }
+}
+
+impl PartialEq for f128 {
+    fn eq(&self, other: &f128) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for f128 {
+    fn partial_cmp(&self, other: &f128) -> Option<Ordering> {
+        if self.is_nan() || other.is_nan() {
+            None
+        } else {
+            self.ordering_key().partial_cmp(&other.ordering_key())
+        }
+    }
+}
+
+macro_rules! f128_op {
+    ($op:ident, $fn:ident, $op_assign:ident, $fn_assign:ident, $intrinsic:path) => {
+        impl ops::$op for f128 {
+            type Output = f128;
+
+            fn $fn(self, other: f128) -> f128 {
+                $intrinsic(self, other)
+            }
+        }
+
+        impl ops::$op_assign for f128 {
+            fn $fn_assign(&mut self, other: f128) {
+                *self = $intrinsic(*self, other);
+            }
+        }
+    }
+}
+
+f128_op!(Add, add, AddAssign, add_assign, __addtf3);
+f128_op!(Sub, sub, SubAssign, sub_assign, __subtf3);
+f128_op!(Mul, mul, MulAssign, mul_assign, __multf3);
+f128_op!(Div, div, DivAssign, div_assign, __divtf3);
+f128_op!(Rem, rem, RemAssign, rem_assign, fmod);
@@ -0,0 +1,145 @@
+use int::{Int, CastInto};
+use float::{Float, is_signaling_nan, propagate_nan};
+use float::fenv::{raise_except, INVALID};
+
+/// Returns the remainder of `x / y` rounded towards zero, i.e. C's `fmod`
+pub fn fmod<F: Float>(x: F, y: F) -> F where
+    F::Int: CastInto<i32>,
+    i32: CastInto<F::Int>,
+{
+    rem(x, y, false)
+}
+
+/// Returns `x - n * y`, where `n` is `x / y` rounded to nearest, ties to even,
+/// i.e. C's `remainder`
+pub fn remainder<F: Float>(x: F, y: F) -> F where
+    F::Int: CastInto<i32>,
+    i32: CastInto<F::Int>,
+{
+    rem(x, y, true)
+}
+
+/// Returns `x - n * y`, where `n` is `x / y` rounded towards zero, or to
+/// nearest with ties to even if `quotient_to_nearest` is set
+///
+/// The result is always exact. It's computed by long division of the
+/// significands, one bit of the quotient per exponent step.
+fn rem<F: Float>(x: F, y: F, quotient_to_nearest: bool) -> F where
+    F::Int: CastInto<i32>,
+    i32: CastInto<F::Int>,
+{
+    let one = F::Int::ONE;
+    let zero = F::Int::ZERO;
+
+    let significand_bits = F::SIGNIFICAND_BITS;
+    let implicit_bit =     F::IMPLICIT_BIT;
+    let significand_mask = F::SIGNIFICAND_MASK;
+    let sign_bit =         F::SIGN_MASK;
+    let abs_mask =         sign_bit - one;
+    let inf_rep =          F::EXPONENT_MASK;
+    let quiet_bit =        implicit_bit >> 1;
+    let qnan_rep =         inf_rep | quiet_bit;
+
+    let x_rep = x.repr();
+    let y_rep = y.repr();
+    let x_abs = x_rep & abs_mask;
+    let y_abs = y_rep & abs_mask;
+    let mut sign = x_rep & sign_bit;
+
+    if is_signaling_nan(x) || is_signaling_nan(y) {
+        raise_except(INVALID);
+    }
+    // NaN % anything = qNaN
+    if x_abs > inf_rep {
+        return propagate_nan(x);
+    }
+    // anything % NaN = qNaN
+    if y_abs > inf_rep {
+        return propagate_nan(y);
+    }
+    // infinity % anything = NaN, anything % zero = NaN
+    if x_abs == inf_rep || y_abs == zero {
+        raise_except(INVALID);
+        return F::from_repr(qnan_rep);
+    }
+    // zero % anything = zero, anything % infinity = anything
+    if x_abs == zero || y_abs == inf_rep {
+        return x;
+    }
+
+    // Extract the exponents and significands, normalizing denormals so that
+    // both significands have the implicit bit set.
+    let mut x_exponent: i32 = (x_abs >> significand_bits).cast();
+    let mut x_significand = x_abs & significand_mask;
+    if x_exponent == 0 {
+        let (exponent, significand) = F::normalize(x_significand);
+        x_exponent = exponent;
+        x_significand = significand;
+    } else {
+        x_significand |= implicit_bit;
+    }
+
+    let mut y_exponent: i32 = (y_abs >> significand_bits).cast();
+    let mut y_significand = y_abs & significand_mask;
+    if y_exponent == 0 {
+        let (exponent, significand) = F::normalize(y_significand);
+        y_exponent = exponent;
+        y_significand = significand;
+    } else {
+        y_significand |= implicit_bit;
+    }
+
+    // Divide at the exponent of y, or at the exponent of half of y to tell
+    // which multiple of y is the nearest
+    let (divisor, divisor_exponent) = if quotient_to_nearest {
+        (y_significand << 1, y_exponent - 1)
+    } else {
+        (y_significand, y_exponent)
+    };
+    // |x| < |y| / 2 when rounding to nearest, or |x| < |y| otherwise, so the
+    // quotient is zero
+    if x_exponent < divisor_exponent || x_abs < y_abs && !quotient_to_nearest {
+        return x;
+    }
+
+    // Long division. The partial remainder stays below `2 * divisor`, so
+    // doubling it can't overflow the integer type. Only the parity of the
+    // quotient is kept.
+    while x_exponent > divisor_exponent {
+        if x_significand >= divisor {
+            x_significand = x_significand - divisor;
+        }
+        x_significand = x_significand << 1;
+        x_exponent -= 1;
+    }
+    let quotient_odd = x_significand >= divisor;
+    if quotient_odd {
+        x_significand = x_significand - divisor;
+    }
+
+    // The remainder is less than |y|, go to the nearer multiple of y if needed
+    if quotient_to_nearest &&
+        (x_significand > y_significand || x_significand == y_significand && quotient_odd) {
+        x_significand = divisor - x_significand;
+        sign = sign ^ sign_bit;
+    }
+
+    if x_significand == zero {
+        return F::from_repr(sign);
+    }
+
+    // Renormalize the remainder, which is at most |y|.
+    let shift = x_significand.leading_zeros() - implicit_bit.leading_zeros();
+    x_significand = x_significand << shift;
+    x_exponent -= shift as i32;
+
+    let abs_result = if x_exponent > 0 {
+        (x_significand & significand_mask) | (x_exponent.cast() << significand_bits)
+    } else {
+        // The remainder is a multiple of the smallest denormal, so this shift
+        // doesn't lose any bits.
+        x_significand >> (1 - x_exponent) as u32
+    };
+
+    F::from_repr(abs_result | sign)
+}
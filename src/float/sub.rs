@@ -1,13 +1,18 @@
-use float::Float;
+use float::{Float, f128};
+use float::add::{__adddf3, __addsf3, __addtf3};
 
 intrinsics! {
     #[arm_aeabi_alias = __aeabi_fsub]
     pub extern "C" fn __subsf3(a: f32, b: f32) -> f32 {
-        a + f32::from_repr(b.repr() ^ f32::SIGN_MASK)
+        __addsf3(a, f32::from_repr(b.repr() ^ f32::SIGN_MASK))
     }
 
     #[arm_aeabi_alias = __aeabi_dsub]
     pub extern "C" fn __subdf3(a: f64, b: f64) -> f64 {
-        a + f64::from_repr(b.repr() ^ f64::SIGN_MASK)
+        __adddf3(a, f64::from_repr(b.repr() ^ f64::SIGN_MASK))
+    }
+
+    pub extern "C" fn __subtf3(a: f128, b: f128) -> f128 {
+        __addtf3(a, f128::from_repr(b.repr() ^ f128::SIGN_MASK))
     }
 }
@@ -0,0 +1,180 @@
+use int::{Int, CastInto};
+use float::{Float, bf16, f16, f128, is_signaling_nan};
+use float::fenv::{raise_except, INEXACT, INVALID, OVERFLOW, UNDERFLOW};
+
+/// Generic conversion from a wider to a narrower IEEE-754 floating-point type
+fn trunc<F: Float, R: Float>(a: F) -> R where
+    F::Int: CastInto<u32>,
+    F::Int: CastInto<R::Int>,
+    u32: CastInto<F::Int>,
+    u32: CastInto<R::Int>,
+{
+    let src_zero = F::Int::ZERO;
+    let src_one = F::Int::ONE;
+    let src_bits = F::BITS;
+    let src_sig_bits = F::SIGNIFICAND_BITS;
+    let src_exp_bias = F::EXPONENT_BIAS;
+    let src_min_normal = F::IMPLICIT_BIT;
+    let src_significand_mask = F::SIGNIFICAND_MASK;
+    let src_infinity = F::EXPONENT_MASK;
+    let src_sign_mask = F::SIGN_MASK;
+    let src_abs_mask = src_sign_mask - src_one;
+    let src_qnan = src_min_normal >> 1;
+    let src_nan_code = src_qnan - src_one;
+
+    let dst_zero = R::Int::ZERO;
+    let dst_one = R::Int::ONE;
+    let dst_bits = R::BITS;
+    let dst_sig_bits = R::SIGNIFICAND_BITS;
+    let dst_inf_exp = R::EXPONENT_MAX;
+    let dst_exp_bias = R::EXPONENT_BIAS;
+    let dst_infinity = R::EXPONENT_MASK;
+    let dst_qnan = R::IMPLICIT_BIT >> 1;
+    let dst_nan_code = dst_qnan - dst_one;
+
+    let sig_bits_delta = src_sig_bits - dst_sig_bits;
+    let round_mask = (src_one << sig_bits_delta) - src_one;
+    let halfway = src_one << (sig_bits_delta - 1);
+
+    let underflow_exponent: F::Int = (src_exp_bias + 1 - dst_exp_bias).cast();
+    let overflow_exponent: F::Int = (src_exp_bias + dst_inf_exp - dst_exp_bias).cast();
+    let underflow = underflow_exponent << src_sig_bits;
+    let overflow = overflow_exponent << src_sig_bits;
+
+    // Break a into a sign and representation of the absolute value
+    let a_rep = a.repr();
+    let a_abs = a_rep & src_abs_mask;
+    let sign = a_rep & src_sign_mask;
+    let mut abs_result: R::Int;
+
+    if is_signaling_nan(a) {
+        raise_except(INVALID);
+    }
+
+    if a_abs.wrapping_sub(underflow) < a_abs.wrapping_sub(overflow) {
+        // The exponent of a is within the range of normal numbers in the
+        // destination format.  We can convert by simply right-shifting with
+        // rounding and adjusting the exponent.
+        abs_result = (a_abs >> sig_bits_delta).cast();
+        let bias_delta: R::Int = (src_exp_bias - dst_exp_bias).cast();
+        abs_result = abs_result.wrapping_sub(bias_delta << dst_sig_bits);
+
+        let round_bits = a_abs & round_mask;
+        if round_bits != src_zero {
+            raise_except(INEXACT);
+        }
+        // Round to nearest
+        if round_bits > halfway {
+            abs_result += dst_one;
+        }
+        // Ties to even
+        else if round_bits == halfway {
+            abs_result += abs_result & dst_one;
+        }
+        // The rounding may have carried up to infinity
+        if abs_result == dst_infinity {
+            raise_except(OVERFLOW);
+        }
+    } else if a_abs > src_infinity && cfg!(feature = "default-nan") {
+        // a is NaN, return the default NaN.
+        return R::from_repr(dst_infinity | dst_qnan);
+    } else if a_abs > src_infinity {
+        // a is NaN.
+        // Conjure the result by beginning with infinity, setting the qNaN
+        // bit and inserting the (truncated) trailing NaN field. The qNaN bit
+        // keeps the result a NaN even if the whole payload is truncated away.
+        let nan_code: R::Int = ((a_abs & src_nan_code) >> sig_bits_delta).cast();
+        abs_result = dst_infinity | dst_qnan | (nan_code & dst_nan_code);
+    } else if a_abs >= overflow {
+        // a overflows to infinity, or is infinite.
+        if a_abs != src_infinity {
+            raise_except(OVERFLOW | INEXACT);
+        }
+        abs_result = dst_infinity;
+    } else {
+        // a underflows on conversion to the destination type or is an exact
+        // zero.  The result may be a denormal or zero.  Extract the exponent
+        // to get the shift amount for the denormalization. Denormal inputs
+        // have the same scale as the smallest normal exponent but no implicit
+        // bit.
+        let a_exp: u32 = (a_abs >> src_sig_bits).cast();
+        let (a_exp, significand) = if a_exp == 0 {
+            (1, a_rep & src_significand_mask)
+        } else {
+            (a_exp, (a_rep & src_significand_mask) | src_min_normal)
+        };
+        let shift = src_exp_bias - dst_exp_bias - a_exp + 1;
+
+        // Right shift by the denormalization amount with sticky.
+        if shift > src_sig_bits {
+            if significand != src_zero {
+                raise_except(UNDERFLOW | INEXACT);
+            }
+            abs_result = dst_zero;
+        } else {
+            let sticky = shift != 0 && significand << (src_bits - shift) != src_zero;
+            let denormalized_significand = significand >> shift | F::Int::from_bool(sticky);
+            abs_result = (denormalized_significand >> sig_bits_delta).cast();
+            let round_bits = denormalized_significand & round_mask;
+            // Underflow is detected before rounding
+            if round_bits != src_zero {
+                raise_except(UNDERFLOW | INEXACT);
+            }
+            // Round to nearest
+            if round_bits > halfway {
+                abs_result += dst_one;
+            }
+            // Ties to even
+            else if round_bits == halfway {
+                abs_result += abs_result & dst_one;
+            }
+        }
+    }
+
+    // Apply the signbit to (R)abs(a).
+    let sign_result: R::Int = (sign >> (src_bits - dst_bits)).cast();
+    R::from_repr(abs_result | sign_result)
+}
+
+intrinsics! {
+    #[aapcs_on_arm]
+    #[arm_aeabi_alias = __aeabi_d2f]
+    pub extern "C" fn __truncdfsf2(a: f64) -> f32 {
+        trunc(a)
+    }
+
+    #[aapcs_on_arm]
+    #[arm_aeabi_alias = __aeabi_f2h]
+    pub extern "C" fn __truncsfhf2(a: f32) -> u16 {
+        trunc::<f32, f16>(a).0
+    }
+
+    #[aapcs_on_arm]
+    pub extern "C" fn __gnu_f2h_ieee(a: f32) -> u16 {
+        trunc::<f32, f16>(a).0
+    }
+
+    #[aapcs_on_arm]
+    #[arm_aeabi_alias = __aeabi_d2h]
+    pub extern "C" fn __truncdfhf2(a: f64) -> u16 {
+        trunc::<f64, f16>(a).0
+    }
+
+    #[aapcs_on_arm]
+    pub extern "C" fn __truncsfbf2(a: f32) -> u16 {
+        trunc::<f32, bf16>(a).0
+    }
+
+    #[aapcs_on_arm]
+    pub extern "C" fn __truncdfbf2(a: f64) -> u16 {
+        trunc::<f64, bf16>(a).0
+    }
+
+    pub extern "C" fn __trunctfsf2(a: f128) -> f32 {
+        trunc(a)
+    }
+
+    pub extern "C" fn __trunctfdf2(a: f128) -> f64 {
+        trunc(a)
+    }
+}
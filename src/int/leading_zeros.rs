@@ -0,0 +1,115 @@
+// LLVM lowers `leading_zeros` and `trailing_zeros` to these intrinsics on
+// targets without a count leading zeros instruction, like ARMv6-M, so they
+// can't be implemented with those methods, which would call back into
+// themselves. Instead each step halves the width that's left to search,
+// using the result of a comparison as a shift amount rather than branching.
+
+use int::LargeInt;
+
+/// Returns the number of leading zeros of `x`, 32 if `x` is zero
+fn leading_zeros_u32(x: u32) -> u32 {
+    let mut x = x;
+
+    // If the top 16 bits are zero they are all leading zeros and the search
+    // continues in the bottom 16 bits, otherwise in the top 16 bits
+    let t = ((x & 0xffff_0000 == 0) as u32) << 4;
+    x >>= 16 - t;
+    let mut r = t;
+
+    let t = ((x & 0xff00 == 0) as u32) << 3;
+    x >>= 8 - t;
+    r += t;
+
+    let t = ((x & 0xf0 == 0) as u32) << 2;
+    x >>= 4 - t;
+    r += t;
+
+    let t = ((x & 0xc == 0) as u32) << 1;
+    x >>= 2 - t;
+    r += t;
+
+    // Now x < 4: 0 has two more leading zeros, 1 has one and 2 and 3 have none
+    r + (2u32.wrapping_sub(x) & ((x & 2 == 0) as u32).wrapping_neg())
+}
+
+/// Returns the number of leading zeros of `x`, 64 if `x` is zero
+fn leading_zeros_u64(x: u64) -> u32 {
+    // All ones if the high half is zero, which selects the low half
+    let high_zero = ((x.high() == 0) as u32).wrapping_neg();
+    leading_zeros_u32(x.high() & !high_zero | x.low() & high_zero) + (high_zero & 32)
+}
+
+/// Returns the number of leading zeros of `x`, 128 if `x` is zero
+fn leading_zeros_u128(x: u128) -> u32 {
+    let high_zero = ((x.high() == 0) as u64).wrapping_neg();
+    leading_zeros_u64(x.high() & !high_zero | x.low() & high_zero) + (high_zero as u32 & 64)
+}
+
+/// Returns the number of trailing zeros of `x`, 32 if `x` is zero
+fn trailing_zeros_u32(x: u32) -> u32 {
+    let mut x = x;
+
+    // If the bottom 16 bits are zero they are all trailing zeros and the
+    // search continues in the top 16 bits, otherwise in the bottom 16 bits
+    let t = ((x & 0xffff == 0) as u32) << 4;
+    x >>= t;
+    let mut r = t;
+
+    let t = ((x & 0xff == 0) as u32) << 3;
+    x >>= t;
+    r += t;
+
+    let t = ((x & 0xf == 0) as u32) << 2;
+    x >>= t;
+    r += t;
+
+    let t = ((x & 0x3 == 0) as u32) << 1;
+    x >>= t;
+    r += t;
+
+    // Now the bottom two bits are left: 0 has two more trailing zeros, 2 has
+    // one and 1 and 3 have none
+    let x = x & 3;
+    r + ((2 - (x >> 1)) & ((x & 1 == 0) as u32).wrapping_neg())
+}
+
+/// Returns the number of trailing zeros of `x`, 64 if `x` is zero
+fn trailing_zeros_u64(x: u64) -> u32 {
+    // All ones if the low half is zero, which selects the high half
+    let low_zero = ((x.low() == 0) as u32).wrapping_neg();
+    trailing_zeros_u32(x.high() & low_zero | x.low() & !low_zero) + (low_zero & 32)
+}
+
+/// Returns the number of trailing zeros of `x`, 128 if `x` is zero
+fn trailing_zeros_u128(x: u128) -> u32 {
+    let low_zero = ((x.low() == 0) as u64).wrapping_neg();
+    trailing_zeros_u64(x.high() & low_zero | x.low() & !low_zero) + (low_zero as u32 & 64)
+}
+
+intrinsics! {
+    pub extern "C" fn __clzsi2(a: u32) -> i32 {
+        leading_zeros_u32(a) as i32
+    }
+
+    pub extern "C" fn __clzdi2(a: u64) -> i32 {
+        leading_zeros_u64(a) as i32
+    }
+
+    #[unadjusted_on_win64]
+    pub extern "C" fn __clzti2(a: u128) -> i32 {
+        leading_zeros_u128(a) as i32
+    }
+
+    pub extern "C" fn __ctzsi2(a: u32) -> i32 {
+        trailing_zeros_u32(a) as i32
+    }
+
+    pub extern "C" fn __ctzdi2(a: u64) -> i32 {
+        trailing_zeros_u64(a) as i32
+    }
+
+    #[unadjusted_on_win64]
+    pub extern "C" fn __ctzti2(a: u128) -> i32 {
+        trailing_zeros_u128(a) as i32
+    }
+}
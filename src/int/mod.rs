@@ -12,6 +12,7 @@ macro_rules! os_ty {
     }
 }
 
+pub mod leading_zeros;
 pub mod mul;
 pub mod sdiv;
 pub mod shift;
@@ -179,6 +180,8 @@ macro_rules! int_impl {
     }
 }
 
+int_impl!(i8, u8, 8);
+int_impl!(i16, u16, 16);
 int_impl!(i32, u32, 32);
 int_impl!(i64, u64, 64);
 int_impl!(i128, u128, 128);
@@ -225,6 +228,90 @@ large_int!(i64, u32, i32, 32);
 large_int!(u128, u64, u64, 64);
 large_int!(i128, u64, i64, 64);
 
+/// Trait for integers twice the bit width of another integer. This is used as a
+/// "double word" when full width products and shifts are needed.
+pub trait WideInt: Int {
+    /// Returns the full width product of `self` and `other` as `(high, low)`
+    fn wide_mul(self, other: Self) -> (Self, Self);
+
+    /// Shifts the double word `(high, low)` left by `count` bits, `0 < count < Self::BITS`
+    fn wide_shift_left(high: &mut Self, low: &mut Self, count: u32);
+
+    /// Shifts the double word `(high, low)` right by `count` bits, `0 < count`. Any bits
+    /// shifted out are ORed into the lowest bit of the result.
+    fn wide_shift_right_with_sticky(high: &mut Self, low: &mut Self, count: u32);
+}
+
+macro_rules! wide_int_shifts {
+    ($ty:ty) => {
+        fn wide_shift_left(high: &mut $ty, low: &mut $ty, count: u32) {
+            *high = *high << count | *low >> (<$ty as Int>::BITS - count);
+            *low = *low << count;
+        }
+
+        fn wide_shift_right_with_sticky(high: &mut $ty, low: &mut $ty, count: u32) {
+            let bits = <$ty as Int>::BITS;
+            if count < bits {
+                let sticky = (*low << (bits - count) != 0) as $ty;
+                *low = *high << (bits - count) | *low >> count | sticky;
+                *high = *high >> count;
+            } else if count == bits {
+                let sticky = (*low != 0) as $ty;
+                *low = *high | sticky;
+                *high = 0;
+            } else if count < 2 * bits {
+                let sticky = (*high << (2 * bits - count) | *low != 0) as $ty;
+                *low = *high >> (count - bits) | sticky;
+                *high = 0;
+            } else {
+                *low = (*high | *low != 0) as $ty;
+                *high = 0;
+            }
+        }
+    }
+}
+
+macro_rules! wide_int {
+    ($ty:ty, $tywide:ty) => {
+        impl WideInt for $ty {
+            fn wide_mul(self, other: $ty) -> ($ty, $ty) {
+                let product = (self as $tywide).wrapping_mul(other as $tywide);
+                ((product >> <$ty as Int>::BITS) as $ty, product as $ty)
+            }
+
+            wide_int_shifts!($ty);
+        }
+    }
+}
+
+wide_int!(u32, u64);
+wide_int!(u64, u128);
+
+// There's no 256-bit integer type, so the product is assembled from the products of the 64-bit
+// halves
+impl WideInt for u128 {
+    fn wide_mul(self, other: u128) -> (u128, u128) {
+        let a_low = self.low() as u128;
+        let a_high = self.high() as u128;
+        let b_low = other.low() as u128;
+        let b_high = other.high() as u128;
+
+        let low_low = a_low * b_low;
+        let low_high = a_low * b_high;
+        let high_low = a_high * b_low;
+        let high_high = a_high * b_high;
+
+        // Sum of all the terms of weight 2^64, at most 3 * (2^64 - 1) so this can't overflow
+        let middle = (low_low >> 64) + (low_high.low() as u128) + (high_low.low() as u128);
+
+        let high = high_high + (low_high >> 64) + (high_low >> 64) + (middle >> 64);
+        let low = u128::from_parts(low_low.low(), middle.low());
+        (high, low)
+    }
+
+    wide_int_shifts!(u128);
+}
+
 /// Trait to express (possibly lossy) casting of integers
 pub trait CastInto<T: Copy>: Copy {
     fn cast(self) -> T;
@@ -232,7 +319,7 @@ pub trait CastInto<T: Copy>: Copy {
 
 macro_rules! cast_into {
     ($ty:ty) => {
-        cast_into!($ty; usize, isize, u32, i32, u64, i64, u128, i128);
+        cast_into!($ty; usize, isize, u8, i8, u16, i16, u32, i32, u64, i64, u128, i128);
     };
     ($ty:ty; $($into:ty),*) => {$(
         impl CastInto<$into> for $ty {
@@ -243,6 +330,10 @@ macro_rules! cast_into {
     )*};
 }
 
+cast_into!(u8);
+cast_into!(i8);
+cast_into!(u16);
+cast_into!(i16);
 cast_into!(u32);
 cast_into!(i32);
 cast_into!(u64);
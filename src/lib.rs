@@ -46,6 +46,7 @@ mod macros;
 pub mod int;
 pub mod float;
 
+pub mod math;
 pub mod mem;
 
 #[cfg(target_arch = "arm")]
@@ -0,0 +1,508 @@
+//! Functions of the C math library
+//!
+//! LLVM lowers some float operations, like `f32::mul_add`, to calls to these
+//! functions. Like the `mem` functions, they're only exported unmangled with
+//! the `math` feature, for targets whose C library doesn't provide them.
+
+use float::{Float, rem};
+use float::fenv::{RoundingMode, get_rounding_mode, raise_except};
+use float::fenv::{INEXACT, INVALID, OVERFLOW, UNDERFLOW};
+use int::{Int, CastInto, WideInt};
+
+/// Returns the number of leading zeros of the double word `(high, low)`
+fn wide_leading_zeros<I: Int>(high: I, low: I) -> u32 {
+    if high != I::ZERO {
+        high.leading_zeros()
+    } else {
+        I::BITS + low.leading_zeros()
+    }
+}
+
+/// Shifts the double word `(high, low)` left by `count` bits, `count < 2 * I::BITS`
+fn wide_shift_left<I: WideInt>(high: &mut I, low: &mut I, count: u32) {
+    if count >= I::BITS {
+        *high = *low << (count - I::BITS);
+        *low = I::ZERO;
+    } else if count > 0 {
+        I::wide_shift_left(high, low, count);
+    }
+}
+
+/// Splits the finite, nonzero `x` into its sign, significand and exponent,
+/// such that its magnitude is `significand * 2^exponent` and the implicit bit
+/// of the significand is set
+fn unpack<F: Float>(x: F) -> (bool, F::Int, i32) where
+    F::Int: CastInto<i32>,
+{
+    let rep = x.repr();
+    let sign = rep & F::SIGN_MASK != F::Int::ZERO;
+    let exponent: i32 = ((rep & F::EXPONENT_MASK) >> F::SIGNIFICAND_BITS).cast();
+    let (exponent, significand) = if exponent == 0 {
+        F::normalize(rep & F::SIGNIFICAND_MASK)
+    } else {
+        (exponent, rep & F::SIGNIFICAND_MASK | F::IMPLICIT_BIT)
+    };
+    (sign, significand, exponent - F::EXPONENT_BIAS as i32 - F::SIGNIFICAND_BITS as i32)
+}
+
+/// Returns `x * y + z`, rounded once in the current rounding mode
+///
+/// The product is exact in a double word, and so is the sum, except for the
+/// bits of the smaller operand that are shifted out during the alignment.
+/// Those are kept as a sticky bit, far enough below the rounding position.
+fn fused_mul_add<F: Float>(x: F, y: F, z: F) -> F where
+    F::Int: WideInt,
+    F::Int: CastInto<i32>,
+    i32: CastInto<F::Int>,
+{
+    let zero = F::Int::ZERO;
+    let one = F::Int::ONE;
+    let bits = F::BITS;
+    let abs_mask = !F::SIGN_MASK;
+    let inf_rep = F::EXPONENT_MASK;
+
+    let x_abs = x.repr() & abs_mask;
+    let y_abs = y.repr() & abs_mask;
+    let z_abs = z.repr() & abs_mask;
+
+    // The product of zeros, infinities and NaNs is exact, so only the
+    // addition rounds
+    if x_abs == zero || y_abs == zero || x_abs >= inf_rep || y_abs >= inf_rep ||
+        z_abs > inf_rep {
+        return x * y + z;
+    }
+    // The finite product doesn't matter, even if rounding it would overflow
+    if z_abs == inf_rep {
+        return z;
+    }
+
+    let mode = get_rounding_mode();
+    let (x_sign, x_significand, x_exponent) = unpack(x);
+    let (y_sign, y_significand, y_exponent) = unpack(y);
+    let product_sign = x_sign != y_sign;
+    // A zero z adds nothing to the product, which isn't an exact zero, so its
+    // sign doesn't matter
+    let (z_sign, z_significand, z_exponent) = if z_abs == zero {
+        (product_sign, zero, 0)
+    } else {
+        unpack(z)
+    };
+
+    // Put the product and z in double words, with their top bit just below
+    // the top of the double word so that the sum can't overflow
+    let (mut p_high, mut p_low) = x_significand.wide_mul(y_significand);
+    let p_shift = wide_leading_zeros(p_high, p_low) - 1;
+    wide_shift_left(&mut p_high, &mut p_low, p_shift);
+    let p_exponent = x_exponent + y_exponent - p_shift as i32;
+
+    let (mut z_high, mut z_low) = (zero, z_significand);
+    let z_shift = wide_leading_zeros(z_high, z_low) - 1;
+    wide_shift_left(&mut z_high, &mut z_low, z_shift);
+    let z_exponent = z_exponent - z_shift as i32;
+
+    // Align the operand with the smaller exponent
+    let exponent = if z_abs == zero {
+        p_exponent
+    } else if p_exponent >= z_exponent {
+        if p_exponent > z_exponent {
+            let align = (p_exponent - z_exponent) as u32;
+            F::Int::wide_shift_right_with_sticky(&mut z_high, &mut z_low, align);
+        }
+        p_exponent
+    } else {
+        let align = (z_exponent - p_exponent) as u32;
+        F::Int::wide_shift_right_with_sticky(&mut p_high, &mut p_low, align);
+        z_exponent
+    };
+
+    let (sign, mut high, mut low) = if product_sign == z_sign {
+        let low = p_low.wrapping_add(z_low);
+        let high = p_high + z_high + F::Int::from_bool(low < p_low);
+        (z_sign, high, low)
+    } else {
+        if p_high == z_high && p_low == z_low {
+            // Exact cancellation, the result is +0, or -0 when rounding down
+            if mode == RoundingMode::Downward {
+                return F::from_repr(F::SIGN_MASK);
+            }
+            return F::from_repr(zero);
+        }
+        // Subtract the smaller magnitude from the larger one
+        let p_larger = p_high > z_high || p_high == z_high && p_low > z_low;
+        let (a_high, a_low, b_high, b_low, sign) = if p_larger {
+            (p_high, p_low, z_high, z_low, product_sign)
+        } else {
+            (z_high, z_low, p_high, p_low, z_sign)
+        };
+        let low = a_low.wrapping_sub(b_low);
+        let high = a_high - b_high - F::Int::from_bool(a_low < b_low);
+        (sign, high, low)
+    };
+    let sign_bit = if sign { F::SIGN_MASK } else { zero };
+
+    // Normalize the sum so its top bit is the top bit of `high`
+    let shift = wide_leading_zeros(high, low);
+    wide_shift_left(&mut high, &mut low, shift);
+    let mut biased_exponent =
+        exponent - shift as i32 + 2 * bits as i32 - 1 + F::EXPONENT_BIAS as i32;
+
+    if biased_exponent >= F::EXPONENT_MAX as i32 {
+        raise_except(OVERFLOW | INEXACT);
+        let toward_zero = match mode {
+            RoundingMode::ToNearest => false,
+            RoundingMode::Downward => !sign,
+            RoundingMode::Upward => sign,
+            RoundingMode::TowardZero => true,
+        };
+        if toward_zero {
+            return F::from_repr((inf_rep - one) | sign_bit);
+        }
+        return F::from_repr(inf_rep | sign_bit);
+    }
+
+    // Underflow is detected before rounding
+    let tiny = biased_exponent <= 0;
+    if tiny {
+        // The result is denormal, shift the significand into place
+        F::Int::wide_shift_right_with_sticky(&mut high, &mut low, (1 - biased_exponent) as u32);
+        biased_exponent = 0;
+    }
+
+    // The significand is the top of `high`, the rest of the double word is
+    // only needed for rounding
+    let significand = high >> F::EXPONENT_BITS;
+    let rest = high & ((one << F::EXPONENT_BITS) - one);
+    let half = one << (F::EXPONENT_BITS - 1);
+    let mut result = (biased_exponent.cast() << F::SIGNIFICAND_BITS) +
+        (significand & F::SIGNIFICAND_MASK);
+
+    let inexact = rest != zero || low != zero;
+    let round_up = match mode {
+        RoundingMode::ToNearest => {
+            rest > half || rest == half && (low != zero || result & one == one)
+        }
+        RoundingMode::Downward => inexact && sign,
+        RoundingMode::Upward => inexact && !sign,
+        RoundingMode::TowardZero => false,
+    };
+    if round_up {
+        // This may carry into the exponent, up to infinity
+        result += one;
+    }
+    if inexact {
+        raise_except(if tiny { UNDERFLOW | INEXACT } else { INEXACT });
+        if result == inf_rep {
+            raise_except(OVERFLOW);
+        }
+    }
+
+    F::from_repr(result | sign_bit)
+}
+
+/// Returns the square root of `x`, rounded in the current rounding mode
+///
+/// The significand of the root is computed one bit at a time, with one more
+/// bit for rounding, like in fdlibm. The root of a float is never halfway
+/// between two floats, so the extra bit and the remainder tell which way to
+/// round.
+fn square_root<F: Float>(x: F) -> F where
+    F::Int: CastInto<i32>,
+    i32: CastInto<F::Int>,
+{
+    let zero = F::Int::ZERO;
+    let one = F::Int::ONE;
+    let inf_rep = F::EXPONENT_MASK;
+
+    let x_rep = x.repr();
+    let x_abs = x_rep & !F::SIGN_MASK;
+
+    // sqrt(NaN) = NaN
+    if x_abs > inf_rep {
+        return x + x;
+    }
+    // sqrt(+-0) = +-0
+    if x_abs == zero {
+        return x;
+    }
+    // sqrt(negative) = NaN
+    if x_rep != x_abs {
+        raise_except(INVALID);
+        return F::from_repr(inf_rep | F::IMPLICIT_BIT >> 1);
+    }
+    // sqrt(infinity) = infinity
+    if x_abs == inf_rep {
+        return x;
+    }
+
+    // x = (significand / 2^significand_bits) * 2^exponent, make the exponent
+    // even so that it can be halved
+    let (_, mut significand, exponent) = unpack(x);
+    let mut exponent = exponent + F::SIGNIFICAND_BITS as i32;
+    if exponent & 1 != 0 {
+        significand <<= 1;
+        exponent -= 1;
+    }
+
+    let mut remainder = significand << 1;
+    let mut root = zero;
+    let mut bit = F::IMPLICIT_BIT << 1;
+    let mut s = zero;
+    while bit != zero {
+        let t = s + bit;
+        if t <= remainder {
+            s = t + bit;
+            remainder = remainder - t;
+            root += bit;
+        }
+        remainder <<= 1;
+        bit = bit >> 1;
+    }
+
+    // The lowest bit of `root` is the round bit
+    let inexact = root & one != zero || remainder != zero;
+    let round_up = match get_rounding_mode() {
+        RoundingMode::ToNearest => root & one != zero,
+        RoundingMode::Upward => inexact,
+        RoundingMode::Downward | RoundingMode::TowardZero => false,
+    };
+    if inexact {
+        raise_except(INEXACT);
+    }
+
+    // The root is normal, its implicit bit adds one to the exponent
+    let biased_exponent = exponent / 2 + F::EXPONENT_BIAS as i32 - 1;
+    let mut result = (biased_exponent.cast() << F::SIGNIFICAND_BITS) + (root >> 1);
+    if round_up {
+        result += one;
+    }
+    F::from_repr(result)
+}
+
+/// Returns the exponent of `x` without the bias, which is negative for
+/// `|x| < 1` and at least `F::SIGNIFICAND_BITS` for integers too large to
+/// have a fraction, infinities and NaNs
+fn unbiased_exponent<F: Float>(x: F) -> i32 where
+    F::Int: CastInto<i32>,
+{
+    let exponent: i32 = ((x.repr() & F::EXPONENT_MASK) >> F::SIGNIFICAND_BITS).cast();
+    exponent - F::EXPONENT_BIAS as i32
+}
+
+/// Returns `x` rounded to an integer in the given rounding mode, ties to even
+/// when rounding to nearest
+///
+/// The fraction bits are cleared, and one unit is added to the magnitude if
+/// it needs rounding up. The carry can propagate into the exponent.
+fn round_to_integral<F: Float>(x: F, mode: RoundingMode) -> F where
+    F::Int: CastInto<i32>,
+    i32: CastInto<F::Int>,
+{
+    let zero = F::Int::ZERO;
+    let one = F::Int::ONE;
+
+    let rep = x.repr();
+    let sign_bit = rep & F::SIGN_MASK;
+    let negative = sign_bit != zero;
+    let abs = rep & !F::SIGN_MASK;
+    let exponent = unbiased_exponent(x);
+
+    // Zeros, integers too large to have a fraction, infinities and NaNs
+    if abs == zero || exponent >= F::SIGNIFICAND_BITS as i32 {
+        return x;
+    }
+
+    if exponent < 0 {
+        // 0 < |x| < 1 rounds to a zero or to one
+        let half_rep = (F::EXPONENT_BIAS as i32 - 1).cast() << F::SIGNIFICAND_BITS;
+        let to_one = match mode {
+            RoundingMode::ToNearest => abs > half_rep,
+            RoundingMode::Downward => negative,
+            RoundingMode::Upward => !negative,
+            RoundingMode::TowardZero => false,
+        };
+        if to_one {
+            let one_rep = (F::EXPONENT_BIAS as i32).cast() << F::SIGNIFICAND_BITS;
+            return F::from_repr(one_rep | sign_bit);
+        }
+        return F::from_repr(sign_bit);
+    }
+
+    let fraction_mask = F::SIGNIFICAND_MASK >> exponent as u32;
+    let fraction = abs & fraction_mask;
+    if fraction == zero {
+        return x;
+    }
+    let integral = abs & !fraction_mask;
+    let unit = fraction_mask + one;
+    let half = unit >> 1;
+    let round_up = match mode {
+        RoundingMode::ToNearest => fraction > half || fraction == half && integral & unit != zero,
+        RoundingMode::Downward => negative,
+        RoundingMode::Upward => !negative,
+        RoundingMode::TowardZero => false,
+    };
+    if round_up {
+        F::from_repr((integral + unit) | sign_bit)
+    } else {
+        F::from_repr(integral | sign_bit)
+    }
+}
+
+/// Returns `x` rounded to the nearest integer, ties away from zero
+fn round_half_away<F: Float>(x: F) -> F where
+    F::Int: CastInto<i32>,
+    i32: CastInto<F::Int>,
+{
+    let rep = x.repr();
+    let sign_bit = rep & F::SIGN_MASK;
+    let exponent = unbiased_exponent(x);
+
+    // Integers too large to have a fraction, infinities and NaNs
+    if exponent >= F::SIGNIFICAND_BITS as i32 {
+        return x;
+    }
+    // 0.5 <= |x| < 1 rounds to one, smaller magnitudes to a zero
+    if exponent == -1 {
+        let one_rep = (F::EXPONENT_BIAS as i32).cast() << F::SIGNIFICAND_BITS;
+        return F::from_repr(one_rep | sign_bit);
+    }
+    if exponent < 0 {
+        return F::from_repr(sign_bit);
+    }
+
+    let fraction_mask = F::SIGNIFICAND_MASK >> exponent as u32;
+    let half = (fraction_mask + F::Int::ONE) >> 1;
+    F::from_repr(((rep & !F::SIGN_MASK) + half) & !fraction_mask | sign_bit)
+}
+
+/// Maps the non-NaN `x` to an integer with the same order, where -0 < +0
+fn order_key<F: Float>(x: F) -> F::Int {
+    let rep = x.repr();
+    if rep & F::SIGN_MASK != F::Int::ZERO {
+        !rep
+    } else {
+        rep | F::SIGN_MASK
+    }
+}
+
+/// Returns the smaller of `x` and `y`, ignoring a NaN operand
+fn minimum<F: Float>(x: F, y: F) -> F {
+    if x.repr() & !F::SIGN_MASK > F::EXPONENT_MASK {
+        return y;
+    }
+    if y.repr() & !F::SIGN_MASK > F::EXPONENT_MASK {
+        return x;
+    }
+    if order_key(x) <= order_key(y) { x } else { y }
+}
+
+/// Returns the larger of `x` and `y`, ignoring a NaN operand
+fn maximum<F: Float>(x: F, y: F) -> F {
+    if x.repr() & !F::SIGN_MASK > F::EXPONENT_MASK {
+        return y;
+    }
+    if y.repr() & !F::SIGN_MASK > F::EXPONENT_MASK {
+        return x;
+    }
+    if order_key(x) >= order_key(y) { x } else { y }
+}
+
+#[cfg_attr(all(feature = "math", not(feature = "mangled-names")), no_mangle)]
+pub extern "C" fn fmaf(x: f32, y: f32, z: f32) -> f32 {
+    fused_mul_add(x, y, z)
+}
+
+#[cfg_attr(all(feature = "math", not(feature = "mangled-names")), no_mangle)]
+pub extern "C" fn fma(x: f64, y: f64, z: f64) -> f64 {
+    fused_mul_add(x, y, z)
+}
+
+#[cfg_attr(all(feature = "math", not(feature = "mangled-names")), no_mangle)]
+pub extern "C" fn sqrtf(x: f32) -> f32 {
+    square_root(x)
+}
+
+#[cfg_attr(all(feature = "math", not(feature = "mangled-names")), no_mangle)]
+pub extern "C" fn sqrt(x: f64) -> f64 {
+    square_root(x)
+}
+
+#[cfg_attr(all(feature = "math", not(feature = "mangled-names")), no_mangle)]
+pub extern "C" fn floorf(x: f32) -> f32 {
+    round_to_integral(x, RoundingMode::Downward)
+}
+
+#[cfg_attr(all(feature = "math", not(feature = "mangled-names")), no_mangle)]
+pub extern "C" fn floor(x: f64) -> f64 {
+    round_to_integral(x, RoundingMode::Downward)
+}
+
+#[cfg_attr(all(feature = "math", not(feature = "mangled-names")), no_mangle)]
+pub extern "C" fn ceilf(x: f32) -> f32 {
+    round_to_integral(x, RoundingMode::Upward)
+}
+
+#[cfg_attr(all(feature = "math", not(feature = "mangled-names")), no_mangle)]
+pub extern "C" fn ceil(x: f64) -> f64 {
+    round_to_integral(x, RoundingMode::Upward)
+}
+
+#[cfg_attr(all(feature = "math", not(feature = "mangled-names")), no_mangle)]
+pub extern "C" fn truncf(x: f32) -> f32 {
+    round_to_integral(x, RoundingMode::TowardZero)
+}
+
+#[cfg_attr(all(feature = "math", not(feature = "mangled-names")), no_mangle)]
+pub extern "C" fn trunc(x: f64) -> f64 {
+    round_to_integral(x, RoundingMode::TowardZero)
+}
+
+#[cfg_attr(all(feature = "math", not(feature = "mangled-names")), no_mangle)]
+pub extern "C" fn roundf(x: f32) -> f32 {
+    round_half_away(x)
+}
+
+#[cfg_attr(all(feature = "math", not(feature = "mangled-names")), no_mangle)]
+pub extern "C" fn round(x: f64) -> f64 {
+    round_half_away(x)
+}
+
+#[cfg_attr(all(feature = "math", not(feature = "mangled-names")), no_mangle)]
+pub extern "C" fn fminf(x: f32, y: f32) -> f32 {
+    minimum(x, y)
+}
+
+#[cfg_attr(all(feature = "math", not(feature = "mangled-names")), no_mangle)]
+pub extern "C" fn fmin(x: f64, y: f64) -> f64 {
+    minimum(x, y)
+}
+
+#[cfg_attr(all(feature = "math", not(feature = "mangled-names")), no_mangle)]
+pub extern "C" fn fmaxf(x: f32, y: f32) -> f32 {
+    maximum(x, y)
+}
+
+#[cfg_attr(all(feature = "math", not(feature = "mangled-names")), no_mangle)]
+pub extern "C" fn fmax(x: f64, y: f64) -> f64 {
+    maximum(x, y)
+}
+
+#[cfg_attr(all(feature = "math", not(feature = "mangled-names")), no_mangle)]
+pub extern "C" fn fmodf(x: f32, y: f32) -> f32 {
+    rem::fmod(x, y)
+}
+
+#[cfg_attr(all(feature = "math", not(feature = "mangled-names")), no_mangle)]
+pub extern "C" fn fmod(x: f64, y: f64) -> f64 {
+    rem::fmod(x, y)
+}
+
+#[cfg_attr(all(feature = "math", not(feature = "mangled-names")), no_mangle)]
+pub extern "C" fn remainderf(x: f32, y: f32) -> f32 {
+    rem::remainder(x, y)
+}
+
+#[cfg_attr(all(feature = "math", not(feature = "mangled-names")), no_mangle)]
+pub extern "C" fn remainder(x: f64, y: f64) -> f64 {
+    rem::remainder(x, y)
+}
@@ -67,3 +67,142 @@ pub unsafe fn ___chkstk() {
           ret");
     intrinsics::unreachable();
 }
+
+// NOTE The `xf` intrinsics take and return x87 `long double`s, which are passed
+// in memory and returned on the x87 stack. These shims call the versions in
+// `float::conv` and `float::pow`, which pass the values by reference, and load
+// the result onto the x87 stack.
+//
+// The shims call the helpers through the PLT by their unmangled names, which is
+// ELF syntax, so they only exist on the ELF targets; the others use the C
+// versions from compiler-rt. They also can't follow the `mangled-names`
+// feature like the other intrinsics, because the helpers they call are mangled
+// then, so they don't exist at all when testing with mangled names.
+
+#[cfg(not(any(windows, target_os = "macos", target_os = "ios",
+              feature = "mangled-names")))]
+#[naked]
+#[no_mangle]
+pub unsafe fn __floatdixf() {
+    asm!("sub    $$24,%rsp
+          mov    %rdi,%rsi
+          mov    %rsp,%rdi
+          call   __rust_floatdixf@PLT
+          fldt   (%rsp)
+          add    $$24,%rsp
+          ret");
+    intrinsics::unreachable();
+}
+
+#[cfg(not(any(windows, target_os = "macos", target_os = "ios",
+              feature = "mangled-names")))]
+#[naked]
+#[no_mangle]
+pub unsafe fn __floatundixf() {
+    asm!("sub    $$24,%rsp
+          mov    %rdi,%rsi
+          mov    %rsp,%rdi
+          call   __rust_floatundixf@PLT
+          fldt   (%rsp)
+          add    $$24,%rsp
+          ret");
+    intrinsics::unreachable();
+}
+
+#[cfg(not(any(windows, target_os = "macos", target_os = "ios",
+              feature = "mangled-names")))]
+#[naked]
+#[no_mangle]
+pub unsafe fn __floattixf() {
+    asm!("sub    $$24,%rsp
+          mov    %rsi,%rdx
+          mov    %rdi,%rsi
+          mov    %rsp,%rdi
+          call   __rust_floattixf@PLT
+          fldt   (%rsp)
+          add    $$24,%rsp
+          ret");
+    intrinsics::unreachable();
+}
+
+#[cfg(not(any(windows, target_os = "macos", target_os = "ios",
+              feature = "mangled-names")))]
+#[naked]
+#[no_mangle]
+pub unsafe fn __floatuntixf() {
+    asm!("sub    $$24,%rsp
+          mov    %rsi,%rdx
+          mov    %rdi,%rsi
+          mov    %rsp,%rdi
+          call   __rust_floatuntixf@PLT
+          fldt   (%rsp)
+          add    $$24,%rsp
+          ret");
+    intrinsics::unreachable();
+}
+
+#[cfg(not(any(windows, target_os = "macos", target_os = "ios",
+              feature = "mangled-names")))]
+#[naked]
+#[no_mangle]
+pub unsafe fn __fixxfdi() {
+    asm!("lea    8(%rsp),%rdi   // pointer to the argument
+          jmp    __rust_fixxfdi@PLT");
+    intrinsics::unreachable();
+}
+
+#[cfg(not(any(windows, target_os = "macos", target_os = "ios",
+              feature = "mangled-names")))]
+#[naked]
+#[no_mangle]
+pub unsafe fn __fixxfti() {
+    asm!("lea    8(%rsp),%rdi   // pointer to the argument
+          jmp    __rust_fixxfti@PLT");
+    intrinsics::unreachable();
+}
+
+#[cfg(not(any(windows, target_os = "macos", target_os = "ios",
+              feature = "mangled-names")))]
+#[naked]
+#[no_mangle]
+pub unsafe fn __fixunsxfsi() {
+    asm!("lea    8(%rsp),%rdi   // pointer to the argument
+          jmp    __rust_fixunsxfsi@PLT");
+    intrinsics::unreachable();
+}
+
+#[cfg(not(any(windows, target_os = "macos", target_os = "ios",
+              feature = "mangled-names")))]
+#[naked]
+#[no_mangle]
+pub unsafe fn __fixunsxfdi() {
+    asm!("lea    8(%rsp),%rdi   // pointer to the argument
+          jmp    __rust_fixunsxfdi@PLT");
+    intrinsics::unreachable();
+}
+
+#[cfg(not(any(windows, target_os = "macos", target_os = "ios",
+              feature = "mangled-names")))]
+#[naked]
+#[no_mangle]
+pub unsafe fn __fixunsxfti() {
+    asm!("lea    8(%rsp),%rdi   // pointer to the argument
+          jmp    __rust_fixunsxfti@PLT");
+    intrinsics::unreachable();
+}
+
+#[cfg(not(any(windows, target_os = "macos", target_os = "ios",
+              feature = "mangled-names")))]
+#[naked]
+#[no_mangle]
+pub unsafe fn __powixf2() {
+    asm!("sub    $$24,%rsp
+          mov    %edi,%edx
+          lea    32(%rsp),%rsi  // pointer to the argument
+          mov    %rsp,%rdi
+          call   __rust_powixf2@PLT
+          fldt   (%rsp)
+          add    $$24,%rsp
+          ret");
+    intrinsics::unreachable();
+}
@@ -0,0 +1,104 @@
+// The flag-setting comparison helpers only exist on ARM, and are called with
+// inline assembly, which needs `std` for the test harness
+#![cfg(all(target_arch = "arm", any(target_env = "gnu", target_env = "musl")))]
+#![feature(asm)]
+#![feature(compiler_builtins_lib)]
+
+extern crate compiler_builtins;
+
+use std::mem;
+
+use compiler_builtins::arm::{__aeabi_cdcmpeq, __aeabi_cdcmpeq_check_nan, __aeabi_cdcmple,
+                             __aeabi_cdrcmple, __aeabi_cfcmpeq, __aeabi_cfcmpeq_check_nan,
+                             __aeabi_cfcmple, __aeabi_cfrcmple};
+use compiler_builtins::float::fenv::{ALL_EXCEPTIONS, INVALID, clear_except, test_except};
+
+// The Z and C bits of the flags returned by `call`
+const Z: u32 = 0b0100;
+const C: u32 = 0b0010;
+
+// The value of ip before the call, which must be preserved
+const IP: u32 = 0x1234_5678;
+
+/// Calls the helper `f` with `args` in r0-r3, returns the N, Z, C and V flags
+/// and r0-r3 and ip afterwards
+fn call(f: unsafe fn(), args: [u32; 4]) -> (u32, [u32; 5]) {
+    let (flags, r0, r1, r2, r3, ip): (u32, u32, u32, u32, u32, u32);
+    unsafe {
+        asm!("blx $6
+              mrs $0, apsr"
+             : "=r"(flags), "={r0}"(r0), "={r1}"(r1), "={r2}"(r2), "={r3}"(r3), "={r12}"(ip)
+             : "r"(f), "{r0}"(args[0]), "{r1}"(args[1]), "{r2}"(args[2]), "{r3}"(args[3]),
+               "{r12}"(IP)
+             : "lr", "cc"
+             : "volatile");
+    }
+    (flags >> 28, [r0, r1, r2, r3, ip])
+}
+
+fn f32_args(a: f32, b: f32) -> [u32; 4] {
+    unsafe { [mem::transmute(a), mem::transmute(b), 0xdead_beef, 0xcafe_f00d] }
+}
+
+fn f64_args(a: f64, b: f64) -> [u32; 4] {
+    let a: [u32; 2] = unsafe { mem::transmute(a) };
+    let b: [u32; 2] = unsafe { mem::transmute(b) };
+    [a[0], a[1], b[0], b[1]]
+}
+
+/// Checks that the flag-setting comparison `f` with the arguments `args` sets
+/// the flags like a `cmp` of `a` with `b`, and preserves the registers
+fn check(name: &str, f: unsafe fn(), args: [u32; 4], a: f64, b: f64) {
+    let flags = if a < b {
+        0
+    } else if a == b {
+        Z | C
+    } else {
+        // Greater or unordered
+        C
+    };
+    let expected = (flags, [args[0], args[1], args[2], args[3], IP]);
+    let (flags_, registers_) = call(f, args);
+    assert_eq!((name, a, b, expected), (name, a, b, (flags_ & (Z | C), registers_)));
+}
+
+#[test]
+fn aeabi_ccmp() {
+    let values = [0.0, -0.0, 1.0, -1.0, 1.5, 1.0e-40, -1.0e-40, 3.0e38,
+                  ::std::f64::INFINITY, -::std::f64::INFINITY, ::std::f64::NAN];
+    for &a in &values {
+        for &b in &values {
+            let (a32, b32) = (a as f32, b as f32);
+            let (a64, b64) = (a32 as f64, b32 as f64);
+            check("__aeabi_cfcmpeq", __aeabi_cfcmpeq, f32_args(a32, b32), a64, b64);
+            check("__aeabi_cfcmple", __aeabi_cfcmple, f32_args(a32, b32), a64, b64);
+            check("__aeabi_cfrcmple", __aeabi_cfrcmple, f32_args(a32, b32), b64, a64);
+            check("__aeabi_cdcmpeq", __aeabi_cdcmpeq, f64_args(a, b), a, b);
+            check("__aeabi_cdcmple", __aeabi_cdcmple, f64_args(a, b), a, b);
+            check("__aeabi_cdrcmple", __aeabi_cdrcmple, f64_args(a, b), b, a);
+
+            let unordered = (a.is_nan() || b.is_nan()) as i32;
+            assert_eq!((a, b, unordered), (a, b, __aeabi_cfcmpeq_check_nan(a32, b32)));
+            assert_eq!((a, b, unordered), (a, b, __aeabi_cdcmpeq_check_nan(a, b)));
+        }
+    }
+
+    // The quiet comparisons don't raise the invalid exception for quiet NaNs.
+    // Without the `fp-exceptions` feature nothing is raised anyway.
+    let nan = ::std::f64::NAN;
+    clear_except(ALL_EXCEPTIONS);
+    call(__aeabi_cfcmpeq, f32_args(nan as f32, 1.0));
+    call(__aeabi_cfcmpeq, f32_args(1.0, nan as f32));
+    call(__aeabi_cdcmpeq, f64_args(nan, 1.0));
+    call(__aeabi_cdcmpeq, f64_args(1.0, nan));
+    assert_eq!(test_except(INVALID), 0);
+
+    // The ordered comparisons raise it for any NaN
+    if cfg!(feature = "fp-exceptions") {
+        call(__aeabi_cfcmple, f32_args(nan as f32, 1.0));
+        assert_eq!(test_except(INVALID), INVALID);
+        clear_except(ALL_EXCEPTIONS);
+        call(__aeabi_cdrcmple, f64_args(1.0, nan));
+        assert_eq!(test_except(INVALID), INVALID);
+    }
+}
@@ -0,0 +1,10 @@
+// The `__aeabi_*` helpers only exist on ARM
+#![cfg(target_arch = "arm")]
+#![feature(compiler_builtins_lib)]
+#![feature(i128_type)]
+#![cfg_attr(all(target_arch = "arm",
+                not(any(target_env = "gnu", target_env = "musl")),
+                target_os = "linux",
+                test), no_std)]
+
+include!(concat!(env!("OUT_DIR"), "/aeabi_dcmp.rs"));
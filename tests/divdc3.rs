@@ -0,0 +1,60 @@
+#![cfg(not(all(target_arch = "arm",
+               not(any(target_env = "gnu", target_env = "musl")),
+               target_os = "linux")))]
+#![feature(compiler_builtins_lib)]
+
+extern crate compiler_builtins;
+
+use std::f64::{INFINITY, NAN};
+use std::mem;
+
+use compiler_builtins::float::complex::__divdc3;
+
+fn repr(x: f64) -> u64 {
+    if x.is_nan() { 0x7ff8_0000_0000_0000 } else { unsafe { mem::transmute(x) } }
+}
+
+// 2^1000, 2^-1000, 2^1023 and the smallest denormal
+const P1000: f64 = 1.0715086071862673e301;
+const M1000: f64 = 9.332636185032189e-302;
+const P1023: f64 = 8.98846567431158e307;
+const MIN: f64 = 5.0e-324;
+
+// `(a + ib) / (c + id) = re + i im`, checked by hand
+static TEST_CASES: &[((f64, f64, f64, f64), (f64, f64))] = &[
+    ((4.0, 6.0, 2.0, 0.0), (2.0, 3.0)),
+    ((1.0, 1.0, 1.0, 1.0), (1.0, 0.0)),
+    ((7.0, 1.0, 1.0, 1.0), (4.0, -3.0)),
+    ((0.0, 2.0, 0.0, 1.0), (2.0, 0.0)),
+    ((-3.0, 4.0, 0.0, -2.0), (-2.0, -1.5)),
+    // The divisor is scaled, `c * c + d * d` would overflow or underflow
+    ((P1000, P1000, P1000, P1000), (1.0, 0.0)),
+    ((P1023, 0.0, P1023, P1023), (0.5, -0.5)),
+    ((1.0, 0.0, P1000, 0.0), (M1000, 0.0)),
+    ((MIN, 0.0, MIN, 0.0), (1.0, 0.0)),
+    ((MIN, MIN, 0.0, MIN), (1.0, -1.0)),
+    // Division by zero
+    ((1.0, 2.0, 0.0, 0.0), (INFINITY, INFINITY)),
+    ((1.0, -2.0, -0.0, 0.0), (-INFINITY, INFINITY)),
+    // Infinite dividend
+    ((INFINITY, INFINITY, 0.0, 1.0), (INFINITY, -INFINITY)),
+    ((INFINITY, 0.0, 1.0, 0.0), (INFINITY, NAN)),
+    ((INFINITY, NAN, 2.0, 0.0), (INFINITY, NAN)),
+    // Infinite divisor
+    ((1.0, 1.0, INFINITY, INFINITY), (0.0, 0.0)),
+    ((1.0, 1.0, -INFINITY, INFINITY), (0.0, -0.0)),
+    ((1.0, 2.0, INFINITY, NAN), (0.0, 0.0)),
+    // NaN results
+    ((NAN, 1.0, 1.0, 1.0), (NAN, NAN)),
+    ((1.0, 1.0, NAN, 0.0), (NAN, NAN)),
+    ((0.0, 0.0, 0.0, 0.0), (NAN, NAN)),
+    ((INFINITY, 0.0, INFINITY, 0.0), (NAN, NAN)),
+];
+
+#[test]
+fn divdc3() {
+    for (i, &((a, b, c, d), (re, im))) in TEST_CASES.iter().enumerate() {
+        let z = __divdc3(a, b, c, d);
+        assert_eq!((i, (repr(re), repr(im))), (i, (repr(z.re), repr(z.im))));
+    }
+}
@@ -0,0 +1,61 @@
+#![cfg(not(all(target_arch = "arm",
+               not(any(target_env = "gnu", target_env = "musl")),
+               target_os = "linux")))]
+#![feature(compiler_builtins_lib)]
+
+extern crate compiler_builtins;
+
+use std::f32::{INFINITY, NAN};
+use std::mem;
+
+use compiler_builtins::float::complex::__divsc3;
+
+fn repr(x: f32) -> u32 {
+    if x.is_nan() { 0x7fc0_0000 } else { unsafe { mem::transmute(x) } }
+}
+
+// 2^100, 2^-100, 2^127 and the smallest denormal
+const P100: f32 = 1.2676506e30;
+const M100: f32 = 7.888609e-31;
+const P127: f32 = 1.7014118e38;
+const MIN: f32 = 1.4e-45;
+
+// `(a + ib) / (c + id) = re + i im`, checked by hand
+static TEST_CASES: &[((f32, f32, f32, f32), (f32, f32))] = &[
+    ((4.0, 6.0, 2.0, 0.0), (2.0, 3.0)),
+    ((1.0, 1.0, 1.0, 1.0), (1.0, 0.0)),
+    ((7.0, 1.0, 1.0, 1.0), (4.0, -3.0)),
+    ((0.0, 2.0, 0.0, 1.0), (2.0, 0.0)),
+    ((-3.0, 4.0, 0.0, -2.0), (-2.0, -1.5)),
+    // The divisor is scaled, `c * c + d * d` would overflow or underflow
+    ((P100, P100, P100, P100), (1.0, 0.0)),
+    ((P127, 0.0, P127, P127), (0.5, -0.5)),
+    ((1.0, 0.0, P100, 0.0), (M100, 0.0)),
+    ((MIN, 0.0, MIN, 0.0), (1.0, 0.0)),
+    ((MIN, MIN, 0.0, MIN), (1.0, -1.0)),
+    // Division by zero
+    ((1.0, 2.0, 0.0, 0.0), (INFINITY, INFINITY)),
+    ((1.0, -2.0, -0.0, 0.0), (-INFINITY, INFINITY)),
+    // Infinite dividend
+    ((INFINITY, INFINITY, 0.0, 1.0), (INFINITY, -INFINITY)),
+    ((INFINITY, 0.0, 1.0, 0.0), (INFINITY, NAN)),
+    ((INFINITY, NAN, 2.0, 0.0), (INFINITY, NAN)),
+    // Infinite divisor
+    ((1.0, 1.0, INFINITY, INFINITY), (0.0, 0.0)),
+    ((1.0, 1.0, -INFINITY, INFINITY), (0.0, -0.0)),
+    ((1.0, 2.0, INFINITY, NAN), (0.0, 0.0)),
+    // NaN results
+    ((NAN, 1.0, 1.0, 1.0), (NAN, NAN)),
+    ((1.0, 1.0, NAN, 0.0), (NAN, NAN)),
+    ((0.0, 0.0, 0.0, 0.0), (NAN, NAN)),
+    ((INFINITY, 0.0, INFINITY, 0.0), (NAN, NAN)),
+];
+
+#[test]
+fn divsc3() {
+    for (i, &((a, b, c, d), (re, im))) in TEST_CASES.iter().enumerate() {
+        // The result is a packed `u64` on x86
+        let z: [f32; 2] = unsafe { mem::transmute(__divsc3(a, b, c, d)) };
+        assert_eq!((i, (repr(re), repr(im))), (i, (repr(z[0]), repr(z[1]))));
+    }
+}
@@ -0,0 +1,10 @@
+// The `f80` helpers only exist on the x86_64 ELF targets
+#![cfg(all(target_arch = "x86_64", not(any(windows, target_os = "macos", target_os = "ios"))))]
+#![feature(compiler_builtins_lib)]
+#![feature(i128_type)]
+#![cfg_attr(all(target_arch = "arm",
+                not(any(target_env = "gnu", target_env = "musl")),
+                target_os = "linux",
+                test), no_std)]
+
+include!(concat!(env!("OUT_DIR"), "/fixunsxfsi.rs"));
@@ -0,0 +1,190 @@
+#![cfg(not(all(target_arch = "arm",
+               not(any(target_env = "gnu", target_env = "musl")),
+               target_os = "linux")))]
+#![feature(compiler_builtins_lib)]
+
+extern crate compiler_builtins;
+
+use std::mem;
+
+use compiler_builtins::float::{f8e4m3, f8e5m2};
+
+struct Format {
+    exponent_bits: i32,
+    has_infinity: bool,
+    from_f32: fn(f32, bool) -> u8,
+    to_f32: fn(u8) -> f32,
+}
+
+const E4M3: Format = Format {
+    exponent_bits: 4,
+    has_infinity: false,
+    from_f32: e4m3_from_f32,
+    to_f32: e4m3_to_f32,
+};
+
+const E5M2: Format = Format {
+    exponent_bits: 5,
+    has_infinity: true,
+    from_f32: e5m2_from_f32,
+    to_f32: e5m2_to_f32,
+};
+
+fn e4m3_from_f32(a: f32, saturate: bool) -> u8 {
+    f8e4m3::from_f32(a, saturate).0
+}
+
+fn e4m3_to_f32(a: u8) -> f32 {
+    f8e4m3(a).to_f32()
+}
+
+fn e5m2_from_f32(a: f32, saturate: bool) -> u8 {
+    f8e5m2::from_f32(a, saturate).0
+}
+
+fn e5m2_to_f32(a: u8) -> f32 {
+    f8e5m2(a).to_f32()
+}
+
+fn to_u32(x: f32) -> u32 {
+    unsafe { mem::transmute(x) }
+}
+
+fn mk_f32(x: u32) -> f32 {
+    unsafe { mem::transmute(x) }
+}
+
+/// The value of an encoding, from the definition of the format
+fn decode(format: &Format, x: u8) -> f64 {
+    let significand_bits = 7 - format.exponent_bits;
+    let exponent_max = (1 << format.exponent_bits) - 1;
+    let bias = exponent_max >> 1;
+    let exponent = (x as i32 >> significand_bits) & exponent_max;
+    let significand = x as i32 & ((1 << significand_bits) - 1);
+    let sign = if x & 0x80 != 0 { -1.0 } else { 1.0 };
+
+    if exponent == exponent_max {
+        if format.has_infinity {
+            return if significand == 0 { sign * std::f64::INFINITY } else { std::f64::NAN };
+        } else if significand == (1 << significand_bits) - 1 {
+            return std::f64::NAN;
+        }
+    }
+    if exponent == 0 {
+        sign * significand as f64 * 2f64.powi(1 - bias - significand_bits)
+    } else {
+        sign * (significand + (1 << significand_bits)) as f64 *
+            2f64.powi(exponent - bias - significand_bits)
+    }
+}
+
+fn is_finite(format: &Format, x: u8) -> bool {
+    decode(format, x).is_finite()
+}
+
+/// The largest finite encoding
+fn max(format: &Format) -> u8 {
+    if format.has_infinity { 0x7b } else { 0x7e }
+}
+
+/// The result of an overflow with the sign of `sign`
+fn overflow(format: &Format, sign: u8, saturate: bool) -> u8 {
+    if saturate {
+        max(format) | sign
+    } else if format.has_infinity {
+        0x7c | sign
+    } else {
+        0x7f | sign
+    }
+}
+
+fn check_from_f32(format: &Format, a: f32, saturate: bool, expected: u8) {
+    let result = (format.from_f32)(a, saturate);
+    assert_eq!(
+        (to_u32(a), saturate, expected),
+        (to_u32(a), saturate, result)
+    );
+}
+
+fn to_f32_exhaustive(format: &Format) {
+    for x in 0..256 {
+        let x = x as u8;
+        let expected = decode(format, x);
+        let result = (format.to_f32)(x);
+        if expected.is_nan() {
+            assert!(result.is_nan(), "{:#x}", x);
+        } else {
+            assert_eq!((x, to_u32(expected as f32)), (x, to_u32(result)));
+        }
+    }
+}
+
+fn from_f32_exhaustive(format: &Format) {
+    for x in 0..256 {
+        let x = x as u8;
+        let value = decode(format, x);
+        if value.is_nan() {
+            for &saturate in &[false, true] {
+                let result = (format.from_f32)(value as f32, saturate);
+                assert!(decode(format, result).is_nan(), "{:#x}", x);
+            }
+            continue;
+        }
+
+        // Every number converts back to itself, infinities saturate
+        check_from_f32(format, value as f32, false, x);
+        let saturated = if value.is_infinite() { max(format) | x & 0x80 } else { x };
+        check_from_f32(format, value as f32, true, saturated);
+
+        // Halfway to the next number of larger magnitude, which is exact in
+        // `f32`, rounds to even, and the `f32`s around it round to the nearer
+        // one
+        let next = x + 1;
+        if x & 0x7f == max(format) {
+            let ulp = value - decode(format, x - 1);
+            let halfway = (value + ulp / 2.0) as f32;
+            for &saturate in &[false, true] {
+                let tie = if x & 1 == 0 { x } else { overflow(format, x & 0x80, saturate) };
+                check_from_f32(format, halfway, saturate, tie);
+                let above = mk_f32(to_u32(halfway) + 1);
+                check_from_f32(format, above, saturate, overflow(format, x & 0x80, saturate));
+                let below = mk_f32(to_u32(halfway) - 1);
+                check_from_f32(format, below, saturate, x);
+            }
+        } else if is_finite(format, x) && is_finite(format, next) {
+            let halfway = ((value + decode(format, next)) / 2.0) as f32;
+            let tie = if x & 1 == 0 { x } else { next };
+            check_from_f32(format, halfway, false, tie);
+            check_from_f32(format, mk_f32(to_u32(halfway) + 1), false, next);
+            check_from_f32(format, mk_f32(to_u32(halfway) - 1), false, x);
+        }
+    }
+
+    // Values far out of range
+    for &a in &[std::f32::MAX, -std::f32::MAX, 1.0e10, -1.0e10] {
+        let sign = if a < 0.0 { 0x80 } else { 0 };
+        for &saturate in &[false, true] {
+            check_from_f32(format, a, saturate, overflow(format, sign, saturate));
+        }
+    }
+}
+
+#[test]
+fn e4m3_to_f32_exhaustive() {
+    to_f32_exhaustive(&E4M3);
+}
+
+#[test]
+fn e4m3_from_f32_exhaustive() {
+    from_f32_exhaustive(&E4M3);
+}
+
+#[test]
+fn e5m2_to_f32_exhaustive() {
+    to_f32_exhaustive(&E5M2);
+}
+
+#[test]
+fn e5m2_from_f32_exhaustive() {
+    from_f32_exhaustive(&E5M2);
+}
@@ -0,0 +1,28 @@
+// Only on x86, where the reference is the `sqrtss` instruction, the other
+// targets run their tests in an emulator
+#![cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#![feature(compiler_builtins_lib)]
+
+extern crate compiler_builtins;
+
+use std::mem;
+
+use compiler_builtins::math::sqrtf;
+
+// Checks every `f32`. This takes too long without optimizations.
+#[cfg_attr(debug_assertions, ignore)]
+#[test]
+fn sqrtf_exhaustive() {
+    for i in 0..(1u64 << 32) {
+        let x: f32 = unsafe { mem::transmute(i as u32) };
+        let expected = x.sqrt();
+        let actual = sqrtf(x);
+        if expected.is_nan() {
+            assert!(actual.is_nan(), "sqrtf({:#x})", i);
+        } else {
+            let expected: u32 = unsafe { mem::transmute(expected) };
+            let actual: u32 = unsafe { mem::transmute(actual) };
+            assert_eq!(expected, actual, "sqrtf({:#x})", i);
+        }
+    }
+}